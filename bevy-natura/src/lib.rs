@@ -1,11 +1,20 @@
+use bevy::ecs::world::EntityMutExcept;
 use bevy::prelude::*;
-use natura::{Spring, Sprite as NaturaSpriteCore};
+use bevy::reflect::{GetPath, ParsedPath};
+use natura::{Animatable, DynamicSpring, Float, SpringTrajectory, Sprite as NaturaSpriteCore};
+
+#[cfg(feature = "presets")]
+mod presets;
+#[cfg(feature = "presets")]
+pub use presets::*;
 
 // ==================== Animation Events ====================
 
 /// Event emitted when an animation starts moving towards its target.
 /// This is sent when an entity begins animating from rest or when the target changes.
+/// For scale animations (`NaturaScaleTarget`), `target` is the target scale.
 #[derive(Event, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimationStarted {
     /// The entity that started animating
     pub entity: Entity,
@@ -14,7 +23,9 @@ pub struct AnimationStarted {
 }
 
 /// Event emitted when an animation completes (reaches its target and comes to rest).
+/// For scale animations (`NaturaScaleTarget`), `final_position` is the final scale.
 #[derive(Event, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimationCompleted {
     /// The entity that completed its animation
     pub entity: Entity,
@@ -27,6 +38,7 @@ pub struct AnimationCompleted {
 /// Tracks the animation state for event emission
 #[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 #[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AnimationState {
     /// Animation is not moving (at rest)
     #[default]
@@ -37,25 +49,73 @@ pub enum AnimationState {
     JustCompleted,
 }
 
+/// Transition of an `AnimationState` that is reported with an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AnimationTransition {
+    Started,
+    Completed,
+}
+
+impl AnimationState {
+    /// Advances the state machine after a spring update and returns the
+    /// transition to report, if any.
+    fn advance(&mut self, prev_at_rest: bool, now_at_rest: bool, at_target: bool) -> Option<AnimationTransition> {
+        match *self {
+            AnimationState::Idle => {
+                if !now_at_rest && !at_target {
+                    *self = AnimationState::Animating;
+                    return Some(AnimationTransition::Started);
+                }
+            }
+            AnimationState::Animating => {
+                if now_at_rest && at_target {
+                    *self = AnimationState::JustCompleted;
+                    return Some(AnimationTransition::Completed);
+                }
+            }
+            AnimationState::JustCompleted => {
+                // Transition back to Idle after one frame
+                *self = AnimationState::Idle;
+            }
+        }
+
+        // If was at rest and now moving towards a different target, it started again
+        if prev_at_rest && !now_at_rest && *self == AnimationState::Idle {
+            *self = AnimationState::Animating;
+            return Some(AnimationTransition::Started);
+        }
+
+        None
+    }
+}
+
 // ==================== Pause/Resume ====================
 
 /// Component to pause an individual entity's animation.
 /// Add this component to pause, remove it to resume.
+///
+/// Pausing, whether individually, by group or globally, stops every kind of
+/// animation of an entity: springs, rotations, scales, lenses, tweens,
+/// timelines and particles.
 #[derive(Component, Default, Debug, Clone, Reflect)]
 #[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimationPaused;
 
 /// Resource to globally pause all Natura animations.
 /// Insert this resource to pause all animations, remove to resume.
 #[derive(Resource, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GlobalAnimationPaused;
 
 // ==================== Animation Groups ====================
 
 /// Component to group animations together.
-/// Entities with the same group ID can be controlled together.
+/// Entities with the same group ID can be controlled together: paused with
+/// `PausedGroups` and animated with `AnimateGroup`.
 #[derive(Component, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimationGroup(pub u32);
 
 impl AnimationGroup {
@@ -69,6 +129,7 @@ impl AnimationGroup {
 /// Resource to pause specific animation groups.
 /// Groups listed here will not animate.
 #[derive(Resource, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PausedGroups {
     /// Set of paused group IDs
     pub groups: std::collections::HashSet<u32>,
@@ -92,14 +153,30 @@ impl PausedGroups {
     }
 }
 
+/// Returns true if an entity's animation is paused, either individually or
+/// through its animation group.
+fn is_entity_paused(
+    paused: Option<&AnimationPaused>,
+    group: Option<&AnimationGroup>,
+    paused_groups: Option<&PausedGroups>,
+) -> bool {
+    paused.is_some() || matches!((group, paused_groups), (Some(group), Some(paused_groups)) if paused_groups.is_paused(group.0))
+}
+
 // ==================== Easing Curves ====================
 
-/// Easing curve types for animation modification.
-/// These curves modify how the spring animation progresses over time.
+/// Easing curve types that shape the motion of a duration-based `NaturaTween`.
+/// Springs follow their own physics, so the curve has no effect on entities
+/// animated by `NaturaSpring`.
+///
+/// The curves are evaluated by `natura::Easing`, which non-Bevy code can use
+/// directly. `EaseIn`, `EaseOut` and `EaseInOut` are the quadratic curves, and
+/// `Elastic` and `Bounce` are the elastic and bounce ease outs.
 #[derive(Component, Default, Debug, Clone, Copy, PartialEq, Reflect)]
 #[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EasingCurve {
-    /// No easing modification - pure spring physics
+    /// No easing - linear progress
     #[default]
     None,
     /// Ease in - starts slow, speeds up
@@ -120,6 +197,61 @@ pub enum EasingCurve {
     Elastic,
     /// Bounce effect at the end
     Bounce,
+    /// Sine ease in
+    SineIn,
+    /// Sine ease out
+    SineOut,
+    /// Sine ease in and out
+    SineInOut,
+    /// Quadratic ease in and out
+    QuadraticInOut,
+    /// Cubic ease in and out
+    CubicInOut,
+    /// Quartic ease in
+    QuarticIn,
+    /// Quartic ease out
+    QuarticOut,
+    /// Quartic ease in and out
+    QuarticInOut,
+    /// Quintic ease in
+    QuinticIn,
+    /// Quintic ease out
+    QuinticOut,
+    /// Quintic ease in and out
+    QuinticInOut,
+    /// Exponential ease in
+    ExponentialIn,
+    /// Exponential ease out
+    ExponentialOut,
+    /// Exponential ease in and out
+    ExponentialInOut,
+    /// Circular ease in
+    CircularIn,
+    /// Circular ease out
+    CircularOut,
+    /// Circular ease in and out
+    CircularInOut,
+    /// Back ease in - pulls back before moving
+    BackIn,
+    /// Back ease out - overshoots before settling
+    BackOut,
+    /// Back ease in and out
+    BackInOut,
+    /// Elastic ease in
+    ElasticIn,
+    /// Elastic ease in and out
+    ElasticInOut,
+    /// Bounce effect at the start
+    BounceIn,
+    /// Bounce effect at the start and end
+    BounceInOut,
+    /// CSS `cubic-bezier(x1, y1, x2, y2)` timing function
+    CubicBezier { x1: f64, y1: f64, x2: f64, y2: f64 },
+    /// CSS `steps(n)` timing function, jumping at the end of each step
+    Steps(u32),
+    /// Motion of a spring released from rest, compressed into the animation.
+    /// Create it with `EasingCurve::spring` to last until the spring settles.
+    Spring { angular_frequency: f64, damping_ratio: f64, duration: f64 },
 }
 
 impl EasingCurve {
@@ -127,50 +259,81 @@ impl EasingCurve {
     /// Returns the eased progress value.
     #[must_use]
     pub fn apply(&self, t: f64) -> f64 {
-        let t = t.clamp(0.0, 1.0);
-        match self {
-            EasingCurve::None => t,
-            EasingCurve::EaseIn => t * t,
-            EasingCurve::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
-            EasingCurve::EaseInOut => {
-                if t < 0.5 {
-                    2.0 * t * t
-                } else {
-                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
-                }
-            }
-            EasingCurve::QuadraticIn => t * t,
-            EasingCurve::QuadraticOut => t * (2.0 - t),
-            EasingCurve::CubicIn => t * t * t,
-            EasingCurve::CubicOut => {
-                let t1 = t - 1.0;
-                t1 * t1 * t1 + 1.0
-            }
-            EasingCurve::Elastic => {
-                if t == 0.0 || t == 1.0 {
-                    t
-                } else {
-                    let p = 0.3;
-                    let s = p / 4.0;
-                    (2.0_f64).powf(-10.0 * t) * ((t - s) * (2.0 * std::f64::consts::PI / p)).sin() + 1.0
-                }
-            }
-            EasingCurve::Bounce => {
-                let n1 = 7.5625;
-                let d1 = 2.75;
-                if t < 1.0 / d1 {
-                    n1 * t * t
-                } else if t < 2.0 / d1 {
-                    let t = t - 1.5 / d1;
-                    n1 * t * t + 0.75
-                } else if t < 2.5 / d1 {
-                    let t = t - 2.25 / d1;
-                    n1 * t * t + 0.9375
-                } else {
-                    let t = t - 2.625 / d1;
-                    n1 * t * t + 0.984375
-                }
-            }
+        natura::Easing::from(*self).apply(t)
+    }
+
+    /// Creates a CSS `cubic-bezier(x1, y1, x2, y2)` curve. `x1` and `x2` are
+    /// clamped to [0, 1].
+    #[must_use]
+    pub fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        natura::CubicBezier::new(x1, y1, x2, y2).into()
+    }
+
+    /// Creates a curve shaped like the motion of a spring with the given
+    /// parameters, lasting until the spring settles. `natura::SpringEasing`
+    /// also tells the natural duration of such an animation.
+    #[must_use]
+    pub fn spring(params: impl Into<SpringParams>) -> Self {
+        natura::SpringEasing::new(params).into()
+    }
+}
+
+impl From<natura::CubicBezier> for EasingCurve {
+    fn from(bezier: natura::CubicBezier) -> Self {
+        EasingCurve::CubicBezier { x1: bezier.x1, y1: bezier.y1, x2: bezier.x2, y2: bezier.y2 }
+    }
+}
+
+impl From<SpringEasing> for EasingCurve {
+    fn from(spring: SpringEasing) -> Self {
+        let params = spring.params();
+        EasingCurve::Spring {
+            angular_frequency: params.angular_frequency.0,
+            damping_ratio: params.damping_ratio.0,
+            duration: spring.duration(),
+        }
+    }
+}
+
+impl From<EasingCurve> for Easing {
+    fn from(curve: EasingCurve) -> Self {
+        match curve {
+            EasingCurve::None => Easing::Linear,
+            EasingCurve::EaseIn | EasingCurve::QuadraticIn => Easing::QuadIn,
+            EasingCurve::EaseOut | EasingCurve::QuadraticOut => Easing::QuadOut,
+            EasingCurve::EaseInOut | EasingCurve::QuadraticInOut => Easing::QuadInOut,
+            EasingCurve::CubicIn => Easing::CubicIn,
+            EasingCurve::CubicOut => Easing::CubicOut,
+            EasingCurve::CubicInOut => Easing::CubicInOut,
+            EasingCurve::Elastic => Easing::ElasticOut,
+            EasingCurve::Bounce => Easing::BounceOut,
+            EasingCurve::SineIn => Easing::SineIn,
+            EasingCurve::SineOut => Easing::SineOut,
+            EasingCurve::SineInOut => Easing::SineInOut,
+            EasingCurve::QuarticIn => Easing::QuartIn,
+            EasingCurve::QuarticOut => Easing::QuartOut,
+            EasingCurve::QuarticInOut => Easing::QuartInOut,
+            EasingCurve::QuinticIn => Easing::QuintIn,
+            EasingCurve::QuinticOut => Easing::QuintOut,
+            EasingCurve::QuinticInOut => Easing::QuintInOut,
+            EasingCurve::ExponentialIn => Easing::ExpoIn,
+            EasingCurve::ExponentialOut => Easing::ExpoOut,
+            EasingCurve::ExponentialInOut => Easing::ExpoInOut,
+            EasingCurve::CircularIn => Easing::CircIn,
+            EasingCurve::CircularOut => Easing::CircOut,
+            EasingCurve::CircularInOut => Easing::CircInOut,
+            EasingCurve::BackIn => Easing::BackIn,
+            EasingCurve::BackOut => Easing::BackOut,
+            EasingCurve::BackInOut => Easing::BackInOut,
+            EasingCurve::ElasticIn => Easing::ElasticIn,
+            EasingCurve::ElasticInOut => Easing::ElasticInOut,
+            EasingCurve::BounceIn => Easing::BounceIn,
+            EasingCurve::BounceInOut => Easing::BounceInOut,
+            EasingCurve::CubicBezier { x1, y1, x2, y2 } => Easing::cubic_bezier(x1, y1, x2, y2),
+            EasingCurve::Steps(count) => Easing::steps(count, StepPosition::JumpEnd),
+            EasingCurve::Spring { angular_frequency, damping_ratio, duration } => Easing::Spring(
+                SpringEasing::with_duration((AngularFrequency(angular_frequency), DampingRatio(damping_ratio)), duration),
+            ),
         }
     }
 }
@@ -215,19 +378,20 @@ pub struct NaturaAnimationPlugin;
 /// Supports 3D positions (x, y, z) for both 2D and 3D games.
 #[derive(Component, Default, Debug, Clone, Reflect)]
 #[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NaturaSprite {
-    pub x: f64,
-    pub x_velocity: f64,
-    pub y: f64,
-    pub y_velocity: f64,
-    pub z: f64,
-    pub z_velocity: f64,
+    pub x: f32,
+    pub x_velocity: f32,
+    pub y: f32,
+    pub y_velocity: f32,
+    pub z: f32,
+    pub z_velocity: f32,
 }
 
 impl NaturaSprite {
     /// Creates a new NaturaSprite with the specified initial position.
     #[must_use]
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
         NaturaSprite {
             x,
             y,
@@ -240,22 +404,22 @@ impl NaturaSprite {
 
     /// Creates a new 2D NaturaSprite (z = 0).
     #[must_use]
-    pub fn new_2d(x: f64, y: f64) -> Self {
+    pub fn new_2d(x: f32, y: f32) -> Self {
         Self::new(x, y, 0.0)
     }
 
     /// Returns true if the sprite has effectively stopped moving.
     /// Uses a threshold to determine if velocities are negligible.
     #[must_use]
-    pub fn is_at_rest(&self, velocity_threshold: f64) -> bool {
+    pub fn is_at_rest(&self, velocity_threshold: f32) -> bool {
         self.x_velocity.abs() < velocity_threshold
             && self.y_velocity.abs() < velocity_threshold
             && self.z_velocity.abs() < velocity_threshold
     }
 }
 
-impl From<NaturaSpriteCore> for NaturaSprite {
-    fn from(sprite: NaturaSpriteCore) -> Self {
+impl From<NaturaSpriteCore<f32>> for NaturaSprite {
+    fn from(sprite: NaturaSpriteCore<f32>) -> Self {
         NaturaSprite {
             x: sprite.x,
             x_velocity: sprite.x_velocity,
@@ -271,18 +435,30 @@ impl From<NaturaSpriteCore> for NaturaSprite {
 /// Each entity can have its own spring parameters.
 /// 
 /// The spring uses Bevy's Time resource for frame-rate independent animation.
+/// Each frame is evaluated exactly for its own delta time.
 #[derive(Component, Reflect)]
 #[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NaturaSpring {
     /// Angular frequency - controls animation speed (higher = faster)
-    pub angular_frequency: f64,
+    pub angular_frequency: f32,
     /// Damping ratio - controls springiness (< 1 bouncy, = 1 smooth, > 1 sluggish)
-    pub damping_ratio: f64,
-    /// Cached spring for the current frame's delta time
+    pub damping_ratio: f32,
+    /// Spring evaluator with cached coefficients for recently seen delta times
     #[reflect(ignore)]
-    cached_spring: Option<(f64, Spring)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cached_spring: Option<DynamicSpring<f32>>,
 }
 
+/// Number of per-delta-time coefficient sets each `NaturaSpring` keeps cached
+const SPRING_CACHE_CAPACITY: usize = 4;
+
+/// Sampling interval in seconds used when estimating animation completion
+const COMPLETION_ESTIMATE_STEP: f64 = 1.0 / 240.0;
+
+/// Largest number of samples taken when estimating animation completion
+const MAX_COMPLETION_SAMPLES: usize = 1 << 16;
+
 impl Clone for NaturaSpring {
     fn clone(&self) -> Self {
         // Don't clone the cached spring - it will be recreated on first use
@@ -320,7 +496,7 @@ impl NaturaSpring {
     /// * `angular_frequency` - Controls the speed of the animation (higher = faster)
     /// * `damping_ratio` - Controls the springiness (< 1 bouncy, = 1 smooth, > 1 sluggish)
     #[must_use]
-    pub fn new(angular_frequency: AngularFrequency, damping_ratio: DampingRatio) -> Self {
+    pub fn new(angular_frequency: AngularFrequency<f32>, damping_ratio: DampingRatio<f32>) -> Self {
         NaturaSpring {
             angular_frequency: angular_frequency.0,
             damping_ratio: damping_ratio.0,
@@ -328,31 +504,142 @@ impl NaturaSpring {
         }
     }
 
-    /// Gets or creates a spring for the given delta time.
-    fn get_spring(&mut self, delta_seconds: f64) -> &mut Spring {
-        // Check if we need to recreate the spring (delta time changed significantly)
+    /// Creates a new NaturaSpring from any description of a spring that
+    /// converts into [`SpringParams`], such as a perceptual duration and bounce.
+    ///
+    /// ```rust,ignore
+    /// let spring = NaturaSpring::with_params(DurationBounce { duration: 0.5, bounce: 0.2 });
+    /// ```
+    #[must_use]
+    pub fn with_params(params: impl Into<SpringParams>) -> Self {
+        let params = params.into();
+        Self::new(
+            AngularFrequency(f32::from_f64(params.angular_frequency.0)),
+            DampingRatio(f32::from_f64(params.damping_ratio.0)),
+        )
+    }
+
+    /// Returns the spring parameters, which can be converted into other
+    /// representations (e.g. `DurationBounce`) for display in tools.
+    #[must_use]
+    pub fn params(&self) -> SpringParams {
+        SpringParams::new(
+            AngularFrequency(f64::from(self.angular_frequency)),
+            DampingRatio(f64::from(self.damping_ratio)),
+        )
+    }
+
+    /// Gets the spring evaluator, recreating it if the parameters changed.
+    fn get_spring(&mut self) -> &mut DynamicSpring<f32> {
         let needs_update = match &self.cached_spring {
-            Some((cached_dt, _)) => (cached_dt - delta_seconds).abs() > 0.001,
+            Some(spring) => {
+                spring.angular_frequency().0 != self.angular_frequency
+                    || spring.damping_ratio().0 != self.damping_ratio
+            }
             None => true,
         };
 
         if needs_update {
-            let spring = Spring::new(
-                DeltaTime(delta_seconds),
+            self.cached_spring = Some(DynamicSpring::with_cache_capacity(
                 AngularFrequency(self.angular_frequency),
                 DampingRatio(self.damping_ratio),
-            );
-            self.cached_spring = Some((delta_seconds, spring));
+                SPRING_CACHE_CAPACITY,
+            ));
+        }
+
+        self.cached_spring.as_mut().unwrap()
+    }
+
+    /// Estimates how many seconds it takes for the sprite to come to rest at
+    /// the target, i.e. roughly when `AnimationCompleted` will fire.
+    /// Returns `None` if the spring never settles (zero damping).
+    ///
+    /// The motion is sampled from the earliest time its decay allows it to be
+    /// at rest, for at most `MAX_COMPLETION_SAMPLES` samples; if it hasn't
+    /// completed by then, the time after which it stays at rest is returned.
+    #[must_use]
+    pub fn estimate_completion_time(&self, sprite: &NaturaSprite, target: &NaturaTarget) -> Option<f64> {
+        let trajectories = [
+            (sprite.x, sprite.x_velocity, target.x),
+            (sprite.y, sprite.y_velocity, target.y),
+            (sprite.z, sprite.z_velocity, target.z),
+        ]
+        .map(|(pos, vel, target)| {
+            SpringTrajectory::new(
+                AngularFrequency(f64::from(self.angular_frequency)),
+                DampingRatio(f64::from(self.damping_ratio)),
+                f64::from(pos),
+                f64::from(vel),
+                f64::from(target),
+            )
+        });
+
+        // After this time every axis stays within the thresholds for good.
+        let settled = trajectories.iter().try_fold(0.0_f64, |time, trajectory| {
+            let position_time = trajectory.settling_time(f64::from(TARGET_POSITION_THRESHOLD))?;
+            let velocity_time = trajectory.velocity_settling_time(f64::from(REST_VELOCITY_THRESHOLD))?;
+            Some(time.max(position_time).max(velocity_time))
+        })?;
+
+        // The animation completes the first time all thresholds hold at once,
+        // which may happen earlier, at a turning point of the motion.
+        let is_complete = |time: f64| {
+            trajectories.iter().all(|trajectory| {
+                let (pos, vel) = trajectory.state_at(time);
+                (pos - trajectory.equilibrium_position()).abs() < f64::from(TARGET_POSITION_THRESHOLD)
+                    && vel.abs() < f64::from(REST_VELOCITY_THRESHOLD)
+            })
+        };
+        let earliest = self.earliest_completion_time(sprite, target).min(settled);
+        let steps = ((settled - earliest) / COMPLETION_ESTIMATE_STEP).ceil() as usize;
+        let first = (0..steps.min(MAX_COMPLETION_SAMPLES))
+            .map(|i| earliest + i as f64 * COMPLETION_ESTIMATE_STEP)
+            .find(|time| is_complete(*time));
+
+        Some(first.unwrap_or(settled))
+    }
+
+    /// Returns a time before which the sprite can't be at rest at the target.
+    ///
+    /// An under-damped axis with offset `y` from the target moves so that
+    /// `y² + ((y' + σy) / ω_d)²` decays as `exp(-2σt)`, where `σ = ζω` and
+    /// `ω_d = ω√(1 - ζ²)`. It can't be within the thresholds until that
+    /// quantity is below its value at the thresholds.
+    fn earliest_completion_time(&self, sprite: &NaturaSprite, target: &NaturaTarget) -> f64 {
+        let (omega, zeta) = (f64::from(self.angular_frequency), f64::from(self.damping_ratio));
+        if omega <= 0.0 || zeta <= 0.0 || zeta >= 1.0 {
+            return 0.0;
         }
 
-        &mut self.cached_spring.as_mut().unwrap().1
+        let decay = zeta * omega;
+        let damped_frequency = omega * (1.0 - zeta * zeta).sqrt();
+        let amplitude = |offset: f64, velocity: f64| offset.hypot((velocity + decay * offset) / damped_frequency);
+        let at_rest = amplitude(f64::from(TARGET_POSITION_THRESHOLD), f64::from(REST_VELOCITY_THRESHOLD));
+
+        [
+            (sprite.x - target.x, sprite.x_velocity),
+            (sprite.y - target.y, sprite.y_velocity),
+            (sprite.z - target.z, sprite.z_velocity),
+        ]
+        .into_iter()
+        .map(|(offset, velocity)| {
+            (amplitude(f64::from(offset), f64::from(velocity)) / at_rest).ln().max(0.0) / decay
+        })
+        .fold(0.0, f64::max)
     }
 
     /// Updates the position and velocity based on the spring physics.
     /// Returns the new (position, velocity) tuple.
-    pub fn update(&mut self, pos: f64, vel: f64, equilibrium_pos: f64, delta_seconds: f64) -> (f64, f64) {
-        let spring = self.get_spring(delta_seconds);
-        spring.update(pos, vel, equilibrium_pos)
+    pub fn update(&mut self, pos: f32, vel: f32, equilibrium_pos: f32, delta_seconds: f32) -> (f32, f32) {
+        self.update_value(pos, vel, equilibrium_pos, delta_seconds)
+    }
+
+    /// Updates any [`Animatable`] position and velocity based on the spring physics,
+    /// so all axes can be advanced in one call.
+    /// Returns the new (position, velocity) tuple.
+    pub fn update_value<T: Animatable<f32>>(&mut self, pos: T, vel: T, equilibrium_pos: T, delta_seconds: f32) -> (T, T) {
+        self.get_spring()
+            .update_value(pos, vel, equilibrium_pos, DeltaTime(delta_seconds))
     }
 }
 
@@ -362,22 +649,23 @@ impl NaturaSpring {
 /// Supports 3D targets (x, y, z) for both 2D and 3D games.
 #[derive(Component, Default, Debug, Clone, Reflect)]
 #[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NaturaTarget {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
 }
 
 impl NaturaTarget {
     /// Creates a new 2D target (z = 0).
     #[must_use]
-    pub fn new_2d(x: f64, y: f64) -> Self {
+    pub fn new_2d(x: f32, y: f32) -> Self {
         NaturaTarget { x, y, z: 0.0 }
     }
 
     /// Creates a new 3D target.
     #[must_use]
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
         NaturaTarget { x, y, z }
     }
 }
@@ -408,7 +696,7 @@ pub struct NaturaSpringBundle {
 impl NaturaSpringBundle {
     /// Creates a new bundle with the specified spring parameters.
     #[must_use]
-    pub fn new(angular_frequency: AngularFrequency, damping_ratio: DampingRatio) -> Self {
+    pub fn new(angular_frequency: AngularFrequency<f32>, damping_ratio: DampingRatio<f32>) -> Self {
         NaturaSpringBundle {
             sprite: NaturaSprite::default(),
             spring: NaturaSpring::new(angular_frequency, damping_ratio),
@@ -420,11 +708,11 @@ impl NaturaSpringBundle {
     /// Creates a new bundle with a custom initial position.
     #[must_use]
     pub fn with_position(
-        angular_frequency: AngularFrequency,
-        damping_ratio: DampingRatio,
-        initial_x: f64,
-        initial_y: f64,
-        initial_z: f64,
+        angular_frequency: AngularFrequency<f32>,
+        damping_ratio: DampingRatio<f32>,
+        initial_x: f32,
+        initial_y: f32,
+        initial_z: f32,
     ) -> Self {
         NaturaSpringBundle {
             sprite: NaturaSprite::new(initial_x, initial_y, initial_z),
@@ -434,11 +722,13 @@ impl NaturaSpringBundle {
         }
     }
 
-    /// Creates a new bundle with an easing curve.
+    /// Creates a new bundle with an easing curve. The curve has no effect on
+    /// the spring; use a `NaturaTween` for eased motion.
     #[must_use]
+    #[deprecated(note = "easing curves don't affect springs; use `NaturaTween` for eased motion")]
     pub fn with_easing(
-        angular_frequency: AngularFrequency,
-        damping_ratio: DampingRatio,
+        angular_frequency: AngularFrequency<f32>,
+        damping_ratio: DampingRatio<f32>,
         easing: EasingCurve,
     ) -> Self {
         NaturaSpringBundle {
@@ -452,8 +742,8 @@ impl NaturaSpringBundle {
     /// Creates a new bundle with a group assignment.
     #[must_use]
     pub fn with_group(
-        angular_frequency: AngularFrequency,
-        damping_ratio: DampingRatio,
+        angular_frequency: AngularFrequency<f32>,
+        damping_ratio: DampingRatio<f32>,
         _group_id: u32,
     ) -> Self {
         // Note: AnimationGroup component should be added separately
@@ -466,10 +756,176 @@ impl NaturaSpringBundle {
     }
 }
 
+// ==================== Timestep ====================
+
+/// Resource that selects how spring animations advance with time.
+/// Insert it to switch from the default variable timestep to sub-stepping.
+///
+/// ```rust,ignore
+/// app.insert_resource(NaturaTimestep::fixed_hz(120.0, 8));
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NaturaTimestep {
+    /// One spring update per frame, exact for the frame's delta time. Frames
+    /// shorter than 0.1 ms or longer than 100 ms (e.g. lag spikes) are skipped.
+    #[default]
+    Variable,
+    /// Frame time is accumulated and springs advance in fixed steps of `step`
+    /// seconds, at most `max_steps` per frame, so lag spikes are caught up
+    /// without freezing; time beyond `max_steps` steps is dropped. Entities
+    /// are rendered at the exact frame time, between the last fixed step and
+    /// the next one, so motion stays smooth at any frame rate.
+    Fixed {
+        /// Duration of a step in seconds. Must be positive.
+        step: f32,
+        /// Maximum number of steps per frame
+        max_steps: u32,
+    },
+}
+
+impl NaturaTimestep {
+    /// Creates a fixed timestep of `hz` steps per second, taking at most
+    /// `max_steps` steps per frame.
+    #[must_use]
+    pub fn fixed_hz(hz: f32, max_steps: u32) -> Self {
+        NaturaTimestep::Fixed { step: 1.0 / hz, max_steps }
+    }
+}
+
+/// Steps the springs advance by in the current frame.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+struct NaturaClock {
+    /// Duration of each step in seconds
+    step: f32,
+    /// Number of steps to take this frame
+    steps: u32,
+    /// Seconds between the last step and the frame time, not yet simulated
+    remainder: f32,
+    /// Whether all animations are paused by `GlobalAnimationPaused`
+    paused: bool,
+}
+
+impl NaturaClock {
+    /// Returns true if nothing moves this frame.
+    fn is_idle(&self) -> bool {
+        self.paused || (self.steps == 0 && self.remainder == 0.0)
+    }
+}
+
+/// System that turns the frame's delta time into spring steps according to
+/// the `NaturaTimestep`.
+fn natura_clock_system(
+    time: Res<Time>,
+    timestep: Res<NaturaTimestep>,
+    global_pause: Option<Res<GlobalAnimationPaused>>,
+    mut clock: ResMut<NaturaClock>,
+) {
+    clock.paused = global_pause.is_some();
+    if clock.paused {
+        return;
+    }
+
+    let delta_seconds = time.delta_secs();
+    *clock = match *timestep {
+        NaturaTimestep::Variable => NaturaClock {
+            step: delta_seconds,
+            // Skip if delta is too small or too large (e.g., during pause or lag spikes)
+            steps: u32::from((0.0001..=0.1).contains(&delta_seconds)),
+            remainder: 0.0,
+            paused: false,
+        },
+        NaturaTimestep::Fixed { step, .. } if step <= 0.0 => NaturaClock::default(),
+        NaturaTimestep::Fixed { step, max_steps } => {
+            let accumulated = clock.remainder + delta_seconds;
+            let available = (accumulated / step).floor();
+            NaturaClock {
+                step,
+                steps: (available as u32).min(max_steps),
+                remainder: accumulated - available * step,
+                paused: false,
+            }
+        }
+    };
+}
+
+/// Spring state that animates one property of an entity towards a target,
+/// such as its rotation, its scale or a field reached through a lens.
+trait SpringTrack {
+    /// Value of the animated property
+    type Value: Copy;
+    /// Rate of change of the value per second
+    type Velocity;
+
+    /// Returns the value and velocity after `delta_seconds` without changing
+    /// the state.
+    fn evaluate(&mut self, value: Self::Value, target: Self::Value, delta_seconds: f32) -> (Self::Value, Self::Velocity);
+
+    /// Sets the velocity of the value.
+    fn set_velocity(&mut self, velocity: Self::Velocity);
+
+    /// Returns true if the value has effectively stopped changing.
+    fn is_at_rest(&self, velocity_threshold: f32) -> bool;
+
+    /// Returns true if `value` is close enough to `target` to have reached it.
+    fn is_at_target(value: Self::Value, target: Self::Value) -> bool;
+
+    /// Returns the state used to emit the animation events.
+    fn state(&mut self) -> &mut AnimationState;
+
+    /// Returns the value at the last fixed step, kept while the entity shows
+    /// the value between steps (see `NaturaTimestep::Fixed`).
+    fn between_steps(&mut self) -> &mut Option<Self::Value>;
+}
+
+/// Values of a `SpringTrack` after a frame.
+struct TrackFrame<V> {
+    /// Value at the last step
+    value: V,
+    /// Value to show at the frame time
+    rendered: V,
+    /// Transition of the animation state, if any
+    transition: Option<AnimationTransition>,
+}
+
+/// Springs `track` from `value` towards `target` by the steps of `clock`,
+/// evaluating the spring at the frame time when it lies between two fixed
+/// steps.
+fn step_spring_track<T: SpringTrack>(
+    track: &mut T,
+    value: T::Value,
+    target: T::Value,
+    clock: &NaturaClock,
+) -> TrackFrame<T::Value> {
+    let prev_at_rest = track.is_at_rest(REST_VELOCITY_THRESHOLD);
+    let mut value = track.between_steps().take().unwrap_or(value);
+    for _ in 0..clock.steps {
+        let (next, velocity) = track.evaluate(value, target, clock.step);
+        track.set_velocity(velocity);
+        value = next;
+    }
+
+    let mut rendered = value;
+    if clock.remainder > 0.0 {
+        rendered = track.evaluate(value, target, clock.remainder).0;
+        *track.between_steps() = Some(value);
+    }
+
+    // Events are only sent on frames that step the spring
+    let transition = if clock.steps == 0 {
+        None
+    } else {
+        let now_at_rest = track.is_at_rest(REST_VELOCITY_THRESHOLD);
+        track.state().advance(prev_at_rest, now_at_rest, T::is_at_target(value, target))
+    };
+
+    TrackFrame { value, rendered, transition }
+}
+
 /// Velocity threshold for determining if an animation is at rest
-const REST_VELOCITY_THRESHOLD: f64 = 0.01;
+const REST_VELOCITY_THRESHOLD: f32 = 0.01;
 /// Position threshold for determining if an animation has reached its target
-const TARGET_POSITION_THRESHOLD: f64 = 0.1;
+const TARGET_POSITION_THRESHOLD: f32 = 0.1;
 
 /// System that updates all entities with Natura spring animations.
 /// This system queries all entities that have NaturaSprite, NaturaSpring,
@@ -481,12 +937,13 @@ const TARGET_POSITION_THRESHOLD: f64 = 0.1;
 /// - Global pausing via `GlobalAnimationPaused` resource
 /// - Group pausing via `PausedGroups` resource
 /// - Animation events (`AnimationStarted`, `AnimationCompleted`)
-/// - Easing curves via `EasingCurve` component
+/// - Variable or fixed sub-stepped timesteps via `NaturaTimestep` resource
 /// 
-/// Uses Bevy's Time resource for frame-rate independent animation.
+/// Uses Bevy's Time resource for frame-rate independent animation, stepped
+/// according to the `NaturaTimestep` resource.
+#[allow(clippy::type_complexity)]
 fn natura_animation_system(
-    time: Res<Time>,
-    global_pause: Option<Res<GlobalAnimationPaused>>,
+    clock: Res<NaturaClock>,
     paused_groups: Option<Res<PausedGroups>>,
     mut ev_started: EventWriter<AnimationStarted>,
     mut ev_completed: EventWriter<AnimationCompleted>,
@@ -497,87 +954,51 @@ fn natura_animation_system(
         &NaturaTarget,
         &mut Transform,
         &mut AnimationState,
-        Option<&EasingCurve>,
         Option<&AnimationGroup>,
         Option<&AnimationPaused>,
     )>,
 ) {
-    // Check for global pause
-    if global_pause.is_some() {
-        return;
-    }
-
-    let delta_seconds = time.delta_secs_f64();
-    
-    // Skip if delta is too small or too large (e.g., during pause or lag spikes)
-    if delta_seconds < 0.0001 || delta_seconds > 0.1 {
+    if clock.is_idle() {
         return;
     }
 
-    for (entity, mut sprite, mut spring, target, mut transform, mut state, easing, group, paused) in query.iter_mut() {
-        // Skip if individually paused
-        if paused.is_some() {
+    for (entity, mut sprite, mut spring, target, mut transform, mut state, group, paused) in query.iter_mut() {
+        // Skip if individually paused or if the group is paused
+        if is_entity_paused(paused, group, paused_groups.as_deref()) {
             continue;
         }
 
-        // Skip if group is paused
-        if let (Some(group), Some(paused_groups)) = (group, &paused_groups) {
-            if paused_groups.is_paused(group.0) {
-                continue;
-            }
-        }
-
-        // Calculate distance to target before update
         let prev_at_rest = sprite.is_at_rest(REST_VELOCITY_THRESHOLD);
-        let prev_distance = ((sprite.x - target.x).powi(2) 
-            + (sprite.y - target.y).powi(2) 
-            + (sprite.z - target.z).powi(2)).sqrt();
-
-        // Get easing curve (default to None if not present)
-        let easing_curve = easing.copied().unwrap_or(EasingCurve::None);
-
-        // Update X position with spring physics
-        let (new_x, new_x_vel) = spring.update(sprite.x, sprite.x_velocity, target.x, delta_seconds);
-        sprite.x = new_x;
-        sprite.x_velocity = new_x_vel;
-
-        // Update Y position with spring physics
-        let (new_y, new_y_vel) = spring.update(sprite.y, sprite.y_velocity, target.y, delta_seconds);
-        sprite.y = new_y;
-        sprite.y_velocity = new_y_vel;
-
-        // Update Z position with spring physics
-        let (new_z, new_z_vel) = spring.update(sprite.z, sprite.z_velocity, target.z, delta_seconds);
-        sprite.z = new_z;
-        sprite.z_velocity = new_z_vel;
-
-        // Apply easing curve if present (modifies the interpolation towards target)
-        if easing_curve != EasingCurve::None {
-            // Calculate progress based on distance to target
-            let current_distance = ((sprite.x - target.x).powi(2) 
-                + (sprite.y - target.y).powi(2) 
-                + (sprite.z - target.z).powi(2)).sqrt();
-            
-            if prev_distance > TARGET_POSITION_THRESHOLD {
-                let raw_progress = 1.0 - (current_distance / prev_distance).min(1.0);
-                let eased_progress = easing_curve.apply(raw_progress);
-                
-                // Blend the spring result with eased interpolation
-                let blend_factor = 0.3; // How much easing affects the spring
-                let eased_x = sprite.x + (target.x - sprite.x) * eased_progress * blend_factor;
-                let eased_y = sprite.y + (target.y - sprite.y) * eased_progress * blend_factor;
-                let eased_z = sprite.z + (target.z - sprite.z) * eased_progress * blend_factor;
-                
-                sprite.x = sprite.x * (1.0 - blend_factor) + eased_x * blend_factor;
-                sprite.y = sprite.y * (1.0 - blend_factor) + eased_y * blend_factor;
-                sprite.z = sprite.z * (1.0 - blend_factor) + eased_z * blend_factor;
-            }
+
+        for _ in 0..clock.steps {
+            // Update all axes with spring physics
+            let (new_pos, new_vel) = spring.update_value(
+                [sprite.x, sprite.y, sprite.z],
+                [sprite.x_velocity, sprite.y_velocity, sprite.z_velocity],
+                [target.x, target.y, target.z],
+                clock.step,
+            );
+            [sprite.x, sprite.y, sprite.z] = new_pos;
+            [sprite.x_velocity, sprite.y_velocity, sprite.z_velocity] = new_vel;
         }
 
-        // Apply the animated position to the transform
-        transform.translation.x = sprite.x as f32;
-        transform.translation.y = sprite.y as f32;
-        transform.translation.z = sprite.z as f32;
+        // Apply the animated position to the transform, evaluating the spring
+        // at the frame time when it lies between two fixed steps
+        let [x, y, z] = if clock.remainder > 0.0 {
+            spring.update_value(
+                [sprite.x, sprite.y, sprite.z],
+                [sprite.x_velocity, sprite.y_velocity, sprite.z_velocity],
+                [target.x, target.y, target.z],
+                clock.remainder,
+            ).0
+        } else {
+            [sprite.x, sprite.y, sprite.z]
+        };
+        transform.translation = Vec3::new(x, y, z);
+
+        if clock.steps == 0 {
+            continue;
+        }
 
         // Check if animation just started
         let now_at_rest = sprite.is_at_rest(REST_VELOCITY_THRESHOLD);
@@ -586,625 +1007,3733 @@ fn natura_animation_system(
             && ((sprite.z - target.z).abs() < TARGET_POSITION_THRESHOLD);
 
         // State machine for animation events
-        match *state {
-            AnimationState::Idle => {
-                if !now_at_rest && !at_target {
-                    *state = AnimationState::Animating;
-                    ev_started.send(AnimationStarted {
-                        entity,
-                        target: Vec3::new(target.x as f32, target.y as f32, target.z as f32),
-                    });
-                }
-            }
-            AnimationState::Animating => {
-                if now_at_rest && at_target {
-                    *state = AnimationState::JustCompleted;
-                    ev_completed.send(AnimationCompleted {
-                        entity,
-                        final_position: Vec3::new(sprite.x as f32, sprite.y as f32, sprite.z as f32),
-                    });
-                }
+        match state.advance(prev_at_rest, now_at_rest, at_target) {
+            Some(AnimationTransition::Started) => {
+                ev_started.send(AnimationStarted {
+                    entity,
+                    target: Vec3::new(target.x, target.y, target.z),
+                });
             }
-            AnimationState::JustCompleted => {
-                // Transition back to Idle after one frame
-                *state = AnimationState::Idle;
+            Some(AnimationTransition::Completed) => {
+                ev_completed.send(AnimationCompleted {
+                    entity,
+                    final_position: Vec3::new(sprite.x, sprite.y, sprite.z),
+                });
             }
+            None => {}
         }
+    }
+}
 
-        // If was at rest and now moving towards a different target, send start event
-        if prev_at_rest && !now_at_rest && *state == AnimationState::Idle {
-            *state = AnimationState::Animating;
-            ev_started.send(AnimationStarted {
-                entity,
-                target: Vec3::new(target.x as f32, target.y as f32, target.z as f32),
-            });
-        }
+// ==================== Rotation ====================
+
+/// Angle in radians below which a rotation has reached its target
+const TARGET_ROTATION_THRESHOLD: f32 = 0.001;
+
+/// Event emitted when an entity starts rotating towards its `NaturaRotationTarget`.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RotationStarted {
+    /// The entity that started rotating
+    pub entity: Entity,
+    /// The target rotation the entity is rotating towards
+    pub target: Quat,
+}
+
+/// Event emitted when a rotation reaches its target and comes to rest.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RotationCompleted {
+    /// The entity that completed its rotation
+    pub entity: Entity,
+    /// The final rotation of the entity
+    pub final_rotation: Quat,
+}
+
+/// Component that specifies the target rotation for spring animation.
+/// The entity's `Transform::rotation` springs towards it along the shortest
+/// arc, so there is no gimbal lock and no unwinding through extra turns.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Transform::default(),
+///     NaturaRotation::new(AngularFrequency(8.0), DampingRatio(0.6)),
+///     NaturaRotationTarget::from_rotation_z(std::f32::consts::FRAC_PI_2),
+/// ));
+/// ```
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(NaturaRotation)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaRotationTarget(pub Quat);
+
+impl NaturaRotationTarget {
+    /// Creates a new target rotation.
+    #[must_use]
+    pub fn new(rotation: Quat) -> Self {
+        NaturaRotationTarget(rotation)
+    }
+
+    /// Creates a new target rotation of `angle` radians around the z axis,
+    /// which is the rotation used in 2D.
+    #[must_use]
+    pub fn from_rotation_z(angle: f32) -> Self {
+        NaturaRotationTarget(Quat::from_rotation_z(angle))
     }
 }
 
-impl Plugin for NaturaAnimationPlugin {
-    fn build(&self, app: &mut App) {
-        app.register_type::<NaturaSprite>()
-            .register_type::<NaturaSpring>()
-            .register_type::<NaturaTarget>()
-            .register_type::<AnimationState>()
-            .register_type::<EasingCurve>()
-            .register_type::<AnimationGroup>()
-            .register_type::<AnimationPaused>()
-            .add_event::<AnimationStarted>()
-            .add_event::<AnimationCompleted>()
-            .init_resource::<PausedGroups>()
-            .add_systems(Update, natura_animation_system);
+/// Component that stores the rotation spring state for an entity with a
+/// `NaturaRotationTarget`. It is added with its default spring when missing.
+///
+/// The angular velocity is a world-space axis scaled by the speed in radians
+/// per second, and is springed per axis like a position.
+#[derive(Component, Default, Debug, Clone, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaRotation {
+    /// Spring the rotation follows, independent of the position spring
+    pub spring: NaturaSpring,
+    /// Angular velocity in radians per second around each world axis
+    pub angular_velocity: Vec3,
+    /// State used to emit `RotationStarted` and `RotationCompleted`
+    pub state: AnimationState,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    between_steps: Option<Quat>,
+}
+
+impl NaturaRotation {
+    /// Creates a new rotation state at rest with the specified spring parameters.
+    #[must_use]
+    pub fn new(angular_frequency: AngularFrequency<f32>, damping_ratio: DampingRatio<f32>) -> Self {
+        NaturaRotation {
+            spring: NaturaSpring::new(angular_frequency, damping_ratio),
+            ..default()
+        }
+    }
+
+    /// Creates a new rotation state at rest with a spring described by any
+    /// type that converts into [`SpringParams`].
+    #[must_use]
+    pub fn with_params(params: impl Into<SpringParams>) -> Self {
+        NaturaRotation {
+            spring: NaturaSpring::with_params(params),
+            ..default()
+        }
+    }
+
+    /// Returns true if the rotation has effectively stopped.
+    #[must_use]
+    pub fn is_at_rest(&self, velocity_threshold: f32) -> bool {
+        self.angular_velocity.length() < velocity_threshold
     }
+
+    /// Advances `rotation` towards `target` by `delta_seconds` and returns the
+    /// new rotation. The offset from the target is taken along the shortest
+    /// arc and springed as a rotation vector together with the angular velocity.
+    pub fn update(&mut self, rotation: Quat, target: Quat, delta_seconds: f32) -> Quat {
+        let (rotation, angular_velocity) = self.evaluate(rotation, target, delta_seconds);
+        self.angular_velocity = angular_velocity;
+        rotation
+    }
+
 }
 
-// Re-export natura types for convenience
-pub use natura::{AngularFrequency, DampingRatio, DeltaTime};
+impl SpringTrack for NaturaRotation {
+    type Value = Quat;
+    type Velocity = Vec3;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn evaluate(&mut self, rotation: Quat, target: Quat, delta_seconds: f32) -> (Quat, Vec3) {
+        let target = target.normalize();
+        let offset = rotation_vector(rotation.normalize() * target.inverse());
 
-    // ==================== NaturaSprite Tests ====================
+        let (offset, velocity) = self.spring.update_value(offset, self.angular_velocity, Vec3::ZERO, delta_seconds);
 
-    #[test]
-    fn test_natura_sprite_new() {
-        let sprite = NaturaSprite::new(10.0, 20.0, 30.0);
-        assert_eq!(sprite.x, 10.0);
-        assert_eq!(sprite.y, 20.0);
-        assert_eq!(sprite.z, 30.0);
-        assert_eq!(sprite.x_velocity, 0.0);
-        assert_eq!(sprite.y_velocity, 0.0);
-        assert_eq!(sprite.z_velocity, 0.0);
+        ((Quat::from_scaled_axis(offset) * target).normalize(), velocity)
     }
 
-    #[test]
-    fn test_natura_sprite_new_2d() {
-        let sprite = NaturaSprite::new_2d(10.0, 20.0);
-        assert_eq!(sprite.x, 10.0);
-        assert_eq!(sprite.y, 20.0);
-        assert_eq!(sprite.z, 0.0);
-        assert_eq!(sprite.x_velocity, 0.0);
-        assert_eq!(sprite.y_velocity, 0.0);
-        assert_eq!(sprite.z_velocity, 0.0);
+    fn set_velocity(&mut self, angular_velocity: Vec3) {
+        self.angular_velocity = angular_velocity;
     }
 
-    #[test]
-    fn test_natura_sprite_default() {
-        let sprite = NaturaSprite::default();
-        assert_eq!(sprite.x, 0.0);
-        assert_eq!(sprite.y, 0.0);
-        assert_eq!(sprite.z, 0.0);
-        assert_eq!(sprite.x_velocity, 0.0);
-        assert_eq!(sprite.y_velocity, 0.0);
-        assert_eq!(sprite.z_velocity, 0.0);
+    fn is_at_rest(&self, velocity_threshold: f32) -> bool {
+        Self::is_at_rest(self, velocity_threshold)
     }
 
-    #[test]
-    fn test_natura_sprite_is_at_rest_true() {
-        let sprite = NaturaSprite {
-            x: 100.0,
-            y: 100.0,
-            z: 0.0,
-            x_velocity: 0.001,
-            y_velocity: 0.001,
-            z_velocity: 0.001,
-        };
-        assert!(sprite.is_at_rest(0.01));
+    fn is_at_target(rotation: Quat, target: Quat) -> bool {
+        rotation_vector(rotation * target.inverse()).length() < TARGET_ROTATION_THRESHOLD
     }
 
-    #[test]
-    fn test_natura_sprite_is_at_rest_false() {
-        let sprite = NaturaSprite {
-            x: 100.0,
-            y: 100.0,
-            z: 0.0,
-            x_velocity: 1.0,
-            y_velocity: 0.0,
-            z_velocity: 0.0,
-        };
-        assert!(!sprite.is_at_rest(0.01));
+    fn state(&mut self) -> &mut AnimationState {
+        &mut self.state
     }
 
-    #[test]
-    fn test_natura_sprite_is_at_rest_all_axes() {
-        // X velocity too high
+    fn between_steps(&mut self) -> &mut Option<Quat> {
+        &mut self.between_steps
+    }
+}
+
+/// Returns the rotation vector (axis scaled by angle) of the shortest arc
+/// that performs `rotation`.
+fn rotation_vector(rotation: Quat) -> Vec3 {
+    // q and -q are the same rotation; the one with w >= 0 turns at most half a turn
+    let rotation = if rotation.w < 0.0 { -rotation } else { rotation };
+    rotation.to_scaled_axis()
+}
+
+/// System that springs the rotation of every entity with a
+/// `NaturaRotationTarget` towards its target, and emits `RotationStarted`
+/// and `RotationCompleted`.
+#[allow(clippy::type_complexity)]
+fn natura_rotation_system(
+    clock: Res<NaturaClock>,
+    paused_groups: Option<Res<PausedGroups>>,
+    mut ev_started: EventWriter<RotationStarted>,
+    mut ev_completed: EventWriter<RotationCompleted>,
+    mut query: Query<(
+        Entity,
+        &NaturaRotationTarget,
+        &mut NaturaRotation,
+        &mut Transform,
+        Option<&AnimationGroup>,
+        Option<&AnimationPaused>,
+    )>,
+) {
+    if clock.is_idle() {
+        return;
+    }
+
+    for (entity, target, mut rotation, mut transform, group, paused) in query.iter_mut() {
+        if is_entity_paused(paused, group, paused_groups.as_deref()) {
+            continue;
+        }
+
+        let frame = step_spring_track(&mut *rotation, transform.rotation, target.0, &clock);
+        transform.rotation = frame.rendered;
+
+        match frame.transition {
+            Some(AnimationTransition::Started) => {
+                ev_started.send(RotationStarted { entity, target: target.0 });
+            }
+            Some(AnimationTransition::Completed) => {
+                ev_completed.send(RotationCompleted { entity, final_rotation: frame.value });
+            }
+            None => {}
+        }
+    }
+}
+
+// ==================== Scale ====================
+
+/// Distance below which a scale has reached its target
+const TARGET_SCALE_THRESHOLD: f32 = 0.001;
+
+/// Component that specifies the target scale for spring animation, for
+/// effects like pop-in (spawn with a zero scale) and squash-and-stretch
+/// (give `NaturaScale` a velocity). Each axis springs independently.
+///
+/// `AnimationStarted` and `AnimationCompleted` are sent for scale animations
+/// too, with the target and final scale as their vectors.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Transform::from_scale(Vec3::ZERO),
+///     NaturaScale::new(AngularFrequency(10.0), DampingRatio(0.4)),
+///     NaturaScaleTarget::uniform(1.0),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(NaturaScale)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaScaleTarget(pub Vec3);
+
+impl Default for NaturaScaleTarget {
+    fn default() -> Self {
+        NaturaScaleTarget(Vec3::ONE)
+    }
+}
+
+impl NaturaScaleTarget {
+    /// Creates a new per-axis target scale.
+    #[must_use]
+    pub fn new(scale: Vec3) -> Self {
+        NaturaScaleTarget(scale)
+    }
+
+    /// Creates a new target scale that is the same on every axis.
+    #[must_use]
+    pub fn uniform(scale: f32) -> Self {
+        NaturaScaleTarget(Vec3::splat(scale))
+    }
+
+    /// Creates a new 2D target scale (z = 1).
+    #[must_use]
+    pub fn new_2d(x: f32, y: f32) -> Self {
+        NaturaScaleTarget(Vec3::new(x, y, 1.0))
+    }
+}
+
+/// Component that stores the scale spring state for an entity with a
+/// `NaturaScaleTarget`. It is added with its default spring when missing.
+#[derive(Component, Default, Debug, Clone, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaScale {
+    /// Spring the scale follows, independent of the position spring
+    pub spring: NaturaSpring,
+    /// Rate of change of the scale per second on each axis
+    pub velocity: Vec3,
+    /// State used to emit `AnimationStarted` and `AnimationCompleted`
+    pub state: AnimationState,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    between_steps: Option<Vec3>,
+}
+
+impl NaturaScale {
+    /// Creates a new scale state at rest with the specified spring parameters.
+    #[must_use]
+    pub fn new(angular_frequency: AngularFrequency<f32>, damping_ratio: DampingRatio<f32>) -> Self {
+        NaturaScale {
+            spring: NaturaSpring::new(angular_frequency, damping_ratio),
+            ..default()
+        }
+    }
+
+    /// Creates a new scale state at rest with a spring described by any
+    /// type that converts into [`SpringParams`].
+    #[must_use]
+    pub fn with_params(params: impl Into<SpringParams>) -> Self {
+        NaturaScale {
+            spring: NaturaSpring::with_params(params),
+            ..default()
+        }
+    }
+
+    /// Returns true if the scale has effectively stopped changing.
+    #[must_use]
+    pub fn is_at_rest(&self, velocity_threshold: f32) -> bool {
+        self.velocity.abs().max_element() < velocity_threshold
+    }
+
+    /// Advances `scale` towards `target` by `delta_seconds` and returns the
+    /// new scale.
+    pub fn update(&mut self, scale: Vec3, target: Vec3, delta_seconds: f32) -> Vec3 {
+        let (scale, velocity) = self.evaluate(scale, target, delta_seconds);
+        self.velocity = velocity;
+        scale
+    }
+
+}
+
+impl SpringTrack for NaturaScale {
+    type Value = Vec3;
+    type Velocity = Vec3;
+
+    fn evaluate(&mut self, scale: Vec3, target: Vec3, delta_seconds: f32) -> (Vec3, Vec3) {
+        self.spring.update_value(scale, self.velocity, target, delta_seconds)
+    }
+
+    fn set_velocity(&mut self, velocity: Vec3) {
+        self.velocity = velocity;
+    }
+
+    fn is_at_rest(&self, velocity_threshold: f32) -> bool {
+        Self::is_at_rest(self, velocity_threshold)
+    }
+
+    fn is_at_target(scale: Vec3, target: Vec3) -> bool {
+        (scale - target).abs().max_element() < TARGET_SCALE_THRESHOLD
+    }
+
+    fn state(&mut self) -> &mut AnimationState {
+        &mut self.state
+    }
+
+    fn between_steps(&mut self) -> &mut Option<Vec3> {
+        &mut self.between_steps
+    }
+}
+
+/// System that springs the scale of every entity with a `NaturaScaleTarget`
+/// towards its target, and emits `AnimationStarted` and `AnimationCompleted`.
+#[allow(clippy::type_complexity)]
+fn natura_scale_system(
+    clock: Res<NaturaClock>,
+    paused_groups: Option<Res<PausedGroups>>,
+    mut ev_started: EventWriter<AnimationStarted>,
+    mut ev_completed: EventWriter<AnimationCompleted>,
+    mut query: Query<(
+        Entity,
+        &NaturaScaleTarget,
+        &mut NaturaScale,
+        &mut Transform,
+        Option<&AnimationGroup>,
+        Option<&AnimationPaused>,
+    )>,
+) {
+    if clock.is_idle() {
+        return;
+    }
+
+    for (entity, target, mut scale, mut transform, group, paused) in query.iter_mut() {
+        if is_entity_paused(paused, group, paused_groups.as_deref()) {
+            continue;
+        }
+
+        let frame = step_spring_track(&mut *scale, transform.scale, target.0, &clock);
+        transform.scale = frame.rendered;
+
+        match frame.transition {
+            Some(AnimationTransition::Started) => {
+                ev_started.send(AnimationStarted { entity, target: target.0 });
+            }
+            Some(AnimationTransition::Completed) => {
+                ev_completed.send(AnimationCompleted { entity, final_position: frame.value });
+            }
+            None => {}
+        }
+    }
+}
+
+// ==================== Lenses ====================
+
+/// Distance below which a lens value has reached its target
+const TARGET_LENS_THRESHOLD: f32 = 0.001;
+
+/// Value of a field animated by a `NaturaLens`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LensValue {
+    F32(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    /// Colors are springed in linear RGBA and written back in the color
+    /// space of the field
+    Color(Color),
+}
+
+impl LensValue {
+    /// Returns the components of the value as springed, padded with zeros.
+    fn to_array(self) -> [f32; 4] {
+        match self {
+            LensValue::F32(value) => [value, 0.0, 0.0, 0.0],
+            LensValue::Vec2(value) => value.extend(0.0).extend(0.0).to_array(),
+            LensValue::Vec3(value) => value.extend(0.0).to_array(),
+            LensValue::Color(value) => value.to_linear().to_f32_array(),
+        }
+    }
+
+    /// Returns a value of the same kind as `self` from springed components.
+    fn with_array(self, [x, y, z, w]: [f32; 4]) -> Self {
+        match self {
+            LensValue::F32(_) => LensValue::F32(x),
+            LensValue::Vec2(_) => LensValue::Vec2(Vec2::new(x, y)),
+            LensValue::Vec3(_) => LensValue::Vec3(Vec3::new(x, y, z)),
+            LensValue::Color(color) => LensValue::Color(with_color_space(color, LinearRgba::new(x, y, z, w))),
+        }
+    }
+
+    /// Returns the first three components, as reported in animation events.
+    fn to_vec3(self) -> Vec3 {
+        let [x, y, z, _] = self.to_array();
+        Vec3::new(x, y, z)
+    }
+
+    /// Reads a value of the same kind as `self` from a reflected field.
+    fn read(self, field: &dyn PartialReflect) -> Option<Self> {
+        Some(match self {
+            LensValue::F32(_) => LensValue::F32(*field.try_downcast_ref::<f32>()?),
+            LensValue::Vec2(_) => LensValue::Vec2(*field.try_downcast_ref::<Vec2>()?),
+            LensValue::Vec3(_) => LensValue::Vec3(*field.try_downcast_ref::<Vec3>()?),
+            LensValue::Color(_) => LensValue::Color(*field.try_downcast_ref::<Color>()?),
+        })
+    }
+
+    /// Writes the value to a reflected field of the same type.
+    fn write(self, field: &mut dyn PartialReflect) {
+        match self {
+            LensValue::F32(value) => field.try_downcast_mut::<f32>().map(|field| *field = value),
+            LensValue::Vec2(value) => field.try_downcast_mut::<Vec2>().map(|field| *field = value),
+            LensValue::Vec3(value) => field.try_downcast_mut::<Vec3>().map(|field| *field = value),
+            LensValue::Color(value) => field.try_downcast_mut::<Color>().map(|field| *field = value),
+        };
+    }
+}
+
+impl From<f32> for LensValue {
+    fn from(value: f32) -> Self {
+        LensValue::F32(value)
+    }
+}
+
+impl From<Vec2> for LensValue {
+    fn from(value: Vec2) -> Self {
+        LensValue::Vec2(value)
+    }
+}
+
+impl From<Vec3> for LensValue {
+    fn from(value: Vec3) -> Self {
+        LensValue::Vec3(value)
+    }
+}
+
+impl From<Color> for LensValue {
+    fn from(value: Color) -> Self {
+        LensValue::Color(value)
+    }
+}
+
+/// Converts `color` to the color space of `original`.
+fn with_color_space(original: Color, color: LinearRgba) -> Color {
+    match original {
+        Color::Srgba(_) => Color::Srgba(color.into()),
+        Color::LinearRgba(_) => Color::LinearRgba(color),
+        Color::Hsla(_) => Color::Hsla(color.into()),
+        Color::Hsva(_) => Color::Hsva(color.into()),
+        Color::Hwba(_) => Color::Hwba(color.into()),
+        Color::Laba(_) => Color::Laba(color.into()),
+        Color::Lcha(_) => Color::Lcha(color.into()),
+        Color::Oklaba(_) => Color::Oklaba(color.into()),
+        Color::Oklcha(_) => Color::Oklcha(color.into()),
+        Color::Xyza(_) => Color::Xyza(color.into()),
+    }
+}
+
+/// Returns the component of type `C` as a reflected value.
+type LensComponent = for<'a> fn(&'a mut EntityMutExcept<'_, NaturaLens>) -> Option<Mut<'a, dyn Reflect>>;
+
+/// Component that springs a field of another component on the same entity
+/// towards a target, addressed by its reflection path. Any `f32`, `Vec2`,
+/// `Vec3` or `Color` field can be animated: sprite and text colors, UI node
+/// sizes, font sizes, camera zoom, audio volume...
+///
+/// `AnimationStarted` and `AnimationCompleted` are sent for lenses too, with
+/// the first three components of the target and final values as vectors.
+/// If the component is missing or the path doesn't lead to a field of the
+/// target's type, the lens does nothing.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Sprite::from_color(Color::WHITE, Vec2::splat(50.0)),
+///     NaturaLens::new::<Sprite>("color", Color::srgb(1.0, 0.2, 0.2)),
+/// ));
+/// commands.spawn((
+///     Text::new("Hello"),
+///     TextFont::default(),
+///     NaturaLens::new::<TextFont>("font_size", 48.0)
+///         .with_spring(NaturaSpring::new(AngularFrequency(10.0), DampingRatio(0.5))),
+/// ));
+/// ```
+#[derive(Component, Clone)]
+pub struct NaturaLens {
+    /// The value the field springs towards
+    pub target: LensValue,
+    /// Spring the field follows
+    pub spring: NaturaSpring,
+    /// Rate of change of each component of the field per second
+    pub velocity: [f32; 4],
+    /// State used to emit `AnimationStarted` and `AnimationCompleted`
+    pub state: AnimationState,
+    path: ParsedPath,
+    component: LensComponent,
+    between_steps: Option<LensValue>,
+}
+
+impl std::fmt::Debug for NaturaLens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NaturaLens")
+            .field("target", &self.target)
+            .field("spring", &self.spring)
+            .field("velocity", &self.velocity)
+            .field("state", &self.state)
+            .field("path", &self.path)
+            .field("between_steps", &self.between_steps)
+            .finish()
+    }
+}
+
+impl NaturaLens {
+    /// Creates a new lens that springs the field at `path` of the entity's
+    /// `C` component towards `target`, with the default spring.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` is not a valid reflection path.
+    #[must_use]
+    pub fn new<C: Component + Reflect>(path: &str, target: impl Into<LensValue>) -> Self {
+        let path = ParsedPath::parse(path).unwrap_or_else(|error| panic!("invalid reflect path {path:?}: {error}"));
+
+        NaturaLens {
+            target: target.into(),
+            spring: NaturaSpring::default(),
+            velocity: [0.0; 4],
+            state: AnimationState::default(),
+            path,
+            component: |entity| {
+                entity
+                    .get_mut::<C>()
+                    .map(|component| component.map_unchanged(|component| component as &mut dyn Reflect))
+            },
+            between_steps: None,
+        }
+    }
+
+    /// Sets the spring the field follows.
+    #[must_use]
+    pub fn with_spring(mut self, spring: NaturaSpring) -> Self {
+        self.spring = spring;
+        self
+    }
+
+    /// Returns the reflection path of the animated field.
+    #[must_use]
+    pub fn path(&self) -> &ParsedPath {
+        &self.path
+    }
+
+    /// Returns true if the field has effectively stopped changing.
+    #[must_use]
+    pub fn is_at_rest(&self, velocity_threshold: f32) -> bool {
+        self.velocity.iter().all(|velocity| velocity.abs() < velocity_threshold)
+    }
+
+    /// Advances `value` towards the target by `delta_seconds` and returns
+    /// the new value.
+    pub fn update(&mut self, value: LensValue, delta_seconds: f32) -> LensValue {
+        let (value, velocity) = self.evaluate(value, self.target, delta_seconds);
+        self.velocity = velocity;
+        value
+    }
+}
+
+impl SpringTrack for NaturaLens {
+    type Value = LensValue;
+    type Velocity = [f32; 4];
+
+    fn evaluate(&mut self, value: LensValue, target: LensValue, delta_seconds: f32) -> (LensValue, [f32; 4]) {
+        let (position, velocity) =
+            self.spring
+                .update_value(value.to_array(), self.velocity, target.to_array(), delta_seconds);
+        (value.with_array(position), velocity)
+    }
+
+    fn set_velocity(&mut self, velocity: [f32; 4]) {
+        self.velocity = velocity;
+    }
+
+    fn is_at_rest(&self, velocity_threshold: f32) -> bool {
+        Self::is_at_rest(self, velocity_threshold)
+    }
+
+    fn is_at_target(value: LensValue, target: LensValue) -> bool {
+        value
+            .to_array()
+            .iter()
+            .zip(target.to_array())
+            .all(|(value, target)| (value - target).abs() < TARGET_LENS_THRESHOLD)
+    }
+
+    fn state(&mut self) -> &mut AnimationState {
+        &mut self.state
+    }
+
+    fn between_steps(&mut self) -> &mut Option<LensValue> {
+        &mut self.between_steps
+    }
+}
+
+/// System that springs the field of every `NaturaLens` towards its target,
+/// and emits `AnimationStarted` and `AnimationCompleted`.
+#[allow(clippy::type_complexity)]
+fn natura_lens_system(
+    clock: Res<NaturaClock>,
+    paused_groups: Option<Res<PausedGroups>>,
+    mut ev_started: EventWriter<AnimationStarted>,
+    mut ev_completed: EventWriter<AnimationCompleted>,
+    mut query: Query<(&mut NaturaLens, EntityMutExcept<NaturaLens>)>,
+) {
+    if clock.is_idle() {
+        return;
+    }
+
+    for (mut lens, mut entity) in query.iter_mut() {
+        let entity_id = entity.id();
+        if is_entity_paused(entity.get::<AnimationPaused>(), entity.get::<AnimationGroup>(), paused_groups.as_deref()) {
+            continue;
+        }
+
+        let lens = &mut *lens;
+        let Some(mut component) = (lens.component)(&mut entity) else {
+            continue;
+        };
+        let Some(value) = component
+            .bypass_change_detection()
+            .reflect_path_mut(&lens.path)
+            .ok()
+            .and_then(|field| lens.target.read(field))
+        else {
+            continue;
+        };
+
+        let target = lens.target;
+        let frame = step_spring_track(lens, value, target, &clock);
+        // Written without change detection, so a lens resting on its target
+        // doesn't mark the component changed (and relayout it) every frame.
+        if frame.rendered != value {
+            if let Ok(field) = component.bypass_change_detection().reflect_path_mut(&lens.path) {
+                frame.rendered.write(field);
+                component.set_changed();
+            }
+        }
+
+        match frame.transition {
+            Some(AnimationTransition::Started) => {
+                ev_started.send(AnimationStarted { entity: entity_id, target: target.to_vec3() });
+            }
+            Some(AnimationTransition::Completed) => {
+                ev_completed.send(AnimationCompleted { entity: entity_id, final_position: frame.value.to_vec3() });
+            }
+            None => {}
+        }
+    }
+}
+
+// ==================== Tweens ====================
+
+/// Component that animates `Transform::translation` from `from` to `to`
+/// over a fixed duration, shaped by an `EasingCurve` evaluated over the
+/// normalized time. It is the duration-based alternative to spring animation
+/// with `NaturaTarget`; use one or the other on an entity.
+///
+/// During the delay the entity is held at `from`. `AnimationStarted` is sent
+/// when the delay has passed and `AnimationCompleted` when the tween ends;
+/// pausing and groups work as for springs.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Transform::default(),
+///     NaturaTween::new(Vec3::ZERO, Vec3::new(200.0, 0.0, 0.0), 0.5)
+///         .with_easing(EasingCurve::CubicOut)
+///         .with_delay(0.2),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaTween {
+    /// Position at the start of the tween
+    pub from: Vec3,
+    /// Position at the end of the tween
+    pub to: Vec3,
+    /// Duration of the motion in seconds, after the delay
+    pub duration: f64,
+    /// Seconds to wait before the motion starts
+    pub delay: f64,
+    /// Curve that shapes the motion over the normalized time
+    pub easing: EasingCurve,
+    /// Seconds since the tween was started, including the delay
+    pub elapsed: f64,
+    /// State used to emit `AnimationStarted` and `AnimationCompleted`
+    pub state: AnimationState,
+    /// Whether `AnimationCompleted` was sent; finished tweens are not updated
+    /// until `restart` clears it
+    pub completed: bool,
+}
+
+impl NaturaTween {
+    /// Creates a new linear tween without delay.
+    #[must_use]
+    pub fn new(from: Vec3, to: Vec3, duration: f64) -> Self {
+        NaturaTween {
+            from,
+            to,
+            duration,
+            delay: 0.0,
+            easing: EasingCurve::None,
+            elapsed: 0.0,
+            state: AnimationState::default(),
+            completed: false,
+        }
+    }
+
+    /// Sets the easing curve of the tween.
+    #[must_use]
+    pub fn with_easing(mut self, easing: EasingCurve) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the delay before the tween starts, in seconds.
+    #[must_use]
+    pub fn with_delay(mut self, delay: f64) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the normalized time of the tween (0.0 to 1.0), before easing.
+    #[must_use]
+    pub fn progress(&self) -> f64 {
+        let time = self.elapsed - self.delay;
+        if self.duration <= 0.0 {
+            if time >= 0.0 { 1.0 } else { 0.0 }
+        } else {
+            (time / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Returns the current position of the tween.
+    #[must_use]
+    pub fn value(&self) -> Vec3 {
+        let eased = self.easing.apply(self.progress());
+        self.from.as_dvec3().lerp(self.to.as_dvec3(), eased).as_vec3()
+    }
+
+    /// Returns true once the tween has reached `to`.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.delay + self.duration.max(0.0)
+    }
+
+    /// Starts the tween over, including the delay.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+        self.state = AnimationState::Idle;
+        self.completed = false;
+    }
+}
+
+/// System that advances every `NaturaTween` by the frame's delta time, and
+/// emits `AnimationStarted` and `AnimationCompleted`.
+#[allow(clippy::type_complexity)]
+fn natura_tween_system(
+    time: Res<Time>,
+    global_pause: Option<Res<GlobalAnimationPaused>>,
+    paused_groups: Option<Res<PausedGroups>>,
+    mut ev_started: EventWriter<AnimationStarted>,
+    mut ev_completed: EventWriter<AnimationCompleted>,
+    mut query: Query<(
+        Entity,
+        &mut NaturaTween,
+        &mut Transform,
+        Option<&AnimationGroup>,
+        Option<&AnimationPaused>,
+    )>,
+) {
+    if global_pause.is_some() {
+        return;
+    }
+
+    let delta_seconds = time.delta_secs_f64();
+    for (entity, mut tween, mut transform, group, paused) in query.iter_mut() {
+        if is_entity_paused(paused, group, paused_groups.as_deref()) {
+            continue;
+        }
+
+        // Transition back to Idle one frame after completing
+        if tween.state == AnimationState::JustCompleted {
+            tween.state = AnimationState::Idle;
+        }
+        // Checked instead of `is_finished`, so that a tween without duration
+        // still jumps to `to` and sends its events on the first frame.
+        if tween.completed {
+            continue;
+        }
+
+        tween.elapsed += delta_seconds;
+        transform.translation = tween.value();
+        if tween.elapsed < tween.delay {
+            continue;
+        }
+
+        if tween.state == AnimationState::Idle {
+            tween.state = AnimationState::Animating;
+            ev_started.send(AnimationStarted { entity, target: tween.to });
+        }
+        if tween.is_finished() {
+            tween.state = AnimationState::JustCompleted;
+            tween.completed = true;
+            ev_completed.send(AnimationCompleted { entity, final_position: transform.translation });
+        }
+    }
+}
+
+// ==================== Timelines ====================
+
+/// Event emitted when a `NaturaTimeline` has played all of its repeats.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimelineCompleted {
+    /// The entity whose timeline completed
+    pub entity: Entity,
+}
+
+/// Property of an entity that a `Keyframe` springs towards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyframeTarget {
+    /// Sets the `NaturaTarget`. The entity needs the components of a
+    /// `NaturaSpringBundle` to move.
+    Translation(Vec3),
+    /// Sets the `NaturaRotationTarget`
+    Rotation(Quat),
+    /// Sets the `NaturaScaleTarget`
+    Scale(Vec3),
+}
+
+/// Waypoint of a `NaturaTimeline`: a target to spring towards, optionally
+/// with its own spring parameters, and a time to hold once the spring has
+/// come to rest there. Translations can instead be eased to the target over
+/// a duration, like a `NaturaTween` from wherever the entity is.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keyframe {
+    /// Target of the keyframe
+    pub target: KeyframeTarget,
+    /// Spring parameters to switch the target's spring to, if any
+    pub spring: Option<SpringParams>,
+    /// Easing curve to tween a translation with instead of springing, if any
+    pub easing: Option<EasingCurve>,
+    /// Duration of the eased motion in seconds, if any. Defaults to the time
+    /// the spring takes to settle.
+    pub duration: Option<f64>,
+    /// Seconds to hold at the target before the keyframe finishes
+    pub hold: f64,
+}
+
+impl Keyframe {
+    /// Creates a new keyframe towards the given target.
+    #[must_use]
+    pub fn new(target: KeyframeTarget) -> Self {
+        Keyframe {
+            target,
+            spring: None,
+            easing: None,
+            duration: None,
+            hold: 0.0,
+        }
+    }
+
+    /// Creates a new keyframe that moves the entity to `translation`.
+    #[must_use]
+    pub fn translation(translation: Vec3) -> Self {
+        Self::new(KeyframeTarget::Translation(translation))
+    }
+
+    /// Creates a new keyframe that rotates the entity to `rotation`.
+    #[must_use]
+    pub fn rotation(rotation: Quat) -> Self {
+        Self::new(KeyframeTarget::Rotation(rotation))
+    }
+
+    /// Creates a new keyframe that scales the entity to `scale`.
+    #[must_use]
+    pub fn scale(scale: Vec3) -> Self {
+        Self::new(KeyframeTarget::Scale(scale))
+    }
+
+    /// Sets the spring parameters used from this keyframe on.
+    #[must_use]
+    pub fn with_spring(mut self, params: impl Into<SpringParams>) -> Self {
+        self.spring = Some(params.into());
+        self
+    }
+
+    /// Eases the translation to the target with `easing` instead of springing
+    /// there. The motion lasts the keyframe's duration, or as long as the
+    /// spring takes to settle. Only applies to translations.
+    #[must_use]
+    pub fn with_easing(mut self, easing: EasingCurve) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Sets the duration of the eased motion, in seconds.
+    #[must_use]
+    pub fn with_duration(mut self, duration: f64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the time to hold at the target, in seconds.
+    #[must_use]
+    pub fn with_hold(mut self, hold: f64) -> Self {
+        self.hold = hold;
+        self
+    }
+}
+
+/// Step of a `NaturaTimeline`, composed from keyframes.
+///
+/// Steps that run at the same time should animate different properties
+/// (translation, rotation or scale); a keyframe whose target is replaced by
+/// another step, or by other code, counts as finished.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimelineStep {
+    /// Springs to a target and holds there
+    Keyframe(Keyframe),
+    /// Runs the steps one after the other
+    Sequence(Vec<TimelineStep>),
+    /// Runs the steps at the same time, finishing when all have finished
+    Parallel(Vec<TimelineStep>),
+    /// Starts the steps `interval` seconds apart, finishing when all have
+    /// finished
+    Stagger {
+        /// Seconds between the starts of consecutive steps
+        interval: f64,
+        /// The staggered steps
+        steps: Vec<TimelineStep>,
+    },
+}
+
+impl TimelineStep {
+    /// Creates a step that runs `steps` one after the other.
+    #[must_use]
+    pub fn sequence(steps: impl IntoIterator<Item = impl Into<TimelineStep>>) -> Self {
+        TimelineStep::Sequence(steps.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a step that runs `steps` at the same time.
+    #[must_use]
+    pub fn parallel(steps: impl IntoIterator<Item = impl Into<TimelineStep>>) -> Self {
+        TimelineStep::Parallel(steps.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a step that starts `steps` `interval` seconds apart.
+    #[must_use]
+    pub fn stagger(interval: f64, steps: impl IntoIterator<Item = impl Into<TimelineStep>>) -> Self {
+        TimelineStep::Stagger {
+            interval,
+            steps: steps.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<Keyframe> for TimelineStep {
+    fn from(keyframe: Keyframe) -> Self {
+        TimelineStep::Keyframe(keyframe)
+    }
+}
+
+/// How many times a `NaturaTimeline` plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimelineRepeat {
+    /// Plays the given number of times
+    Times(u32),
+    /// Plays until the component is removed
+    Forever,
+}
+
+impl Default for TimelineRepeat {
+    fn default() -> Self {
+        TimelineRepeat::Times(1)
+    }
+}
+
+/// Progress of a `TimelineStep` while a timeline plays.
+#[derive(Debug, Clone, Default, PartialEq)]
+enum StepProgress {
+    /// Not started yet
+    #[default]
+    Pending,
+    /// Springing towards the keyframe's target
+    Moving,
+    /// Easing towards the keyframe's translation
+    Tweening(NaturaTween),
+    /// Holding at the keyframe's target for the given seconds so far
+    Holding(f64),
+    /// Running the step at `index` of a sequence
+    Sequence { index: usize, current: Box<StepProgress> },
+    /// Running the steps of a parallel or staggered group, `elapsed` seconds
+    /// after it started
+    Group { elapsed: f64, steps: Vec<StepProgress> },
+    /// Finished
+    Done,
+}
+
+/// Component that springs an entity through a timeline of keyframes,
+/// replacing systems that chain targets on `AnimationCompleted`.
+///
+/// Each keyframe sets the target of the entity's translation, rotation or
+/// scale spring and finishes once the spring has come to rest there and the
+/// keyframe's hold time has passed; eased translations finish when their
+/// curve ends instead. Keyframes are composed with
+/// `TimelineStep::sequence`, `parallel` and `stagger`, and the timeline can
+/// repeat, loop forever and play back and forth. `TimelineCompleted` is sent
+/// when the last repeat finishes. Pausing and groups work as for springs.
+///
+/// ```rust,ignore
+/// commands.spawn((
+///     Transform::default(),
+///     NaturaSpringBundle::default(),
+///     NaturaTimeline::new(TimelineStep::sequence([
+///         Keyframe::translation(Vec3::new(100.0, 0.0, 0.0)).with_hold(0.5),
+///         Keyframe::translation(Vec3::new(100.0, 100.0, 0.0))
+///             .with_spring(DurationBounce { duration: 0.4, bounce: 0.3 }),
+///     ]))
+///     .ping_pong()
+///     .looping(),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaTimeline {
+    /// The step the timeline plays
+    pub root: TimelineStep,
+    /// How many times the timeline plays
+    pub repeat: TimelineRepeat,
+    /// Whether every other play runs backwards, visiting the keyframes in
+    /// reverse order
+    pub ping_pong: bool,
+    /// Number of completed plays
+    #[cfg_attr(feature = "serde", serde(skip))]
+    iteration: u32,
+    /// Progress of the current play
+    #[cfg_attr(feature = "serde", serde(skip))]
+    progress: StepProgress,
+}
+
+impl NaturaTimeline {
+    /// Creates a new timeline that plays `root` once.
+    #[must_use]
+    pub fn new(root: impl Into<TimelineStep>) -> Self {
+        NaturaTimeline {
+            root: root.into(),
+            repeat: TimelineRepeat::default(),
+            ping_pong: false,
+            iteration: 0,
+            progress: StepProgress::Pending,
+        }
+    }
+
+    /// Creates a new timeline that visits `keyframes` one after the other.
+    #[must_use]
+    pub fn sequence(keyframes: impl IntoIterator<Item = Keyframe>) -> Self {
+        Self::new(TimelineStep::sequence(keyframes))
+    }
+
+    /// Plays the timeline `times` times.
+    #[must_use]
+    pub fn with_repeat(mut self, times: u32) -> Self {
+        self.repeat = TimelineRepeat::Times(times);
+        self
+    }
+
+    /// Plays the timeline until the component is removed.
+    #[must_use]
+    pub fn looping(mut self) -> Self {
+        self.repeat = TimelineRepeat::Forever;
+        self
+    }
+
+    /// Plays every other repeat backwards.
+    #[must_use]
+    pub fn ping_pong(mut self) -> Self {
+        self.ping_pong = true;
+        self
+    }
+
+    /// Returns the number of completed plays.
+    #[must_use]
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    /// Returns true if the current play runs backwards.
+    #[must_use]
+    pub fn is_reversed(&self) -> bool {
+        self.ping_pong && self.iteration % 2 == 1
+    }
+
+    /// Returns true once the timeline has played all of its repeats.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        match self.repeat {
+            TimelineRepeat::Times(times) => self.iteration >= times,
+            TimelineRepeat::Forever => false,
+        }
+    }
+
+    /// Starts the timeline over from its first play.
+    pub fn restart(&mut self) {
+        self.iteration = 0;
+        self.progress = StepProgress::Pending;
+    }
+}
+
+/// State of the entity that a timeline reads and writes as it advances.
+struct TimelineContext<'a> {
+    /// Tells whether the entity has come to rest at a keyframe's target
+    settled: &'a dyn Fn(&KeyframeTarget) -> bool,
+    /// Current translation of the entity
+    translation: Vec3,
+    /// Current `NaturaTarget` of the entity, if any
+    translation_target: Option<Vec3>,
+    /// Parameters of the entity's translation spring
+    spring: SpringParams,
+    /// Keyframes that started
+    started: Vec<Keyframe>,
+    /// Translation an eased keyframe moved the entity to
+    eased_translation: Option<Vec3>,
+}
+
+impl TimelineContext<'_> {
+    /// Moves the entity to `translation` along an eased keyframe.
+    fn ease_to(&mut self, translation: Vec3) {
+        self.translation = translation;
+        self.eased_translation = Some(translation);
+    }
+}
+
+/// Advances `step` by `delta_seconds` and returns true once it has finished.
+fn advance_step(
+    step: &TimelineStep,
+    progress: &mut StepProgress,
+    reversed: bool,
+    delta_seconds: f64,
+    context: &mut TimelineContext,
+) -> bool {
+    match step {
+        TimelineStep::Keyframe(keyframe) => match progress {
+            StepProgress::Pending => {
+                context.started.push(keyframe.clone());
+                *progress = match (keyframe.target, keyframe.easing) {
+                    (KeyframeTarget::Translation(translation), Some(easing)) => {
+                        let duration = keyframe
+                            .duration
+                            .unwrap_or_else(|| SpringEasing::new(keyframe.spring.unwrap_or(context.spring)).duration());
+                        context.ease_to(context.translation);
+                        StepProgress::Tweening(
+                            NaturaTween::new(context.translation, translation, duration).with_easing(easing),
+                        )
+                    }
+                    _ => StepProgress::Moving,
+                };
+                false
+            }
+            StepProgress::Moving => (context.settled)(&keyframe.target) && finish_keyframe(keyframe, progress),
+            StepProgress::Tweening(tween) => {
+                // Another step, or other code, replaced the target
+                if context.translation_target.is_some_and(|target| target != tween.value()) {
+                    *progress = StepProgress::Done;
+                    return true;
+                }
+                tween.elapsed += delta_seconds;
+                if !tween.is_finished() {
+                    context.ease_to(tween.value());
+                    return false;
+                }
+                context.ease_to(tween.to);
+                finish_keyframe(keyframe, progress)
+            }
+            StepProgress::Holding(held) => {
+                *held += delta_seconds;
+                if *held < keyframe.hold {
+                    return false;
+                }
+                *progress = StepProgress::Done;
+                true
+            }
+            _ => true,
+        },
+        TimelineStep::Sequence(steps) => {
+            if *progress == StepProgress::Pending {
+                *progress = StepProgress::Sequence { index: 0, current: Box::default() };
+            }
+            let StepProgress::Sequence { index, current } = progress else {
+                return true;
+            };
+
+            // Steps that start after another one finished this frame get no
+            // time of their own
+            let mut delta_seconds = delta_seconds;
+            while *index < steps.len() {
+                let step = if reversed { &steps[steps.len() - 1 - *index] } else { &steps[*index] };
+                if !advance_step(step, current, reversed, delta_seconds, context) {
+                    return false;
+                }
+                *index += 1;
+                **current = StepProgress::Pending;
+                delta_seconds = 0.0;
+            }
+            *progress = StepProgress::Done;
+            true
+        }
+        TimelineStep::Parallel(steps) => advance_group(steps, 0.0, progress, reversed, delta_seconds, context),
+        TimelineStep::Stagger { interval, steps } => {
+            advance_group(steps, *interval, progress, reversed, delta_seconds, context)
+        }
+    }
+}
+
+/// Finishes a keyframe that has reached its target, holding there first if
+/// it has a hold time. Returns true if the keyframe is done.
+fn finish_keyframe(keyframe: &Keyframe, progress: &mut StepProgress) -> bool {
+    if keyframe.hold > 0.0 {
+        *progress = StepProgress::Holding(0.0);
+        return false;
+    }
+    *progress = StepProgress::Done;
+    true
+}
+
+/// Advances a group of steps that start `interval` seconds apart and returns
+/// true once all of them have finished.
+fn advance_group(
+    steps: &[TimelineStep],
+    interval: f64,
+    progress: &mut StepProgress,
+    reversed: bool,
+    delta_seconds: f64,
+    context: &mut TimelineContext,
+) -> bool {
+    match progress {
+        StepProgress::Pending => {
+            *progress = StepProgress::Group {
+                elapsed: 0.0,
+                steps: vec![StepProgress::Pending; steps.len()],
+            };
+        }
+        StepProgress::Group { elapsed, .. } => *elapsed += delta_seconds,
+        _ => return true,
+    }
+    let StepProgress::Group { elapsed, steps: step_progress } = progress else {
+        return true;
+    };
+
+    let mut finished = true;
+    for (index, (step, progress)) in steps.iter().zip(step_progress.iter_mut()).enumerate() {
+        let order = if reversed { steps.len() - 1 - index } else { index };
+        let start = interval * order as f64;
+        if *elapsed < start {
+            finished = false;
+            continue;
+        }
+        finished &= advance_step(step, progress, reversed, delta_seconds, context);
+    }
+
+    if finished {
+        *progress = StepProgress::Done;
+    }
+    finished
+}
+
+/// Sets `spring` to `params`, keeping its cache if they are unchanged.
+fn set_spring_params(spring: &mut NaturaSpring, params: SpringParams) {
+    let NaturaSpring { angular_frequency, damping_ratio, .. } = NaturaSpring::with_params(params);
+    spring.angular_frequency = angular_frequency;
+    spring.damping_ratio = damping_ratio;
+}
+
+/// System that plays every `NaturaTimeline` by the frame's delta time,
+/// setting the targets and springs of the keyframes that start, moving eased
+/// translations along their curves, and emits `TimelineCompleted`.
+#[allow(clippy::type_complexity)]
+fn natura_timeline_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    global_pause: Option<Res<GlobalAnimationPaused>>,
+    paused_groups: Option<Res<PausedGroups>>,
+    mut ev_completed: EventWriter<TimelineCompleted>,
+    mut query: Query<(
+        Entity,
+        &mut NaturaTimeline,
+        &mut Transform,
+        Option<&mut NaturaSprite>,
+        Option<&mut NaturaTarget>,
+        Option<&mut NaturaSpring>,
+        Option<&mut NaturaRotationTarget>,
+        Option<&mut NaturaRotation>,
+        Option<&mut NaturaScaleTarget>,
+        Option<&mut NaturaScale>,
+        Option<&AnimationGroup>,
+        Option<&AnimationPaused>,
+    )>,
+) {
+    if global_pause.is_some() {
+        return;
+    }
+
+    let delta_seconds = time.delta_secs_f64();
+    for (
+        entity,
+        mut timeline,
+        mut transform,
+        mut sprite,
+        mut target,
+        mut spring,
+        mut rotation_target,
+        mut rotation,
+        mut scale_target,
+        mut scale,
+        group,
+        paused,
+    ) in query.iter_mut()
+    {
+        if is_entity_paused(paused, group, paused_groups.as_deref()) || timeline.is_finished() {
+            continue;
+        }
+
+        let settled = |keyframe_target: &KeyframeTarget| match *keyframe_target {
+            KeyframeTarget::Translation(translation) => {
+                let Some(target) = target.as_deref() else {
+                    return true;
+                };
+                let position = match sprite.as_deref() {
+                    Some(sprite) => Vec3::new(sprite.x, sprite.y, sprite.z),
+                    None => transform.translation,
+                };
+                Vec3::new(target.x, target.y, target.z) != translation
+                    || (position.distance(translation) < TARGET_POSITION_THRESHOLD
+                        && sprite.as_deref().is_none_or(|sprite| sprite.is_at_rest(REST_VELOCITY_THRESHOLD)))
+            }
+            KeyframeTarget::Rotation(goal) => {
+                let (Some(target), Some(rotation)) = (rotation_target.as_deref(), rotation.as_deref()) else {
+                    return true;
+                };
+                let current = rotation.between_steps.unwrap_or(transform.rotation);
+                target.0 != goal
+                    || (rotation_vector(current * goal.inverse()).length() < TARGET_ROTATION_THRESHOLD
+                        && rotation.is_at_rest(REST_VELOCITY_THRESHOLD))
+            }
+            KeyframeTarget::Scale(goal) => {
+                let (Some(target), Some(scale)) = (scale_target.as_deref(), scale.as_deref()) else {
+                    return true;
+                };
+                let current = scale.between_steps.unwrap_or(transform.scale);
+                target.0 != goal
+                    || ((current - goal).abs().max_element() < TARGET_SCALE_THRESHOLD
+                        && scale.is_at_rest(REST_VELOCITY_THRESHOLD))
+            }
+        };
+
+        let mut context = TimelineContext {
+            settled: &settled,
+            translation: match sprite.as_deref() {
+                Some(sprite) => Vec3::new(sprite.x, sprite.y, sprite.z),
+                None => transform.translation,
+            },
+            translation_target: target.as_deref().map(|target| Vec3::new(target.x, target.y, target.z)),
+            spring: spring.as_deref().map_or_else(|| NaturaSpring::default().params(), NaturaSpring::params),
+            started: Vec::new(),
+            eased_translation: None,
+        };
+        let reversed = timeline.is_reversed();
+        let timeline = &mut *timeline;
+        let finished = advance_step(&timeline.root, &mut timeline.progress, reversed, delta_seconds, &mut context);
+        if finished {
+            timeline.iteration += 1;
+            timeline.progress = StepProgress::Pending;
+            if timeline.is_finished() {
+                ev_completed.send(TimelineCompleted { entity });
+            }
+        }
+
+        let TimelineContext { started, eased_translation, .. } = context;
+        for keyframe in started {
+            match keyframe.target {
+                KeyframeTarget::Translation(translation) => {
+                    // Eased translations are moved below
+                    if keyframe.easing.is_none() {
+                        let new_target = NaturaTarget::new(translation.x, translation.y, translation.z);
+                        match target.as_deref_mut() {
+                            Some(target) => *target = new_target,
+                            None => {
+                                commands.entity(entity).insert(new_target);
+                            }
+                        }
+                    }
+                    if let (Some(params), Some(spring)) = (keyframe.spring, spring.as_deref_mut()) {
+                        set_spring_params(spring, params);
+                    }
+                }
+                KeyframeTarget::Rotation(goal) => {
+                    match rotation_target.as_deref_mut() {
+                        Some(target) => target.0 = goal,
+                        None => {
+                            commands.entity(entity).insert(NaturaRotationTarget(goal));
+                        }
+                    }
+                    match (keyframe.spring, rotation.as_deref_mut()) {
+                        (Some(params), Some(rotation)) => set_spring_params(&mut rotation.spring, params),
+                        (Some(params), None) => {
+                            commands.entity(entity).insert(NaturaRotation::with_params(params));
+                        }
+                        (None, _) => {}
+                    }
+                }
+                KeyframeTarget::Scale(goal) => {
+                    match scale_target.as_deref_mut() {
+                        Some(target) => target.0 = goal,
+                        None => {
+                            commands.entity(entity).insert(NaturaScaleTarget(goal));
+                        }
+                    }
+                    match (keyframe.spring, scale.as_deref_mut()) {
+                        (Some(params), Some(scale)) => set_spring_params(&mut scale.spring, params),
+                        (Some(params), None) => {
+                            commands.entity(entity).insert(NaturaScale::with_params(params));
+                        }
+                        (None, _) => {}
+                    }
+                }
+            }
+        }
+
+        // Hold the spring at rest on the eased translation, so that it
+        // follows the curve without springing
+        if let Some(translation) = eased_translation {
+            match sprite.as_deref_mut() {
+                Some(sprite) => *sprite = NaturaSprite::new(translation.x, translation.y, translation.z),
+                None => transform.translation = translation,
+            }
+            let new_target = NaturaTarget::new(translation.x, translation.y, translation.z);
+            match target.as_deref_mut() {
+                Some(target) => *target = new_target,
+                None => {
+                    commands.entity(entity).insert(new_target);
+                }
+            }
+        }
+    }
+}
+
+// ==================== Group Orchestration ====================
+
+/// Where `AnimateGroup` moves the members of a group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GroupMotion {
+    /// Every member springs to the same target
+    Target(Vec3),
+    /// Every member's target moves by the offset, starting from its current
+    /// `NaturaTarget`, or its position if it has none
+    Offset(Vec3),
+}
+
+/// Order in which `AnimateGroup` starts the members of a group.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GroupStagger {
+    /// All members start at once
+    #[default]
+    None,
+    /// Members start `interval` seconds apart, in the order they were spawned
+    ByIndex {
+        /// Seconds between the starts of consecutive members
+        interval: f64,
+    },
+    /// Members start later the farther they are from `origin`, e.g. for a
+    /// ripple from a click
+    FromPoint {
+        /// Point the ripple starts from
+        origin: Vec3,
+        /// Delay in seconds per unit of distance
+        delay_per_unit: f64,
+    },
+    /// Members start `interval` seconds apart in the given order; members
+    /// not listed follow in the order they were spawned
+    Custom {
+        /// Members in the order they start
+        order: Vec<Entity>,
+        /// Seconds between the starts of consecutive members
+        interval: f64,
+    },
+}
+
+impl GroupStagger {
+    /// Returns the start delay of each member, given with its position in
+    /// spawn order.
+    fn delays(&self, members: &[(Entity, Vec3)]) -> Vec<f64> {
+        match self {
+            GroupStagger::None => vec![0.0; members.len()],
+            GroupStagger::ByIndex { interval } => (0..members.len()).map(|index| index as f64 * interval).collect(),
+            GroupStagger::FromPoint { origin, delay_per_unit } => members
+                .iter()
+                .map(|(_, position)| f64::from(position.distance(*origin)) * delay_per_unit)
+                .collect(),
+            GroupStagger::Custom { order, interval } => {
+                let mut unlisted = order.len();
+                members
+                    .iter()
+                    .map(|(entity, _)| {
+                        let index = order.iter().position(|listed| listed == entity).unwrap_or_else(|| {
+                            unlisted += 1;
+                            unlisted - 1
+                        });
+                        index as f64 * interval
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Event that springs every member of an `AnimationGroup` to a shared target
+/// or by a shared offset, optionally staggered and with a spring that
+/// overrides the members' own. `GroupAnimationCompleted` is sent once every
+/// member has come to rest at its target.
+///
+/// Members need the components of a `NaturaSpringBundle`. Pausing works as
+/// for springs, and also holds back members that haven't started yet.
+///
+/// ```rust,ignore
+/// fn on_click(mut events: EventWriter<AnimateGroup>) {
+///     events.send(
+///         AnimateGroup::offset(1, Vec3::new(0.0, 50.0, 0.0))
+///             .with_stagger(GroupStagger::FromPoint { origin: Vec3::ZERO, delay_per_unit: 0.002 })
+///             .with_spring(DurationBounce { duration: 0.4, bounce: 0.3 }),
+///     );
+/// }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimateGroup {
+    /// ID of the group to animate
+    pub group: u32,
+    /// Where the members move
+    pub motion: GroupMotion,
+    /// When each member starts
+    pub stagger: GroupStagger,
+    /// Spring parameters the members switch to when they start, if any
+    pub spring: Option<SpringParams>,
+}
+
+impl AnimateGroup {
+    /// Creates an event that springs every member of `group` to `target` at once.
+    #[must_use]
+    pub fn target(group: u32, target: Vec3) -> Self {
+        AnimateGroup {
+            group,
+            motion: GroupMotion::Target(target),
+            stagger: GroupStagger::None,
+            spring: None,
+        }
+    }
+
+    /// Creates an event that moves the target of every member of `group` by
+    /// `offset` at once.
+    #[must_use]
+    pub fn offset(group: u32, offset: Vec3) -> Self {
+        AnimateGroup {
+            motion: GroupMotion::Offset(offset),
+            ..Self::target(group, Vec3::ZERO)
+        }
+    }
+
+    /// Sets when each member starts.
+    #[must_use]
+    pub fn with_stagger(mut self, stagger: GroupStagger) -> Self {
+        self.stagger = stagger;
+        self
+    }
+
+    /// Sets the spring parameters the members switch to.
+    #[must_use]
+    pub fn with_spring(mut self, params: impl Into<SpringParams>) -> Self {
+        self.spring = Some(params.into());
+        self
+    }
+}
+
+/// Event emitted when every member of a group animated with `AnimateGroup`
+/// has come to rest at its target.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupAnimationCompleted {
+    /// ID of the group that completed its animation
+    pub group: u32,
+}
+
+/// Target of a group member waiting for its stagger delay.
+#[derive(Debug, Clone, Copy)]
+struct StaggeredTarget {
+    entity: Entity,
+    /// Seconds left before the member starts
+    delay: f64,
+    target: Vec3,
+    spring: Option<SpringParams>,
+}
+
+/// Groups being animated with `AnimateGroup`, with the members that haven't
+/// started yet.
+#[derive(Resource, Default, Debug)]
+struct GroupAnimations {
+    active: std::collections::HashMap<u32, Vec<StaggeredTarget>>,
+}
+
+/// Sets the target, and spring if given, of a group member.
+fn start_group_member(
+    commands: &mut Commands,
+    member: &StaggeredTarget,
+    target: Option<Mut<NaturaTarget>>,
+    spring: Option<Mut<NaturaSpring>>,
+) {
+    let new_target = NaturaTarget::new(member.target.x, member.target.y, member.target.z);
+    match target {
+        Some(mut target) => *target = new_target,
+        None => {
+            commands.entity(member.entity).insert(new_target);
+        }
+    }
+    if let (Some(params), Some(mut spring)) = (member.spring, spring) {
+        set_spring_params(&mut spring, params);
+    }
+}
+
+/// System that starts the members of groups animated with `AnimateGroup`
+/// after their stagger delays, and emits `GroupAnimationCompleted` once all
+/// of a group's members have come to rest at their targets.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn natura_group_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    global_pause: Option<Res<GlobalAnimationPaused>>,
+    paused_groups: Option<Res<PausedGroups>>,
+    mut animations: ResMut<GroupAnimations>,
+    mut ev_animate: EventReader<AnimateGroup>,
+    mut ev_completed: EventWriter<GroupAnimationCompleted>,
+    mut query: Query<(
+        Entity,
+        &AnimationGroup,
+        &Transform,
+        Option<&NaturaSprite>,
+        Option<&mut NaturaTarget>,
+        Option<&mut NaturaSpring>,
+        Option<&AnimationPaused>,
+    )>,
+) {
+    if global_pause.is_some() {
+        return;
+    }
+
+    // Groups whose members have all started complete once every member is
+    // at rest at its target
+    animations.active.retain(|group, pending| {
+        if !pending.is_empty() {
+            return true;
+        }
+        let settled = query
+            .iter()
+            .filter(|(_, member_group, ..)| member_group.0 == *group)
+            .all(|(_, _, _, sprite, target, ..)| match (sprite, target) {
+                (Some(sprite), Some(target)) => {
+                    sprite.is_at_rest(REST_VELOCITY_THRESHOLD)
+                        && Vec3::new(sprite.x, sprite.y, sprite.z).distance(Vec3::new(target.x, target.y, target.z))
+                            < TARGET_POSITION_THRESHOLD
+                }
+                _ => true,
+            });
+        if settled {
+            ev_completed.send(GroupAnimationCompleted { group: *group });
+        }
+        !settled
+    });
+
+    // Start members whose delay has passed
+    let delta_seconds = time.delta_secs_f64();
+    for (group, pending) in animations.active.iter_mut() {
+        if paused_groups.as_deref().is_some_and(|paused_groups| paused_groups.is_paused(*group)) {
+            continue;
+        }
+        pending.retain_mut(|member| {
+            let Ok((.., target, spring, paused)) = query.get_mut(member.entity) else {
+                return false;
+            };
+            if paused.is_some() {
+                return true;
+            }
+            member.delay -= delta_seconds;
+            if member.delay > 0.0 {
+                return true;
+            }
+            start_group_member(&mut commands, member, target, spring);
+            false
+        });
+    }
+
+    for event in ev_animate.read() {
+        let mut members: Vec<_> = query
+            .iter()
+            .filter(|(_, group, ..)| group.0 == event.group)
+            .map(|(entity, _, transform, sprite, target, ..)| {
+                let position = sprite.map_or(transform.translation, |sprite| Vec3::new(sprite.x, sprite.y, sprite.z));
+                let current = target.map_or(position, |target| Vec3::new(target.x, target.y, target.z));
+                (entity, position, current)
+            })
+            .collect();
+        members.sort_by_key(|(entity, ..)| *entity);
+
+        let positions: Vec<_> = members.iter().map(|(entity, position, _)| (*entity, *position)).collect();
+        let delays = event.stagger.delays(&positions);
+
+        let mut pending = Vec::new();
+        for ((entity, _, current), delay) in members.into_iter().zip(delays) {
+            let member = StaggeredTarget {
+                entity,
+                delay,
+                target: match event.motion {
+                    GroupMotion::Target(target) => target,
+                    GroupMotion::Offset(offset) => current + offset,
+                },
+                spring: event.spring,
+            };
+            if delay > 0.0 {
+                pending.push(member);
+            } else if let Ok((.., target, spring, _)) = query.get_mut(entity) {
+                start_group_member(&mut commands, &member, target, spring);
+            }
+        }
+
+        // A new animation of a group replaces the members still waiting
+        animations.active.insert(event.group, pending);
+    }
+}
+
+impl Plugin for NaturaAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<NaturaSprite>()
+            .register_type::<NaturaSpring>()
+            .register_type::<NaturaTarget>()
+            .register_type::<AnimationState>()
+            .register_type::<EasingCurve>()
+            .register_type::<AnimationGroup>()
+            .register_type::<AnimationPaused>()
+            .register_type::<NaturaRotationTarget>()
+            .register_type::<NaturaRotation>()
+            .register_type::<NaturaScaleTarget>()
+            .register_type::<NaturaScale>()
+            .register_type::<NaturaTween>()
+            .add_event::<AnimationStarted>()
+            .add_event::<AnimationCompleted>()
+            .add_event::<RotationStarted>()
+            .add_event::<RotationCompleted>()
+            .add_event::<TimelineCompleted>()
+            .add_event::<AnimateGroup>()
+            .add_event::<GroupAnimationCompleted>()
+            .init_resource::<PausedGroups>()
+            .init_resource::<NaturaTimestep>()
+            .init_resource::<NaturaClock>()
+            .init_resource::<GroupAnimations>()
+            .add_systems(
+                Update,
+                (
+                    natura_clock_system,
+                    (natura_animation_system, natura_rotation_system, natura_scale_system, natura_lens_system),
+                )
+                    .chain(),
+            )
+            .add_systems(Update, natura_tween_system)
+            .add_systems(Update, natura_timeline_system.before(natura_clock_system))
+            .add_systems(Update, natura_group_system.before(natura_clock_system));
+    }
+}
+
+// ==================== Particles ====================
+
+/// Plugin that simulates `NaturaParticles` components and keeps one child
+/// entity per live particle.
+///
+/// Particle entities carry a `NaturaParticle` and a `Transform` in the local
+/// space of the emitting entity, so particles follow the emitter when it
+/// moves. Attach visuals by querying `Added<NaturaParticle>`.
+///
+/// # Usage
+///
+/// ```rust,ignore
+/// use bevy::prelude::*;
+/// use bevy_natura::{NaturaParticlePlugin, NaturaParticles, NaturaParticle};
+/// use natura::{Emitter, EmitterShape, EmissionMode, ParticleSystem, Point, GRAVITY};
+///
+/// fn setup(mut commands: Commands) {
+///     let emitter = Emitter::new(Point::default(), EmitterShape::Point, EmissionMode::Rate { per_second: 50.0 })
+///         .with_speed(50.0, 100.0)
+///         .with_acceleration(GRAVITY);
+///     commands.spawn((
+///         Transform::default(),
+///         NaturaParticles::new(ParticleSystem::new(42).with_emitter(emitter)),
+///     ));
+/// }
+///
+/// fn add_visuals(mut commands: Commands, added: Query<Entity, Added<NaturaParticle>>) {
+///     for entity in &added {
+///         commands.entity(entity).insert(Sprite::from_color(Color::WHITE, Vec2::splat(2.0)));
+///     }
+/// }
+/// ```
+pub struct NaturaParticlePlugin;
+
+/// Component that drives a `natura::ParticleSystem` with Bevy's time.
+/// Pause it with the `AnimationPaused` component, its `AnimationGroup` in
+/// `PausedGroups`, or `GlobalAnimationPaused`.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaParticles {
+    /// The simulated particles and their emitters
+    pub system: ParticleSystem,
+    /// Child entities showing the particles, one per live particle
+    #[cfg_attr(feature = "serde", serde(skip))]
+    entities: Vec<Entity>,
+}
+
+impl NaturaParticles {
+    /// Creates a new component driving the given particle system.
+    #[must_use]
+    pub fn new(system: ParticleSystem) -> Self {
+        NaturaParticles {
+            system,
+            entities: Vec::new(),
+        }
+    }
+
+    /// Returns the child entities showing the particles, in the same order
+    /// as the particles of the system.
+    #[must_use]
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+}
+
+impl From<ParticleSystem> for NaturaParticles {
+    fn from(system: ParticleSystem) -> Self {
+        Self::new(system)
+    }
+}
+
+/// Component on the child entity showing a single particle.
+/// Entities are reused as particles die, so read the values every frame.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturaParticle {
+    /// Index of the particle in its `ParticleSystem`
+    pub index: usize,
+    /// Seconds since the particle was spawned
+    pub age: f64,
+    /// Seconds the particle lives for
+    pub lifetime: f64,
+}
+
+impl NaturaParticle {
+    /// Returns how far the particle is through its life (0.0 to 1.0).
+    #[must_use]
+    pub fn progress(&self) -> f64 {
+        if self.lifetime <= 0.0 {
+            1.0
+        } else {
+            (self.age / self.lifetime).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// System that advances every `NaturaParticles` component and spawns,
+/// updates or despawns its particle entities to match the live particles.
+fn natura_particle_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    global_pause: Option<Res<GlobalAnimationPaused>>,
+    paused_groups: Option<Res<PausedGroups>>,
+    mut systems: Query<(Entity, &mut NaturaParticles, Option<&AnimationGroup>, Option<&AnimationPaused>)>,
+    mut particles: Query<(&mut NaturaParticle, &mut Transform)>,
+) {
+    let delta_seconds = time.delta_secs_f64();
+
+    for (parent, mut emitter, group, paused) in systems.iter_mut() {
+        let emitter = &mut *emitter;
+        let is_paused = global_pause.is_some() || is_entity_paused(paused, group, paused_groups.as_deref());
+        if !is_paused && delta_seconds > 0.0 {
+            emitter.system.update(DeltaTime(delta_seconds));
+        }
+
+        let live = emitter.system.len();
+        while emitter.entities.len() > live {
+            let entity = emitter.entities.pop().unwrap();
+            commands.entity(entity).despawn_recursive();
+        }
+
+        for (index, particle) in emitter.system.iter().enumerate() {
+            let component = NaturaParticle {
+                index,
+                age: particle.age,
+                lifetime: particle.lifetime,
+            };
+            let translation = Vec3::new(
+                particle.position.x as f32,
+                particle.position.y as f32,
+                particle.position.z as f32,
+            );
+
+            match emitter.entities.get(index) {
+                Some(entity) => {
+                    if let Ok((mut existing, mut transform)) = particles.get_mut(*entity) {
+                        *existing = component;
+                        transform.translation = translation;
+                    }
+                }
+                None => {
+                    let entity = commands
+                        .spawn((component, Transform::from_translation(translation)))
+                        .set_parent(parent)
+                        .id();
+                    emitter.entities.push(entity);
+                }
+            }
+        }
+    }
+}
+
+impl Plugin for NaturaParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<NaturaParticle>()
+            .add_systems(Update, natura_particle_system);
+    }
+}
+
+// Re-export natura types for convenience
+pub use natura::{
+    AngularFrequency, DampingRatio, DeltaTime, DurationBounce, Easing, EmissionMode, Emitter, EmitterShape, HalfLife,
+    Particle, ParticleSystem, PhysicalParams, SpringEasing, SpringParams, SpringPreset, SpringPresets, StepPosition,
+    Steps,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== NaturaSprite Tests ====================
+
+    #[test]
+    fn test_natura_sprite_new() {
+        let sprite = NaturaSprite::new(10.0, 20.0, 30.0);
+        assert_eq!(sprite.x, 10.0);
+        assert_eq!(sprite.y, 20.0);
+        assert_eq!(sprite.z, 30.0);
+        assert_eq!(sprite.x_velocity, 0.0);
+        assert_eq!(sprite.y_velocity, 0.0);
+        assert_eq!(sprite.z_velocity, 0.0);
+    }
+
+    #[test]
+    fn test_natura_sprite_new_2d() {
+        let sprite = NaturaSprite::new_2d(10.0, 20.0);
+        assert_eq!(sprite.x, 10.0);
+        assert_eq!(sprite.y, 20.0);
+        assert_eq!(sprite.z, 0.0);
+        assert_eq!(sprite.x_velocity, 0.0);
+        assert_eq!(sprite.y_velocity, 0.0);
+        assert_eq!(sprite.z_velocity, 0.0);
+    }
+
+    #[test]
+    fn test_natura_sprite_default() {
+        let sprite = NaturaSprite::default();
+        assert_eq!(sprite.x, 0.0);
+        assert_eq!(sprite.y, 0.0);
+        assert_eq!(sprite.z, 0.0);
+        assert_eq!(sprite.x_velocity, 0.0);
+        assert_eq!(sprite.y_velocity, 0.0);
+        assert_eq!(sprite.z_velocity, 0.0);
+    }
+
+    #[test]
+    fn test_natura_sprite_is_at_rest_true() {
+        let sprite = NaturaSprite {
+            x: 100.0,
+            y: 100.0,
+            z: 0.0,
+            x_velocity: 0.001,
+            y_velocity: 0.001,
+            z_velocity: 0.001,
+        };
+        assert!(sprite.is_at_rest(0.01));
+    }
+
+    #[test]
+    fn test_natura_sprite_is_at_rest_false() {
+        let sprite = NaturaSprite {
+            x: 100.0,
+            y: 100.0,
+            z: 0.0,
+            x_velocity: 1.0,
+            y_velocity: 0.0,
+            z_velocity: 0.0,
+        };
+        assert!(!sprite.is_at_rest(0.01));
+    }
+
+    #[test]
+    fn test_natura_sprite_is_at_rest_all_axes() {
+        // X velocity too high
         let sprite_x = NaturaSprite {
             x: 0.0, y: 0.0, z: 0.0,
             x_velocity: 1.0, y_velocity: 0.0, z_velocity: 0.0,
         };
-        assert!(!sprite_x.is_at_rest(0.5));
+        assert!(!sprite_x.is_at_rest(0.5));
+
+        // Y velocity too high
+        let sprite_y = NaturaSprite {
+            x: 0.0, y: 0.0, z: 0.0,
+            x_velocity: 0.0, y_velocity: 1.0, z_velocity: 0.0,
+        };
+        assert!(!sprite_y.is_at_rest(0.5));
+
+        // Z velocity too high
+        let sprite_z = NaturaSprite {
+            x: 0.0, y: 0.0, z: 0.0,
+            x_velocity: 0.0, y_velocity: 0.0, z_velocity: 1.0,
+        };
+        assert!(!sprite_z.is_at_rest(0.5));
+    }
+
+    #[test]
+    fn test_natura_sprite_clone() {
+        let sprite = NaturaSprite::new(1.0, 2.0, 3.0);
+        let cloned = sprite.clone();
+        assert_eq!(sprite.x, cloned.x);
+        assert_eq!(sprite.y, cloned.y);
+        assert_eq!(sprite.z, cloned.z);
+    }
+
+    // ==================== NaturaTarget Tests ====================
+
+    #[test]
+    fn test_natura_target_new() {
+        let target = NaturaTarget::new(10.0, 20.0, 30.0);
+        assert_eq!(target.x, 10.0);
+        assert_eq!(target.y, 20.0);
+        assert_eq!(target.z, 30.0);
+    }
+
+    #[test]
+    fn test_natura_target_new_2d() {
+        let target = NaturaTarget::new_2d(10.0, 20.0);
+        assert_eq!(target.x, 10.0);
+        assert_eq!(target.y, 20.0);
+        assert_eq!(target.z, 0.0);
+    }
+
+    #[test]
+    fn test_natura_target_default() {
+        let target = NaturaTarget::default();
+        assert_eq!(target.x, 0.0);
+        assert_eq!(target.y, 0.0);
+        assert_eq!(target.z, 0.0);
+    }
+
+    #[test]
+    fn test_natura_target_clone() {
+        let target = NaturaTarget::new(1.0, 2.0, 3.0);
+        let cloned = target.clone();
+        assert_eq!(target.x, cloned.x);
+        assert_eq!(target.y, cloned.y);
+        assert_eq!(target.z, cloned.z);
+    }
+
+    // ==================== NaturaSpring Tests ====================
+
+    #[test]
+    fn test_natura_spring_new() {
+        let spring = NaturaSpring::new(AngularFrequency(8.0), DampingRatio(0.5));
+        assert_eq!(spring.angular_frequency, 8.0);
+        assert_eq!(spring.damping_ratio, 0.5);
+    }
+
+    #[test]
+    fn test_natura_spring_default() {
+        let spring = NaturaSpring::default();
+        assert_eq!(spring.angular_frequency, 6.0);
+        assert_eq!(spring.damping_ratio, 0.7);
+    }
+
+    #[test]
+    fn test_natura_spring_clone() {
+        let spring = NaturaSpring::new(AngularFrequency(10.0), DampingRatio(0.3));
+        let cloned = spring.clone();
+        assert_eq!(spring.angular_frequency, cloned.angular_frequency);
+        assert_eq!(spring.damping_ratio, cloned.damping_ratio);
+    }
+
+    #[test]
+    fn test_natura_spring_update() {
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
+        let delta_seconds = 1.0 / 60.0; // 60 FPS
+        
+        // Starting at position 0 with no velocity, target at 100
+        let (new_pos, new_vel) = spring.update(0.0, 0.0, 100.0, delta_seconds);
+        
+        // Position should move towards target
+        assert!(new_pos > 0.0);
+        // Velocity should be positive (moving towards target)
+        assert!(new_vel > 0.0);
+    }
+
+    #[test]
+    fn test_natura_spring_update_at_target() {
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(1.0));
+        let delta_seconds = 1.0 / 60.0;
+        
+        // Already at target with no velocity
+        let (new_pos, new_vel) = spring.update(100.0, 0.0, 100.0, delta_seconds);
+        
+        // Should stay at target
+        assert!((new_pos - 100.0).abs() < 0.001);
+        assert!(new_vel.abs() < 0.001);
+    }
+
+    #[test]
+    fn test_natura_spring_variable_delta_is_exact() {
+        // Deltas that differ by less than 1ms must still be evaluated exactly.
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
+        let (pos_a, _) = spring.update(0.0, 0.0, 100.0, 0.0160);
+        let (pos_b, _) = spring.update(0.0, 0.0, 100.0, 0.0165);
+
+        let mut exact = natura::DynamicSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
+        let (expected_b, _) = exact.update(0.0, 0.0, 100.0, DeltaTime(0.0165));
+
+        assert!(pos_b > pos_a);
+        assert_eq!(pos_b, expected_b);
+    }
+
+    #[test]
+    fn test_natura_spring_parameter_change_applies() {
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
+        let (slow, _) = spring.update(0.0, 0.0, 100.0, 1.0 / 60.0);
+
+        spring.angular_frequency = 12.0;
+        let (fast, _) = spring.update(0.0, 0.0, 100.0, 1.0 / 60.0);
+
+        assert!(fast > slow);
+    }
+
+    #[test]
+    fn test_natura_spring_estimate_completion_time() {
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
+        let mut sprite = NaturaSprite::new(0.0, 0.0, 0.0);
+        let target = NaturaTarget::new(100.0, -50.0, 0.0);
+        let delta_seconds = 1.0 / 60.0;
+
+        let estimate = spring.estimate_completion_time(&sprite, &target).unwrap();
+
+        // Step until the completion condition of the animation system holds.
+        let mut elapsed = 0.0;
+        loop {
+            let (pos, vel) = spring.update_value(
+                [sprite.x, sprite.y, sprite.z],
+                [sprite.x_velocity, sprite.y_velocity, sprite.z_velocity],
+                [target.x, target.y, target.z],
+                delta_seconds,
+            );
+            [sprite.x, sprite.y, sprite.z] = pos;
+            [sprite.x_velocity, sprite.y_velocity, sprite.z_velocity] = vel;
+            elapsed += delta_seconds;
+
+            let at_target = (sprite.x - target.x).abs() < TARGET_POSITION_THRESHOLD
+                && (sprite.y - target.y).abs() < TARGET_POSITION_THRESHOLD;
+            if at_target && sprite.is_at_rest(REST_VELOCITY_THRESHOLD) {
+                break;
+            }
+        }
+
+        assert!((f64::from(elapsed) - estimate).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_natura_spring_estimate_completion_time_undamped() {
+        let spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.0));
+        let sprite = NaturaSprite::default();
+        let target = NaturaTarget::new_2d(100.0, 0.0);
+
+        assert!(spring.estimate_completion_time(&sprite, &target).is_none());
+    }
+
+    #[test]
+    fn test_natura_spring_estimate_completion_time_lightly_damped() {
+        let spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(1e-4));
+        let sprite = NaturaSprite::default();
+        let target = NaturaTarget::new_2d(100.0, 0.0);
+
+        // Settling takes hours; the estimate skips the time the amplitude
+        // needs to decay instead of sampling all of it.
+        let estimate = spring.estimate_completion_time(&sprite, &target).unwrap();
+        let earliest = spring.earliest_completion_time(&sprite, &target);
+        assert!(earliest > 10_000.0);
+        assert!(estimate >= earliest && estimate < earliest + 10.0);
+
+        let trajectory = SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(1e-4), 0.0_f64, 0.0, 100.0);
+        let (pos, vel) = trajectory.state_at(estimate);
+        assert!((pos - 100.0).abs() < f64::from(TARGET_POSITION_THRESHOLD) && vel.abs() < f64::from(REST_VELOCITY_THRESHOLD));
+    }
+
+    #[test]
+    fn test_natura_spring_with_params() {
+        let spring = NaturaSpring::with_params(DurationBounce { duration: 1.0, bounce: 0.5 });
+        assert_eq!(spring.damping_ratio, 0.5);
+        assert!((spring.angular_frequency - 2.0 * std::f32::consts::PI).abs() < 1e-6);
+
+        let display = DurationBounce::from(spring.params());
+        assert!((display.duration - 1.0).abs() < 1e-6);
+        assert!((display.bounce - 0.5).abs() < 1e-6);
+    }
+
+    // ==================== NaturaSpringBundle Tests ====================
+
+    #[test]
+    fn test_natura_spring_bundle_new() {
+        let bundle = NaturaSpringBundle::new(AngularFrequency(8.0), DampingRatio(0.5));
+        
+        // Check spring parameters
+        assert_eq!(bundle.spring.angular_frequency, 8.0);
+        assert_eq!(bundle.spring.damping_ratio, 0.5);
+        
+        // Check sprite is at default position
+        assert_eq!(bundle.sprite.x, 0.0);
+        assert_eq!(bundle.sprite.y, 0.0);
+        assert_eq!(bundle.sprite.z, 0.0);
+    }
+
+    #[test]
+    fn test_natura_spring_bundle_with_position() {
+        let bundle = NaturaSpringBundle::with_position(
+            AngularFrequency(8.0),
+            DampingRatio(0.5),
+            10.0,
+            20.0,
+            30.0,
+        );
+        
+        // Check spring parameters
+        assert_eq!(bundle.spring.angular_frequency, 8.0);
+        assert_eq!(bundle.spring.damping_ratio, 0.5);
+        
+        // Check sprite position
+        assert_eq!(bundle.sprite.x, 10.0);
+        assert_eq!(bundle.sprite.y, 20.0);
+        assert_eq!(bundle.sprite.z, 30.0);
+    }
+
+    #[test]
+    fn test_natura_spring_bundle_default() {
+        let bundle = NaturaSpringBundle::default();
+        
+        // Check default spring parameters
+        assert_eq!(bundle.spring.angular_frequency, 6.0);
+        assert_eq!(bundle.spring.damping_ratio, 0.7);
+        
+        // Check sprite is at origin
+        assert_eq!(bundle.sprite.x, 0.0);
+        assert_eq!(bundle.sprite.y, 0.0);
+        assert_eq!(bundle.sprite.z, 0.0);
+    }
+
+    // ==================== Spring Animation Behavior Tests ====================
+
+    #[test]
+    fn test_spring_converges_to_target() {
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
+        let delta_seconds = 1.0 / 60.0;
+        let target = 100.0;
+        
+        let mut pos = 0.0;
+        let mut vel = 0.0;
+        
+        // Simulate 5 seconds (300 frames at 60 FPS)
+        for _ in 0..300 {
+            let (new_pos, new_vel) = spring.update(pos, vel, target, delta_seconds);
+            pos = new_pos;
+            vel = new_vel;
+        }
+        
+        // Should be very close to target after 5 seconds
+        assert!((pos - target).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_under_damped_spring_oscillates() {
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.3));
+        let delta_seconds = 1.0 / 60.0;
+        let target = 100.0;
+        
+        let mut pos = 0.0;
+        let mut vel = 0.0;
+        let mut max_pos = 0.0;
+        
+        // Simulate for a while and track max position
+        for _ in 0..120 {
+            let (new_pos, new_vel) = spring.update(pos, vel, target, delta_seconds);
+            pos = new_pos;
+            vel = new_vel;
+            if pos > max_pos {
+                max_pos = pos;
+            }
+        }
+        
+        // Under-damped spring should overshoot the target
+        assert!(max_pos > target);
+    }
+
+    #[test]
+    fn test_critically_damped_no_overshoot() {
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(1.0));
+        let delta_seconds = 1.0 / 60.0;
+        let target = 100.0;
+        
+        let mut pos = 0.0;
+        let mut vel = 0.0;
+        let mut max_pos = 0.0;
+        
+        // Simulate for a while
+        for _ in 0..300 {
+            let (new_pos, new_vel) = spring.update(pos, vel, target, delta_seconds);
+            pos = new_pos;
+            vel = new_vel;
+            if pos > max_pos {
+                max_pos = pos;
+            }
+        }
+        
+        // Critically damped spring should not significantly overshoot
+        assert!(max_pos <= target + 0.5);
+    }
+
+    // ==================== From Trait Tests ====================
+
+    #[test]
+    fn test_natura_sprite_from_natura_sprite_core() {
+        let core_sprite = natura::Sprite {
+            x: 10.0,
+            x_velocity: 1.0,
+            y: 20.0,
+            y_velocity: 2.0,
+        };
+        
+        let sprite: NaturaSprite = core_sprite.into();
+        
+        assert_eq!(sprite.x, 10.0);
+        assert_eq!(sprite.x_velocity, 1.0);
+        assert_eq!(sprite.y, 20.0);
+        assert_eq!(sprite.y_velocity, 2.0);
+        assert_eq!(sprite.z, 0.0);
+        assert_eq!(sprite.z_velocity, 0.0);
+    }
 
-        // Y velocity too high
-        let sprite_y = NaturaSprite {
-            x: 0.0, y: 0.0, z: 0.0,
-            x_velocity: 0.0, y_velocity: 1.0, z_velocity: 0.0,
+    // ==================== Debug Trait Tests ====================
+
+    #[test]
+    fn test_natura_sprite_debug() {
+        let sprite = NaturaSprite::new(1.0, 2.0, 3.0);
+        let debug_str = format!("{:?}", sprite);
+        assert!(debug_str.contains("NaturaSprite"));
+    }
+
+    #[test]
+    fn test_natura_spring_debug() {
+        let spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
+        let debug_str = format!("{:?}", spring);
+        assert!(debug_str.contains("NaturaSpring"));
+        assert!(debug_str.contains("angular_frequency"));
+        assert!(debug_str.contains("damping_ratio"));
+    }
+
+    #[test]
+    fn test_natura_target_debug() {
+        let target = NaturaTarget::new(1.0, 2.0, 3.0);
+        let debug_str = format!("{:?}", target);
+        assert!(debug_str.contains("NaturaTarget"));
+    }
+
+    // ==================== Easing Curve Tests ====================
+
+    #[test]
+    fn test_easing_curve_none() {
+        let easing = EasingCurve::None;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(0.5), 0.5);
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_ease_in() {
+        let easing = EasingCurve::EaseIn;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert!(easing.apply(0.5) < 0.5); // Should be slower at start
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_ease_out() {
+        let easing = EasingCurve::EaseOut;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert!(easing.apply(0.5) > 0.5); // Should be faster at start
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_ease_in_out() {
+        let easing = EasingCurve::EaseInOut;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert!((easing.apply(0.5) - 0.5).abs() < 0.01); // Middle should be close to 0.5
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_quadratic_in() {
+        let easing = EasingCurve::QuadraticIn;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(0.5), 0.25); // 0.5^2 = 0.25
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_quadratic_out() {
+        let easing = EasingCurve::QuadraticOut;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(0.5), 0.75); // 0.5 * (2 - 0.5) = 0.75
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_cubic_in() {
+        let easing = EasingCurve::CubicIn;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(0.5), 0.125); // 0.5^3 = 0.125
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_cubic_out() {
+        let easing = EasingCurve::CubicOut;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert!((easing.apply(0.5) - 0.875).abs() < 0.001);
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_elastic() {
+        let easing = EasingCurve::Elastic;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+        // Elastic should overshoot
+        assert!(easing.apply(0.9) > 1.0 || easing.apply(0.8) > 1.0);
+    }
+
+    #[test]
+    fn test_easing_curve_bounce() {
+        let easing = EasingCurve::Bounce;
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert!((easing.apply(1.0) - 1.0).abs() < 0.001);
+        // Bounce should have specific values at certain points
+        assert!(easing.apply(0.5) > 0.0);
+    }
+
+    #[test]
+    fn test_easing_curve_clamps_input() {
+        let easing = EasingCurve::None;
+        // Values outside 0-1 should be clamped
+        assert_eq!(easing.apply(-0.5), 0.0);
+        assert_eq!(easing.apply(1.5), 1.0);
+    }
+
+    // ==================== Animation Group Tests ====================
+
+    #[test]
+    fn test_animation_group_new() {
+        let group = AnimationGroup::new(42);
+        assert_eq!(group.0, 42);
+    }
+
+    #[test]
+    fn test_animation_group_equality() {
+        let group1 = AnimationGroup::new(1);
+        let group2 = AnimationGroup::new(1);
+        let group3 = AnimationGroup::new(2);
+        assert_eq!(group1, group2);
+        assert_ne!(group1, group3);
+    }
+
+    #[test]
+    fn test_paused_groups_pause_resume() {
+        let mut paused = PausedGroups::default();
+        assert!(!paused.is_paused(1));
+        
+        paused.pause(1);
+        assert!(paused.is_paused(1));
+        assert!(!paused.is_paused(2));
+        
+        paused.resume(1);
+        assert!(!paused.is_paused(1));
+    }
+
+    #[test]
+    fn test_paused_groups_multiple() {
+        let mut paused = PausedGroups::default();
+        paused.pause(1);
+        paused.pause(2);
+        paused.pause(3);
+        
+        assert!(paused.is_paused(1));
+        assert!(paused.is_paused(2));
+        assert!(paused.is_paused(3));
+        assert!(!paused.is_paused(4));
+        
+        paused.resume(2);
+        assert!(paused.is_paused(1));
+        assert!(!paused.is_paused(2));
+        assert!(paused.is_paused(3));
+    }
+
+    // ==================== Animation State Tests ====================
+
+    #[test]
+    fn test_animation_state_default() {
+        let state = AnimationState::default();
+        assert_eq!(state, AnimationState::Idle);
+    }
+
+    #[test]
+    fn test_animation_state_equality() {
+        assert_eq!(AnimationState::Idle, AnimationState::Idle);
+        assert_eq!(AnimationState::Animating, AnimationState::Animating);
+        assert_eq!(AnimationState::JustCompleted, AnimationState::JustCompleted);
+        assert_ne!(AnimationState::Idle, AnimationState::Animating);
+    }
+
+    // ==================== Event Tests ====================
+
+    #[test]
+    fn test_animation_started_event() {
+        let event = AnimationStarted {
+            entity: Entity::from_raw(42),
+            target: Vec3::new(100.0, 200.0, 0.0),
         };
-        assert!(!sprite_y.is_at_rest(0.5));
+        assert_eq!(event.target, Vec3::new(100.0, 200.0, 0.0));
+    }
 
-        // Z velocity too high
-        let sprite_z = NaturaSprite {
-            x: 0.0, y: 0.0, z: 0.0,
-            x_velocity: 0.0, y_velocity: 0.0, z_velocity: 1.0,
+    #[test]
+    fn test_animation_completed_event() {
+        let event = AnimationCompleted {
+            entity: Entity::from_raw(42),
+            final_position: Vec3::new(100.0, 200.0, 0.0),
         };
-        assert!(!sprite_z.is_at_rest(0.5));
+        assert_eq!(event.final_position, Vec3::new(100.0, 200.0, 0.0));
     }
 
+    // ==================== Bundle with Easing Tests ====================
+
     #[test]
-    fn test_natura_sprite_clone() {
-        let sprite = NaturaSprite::new(1.0, 2.0, 3.0);
-        let cloned = sprite.clone();
-        assert_eq!(sprite.x, cloned.x);
-        assert_eq!(sprite.y, cloned.y);
-        assert_eq!(sprite.z, cloned.z);
+    #[allow(deprecated)]
+    fn test_natura_spring_bundle_with_easing() {
+        let bundle = NaturaSpringBundle::with_easing(
+            AngularFrequency(8.0),
+            DampingRatio(0.5),
+            EasingCurve::EaseOut,
+        );
+        
+        assert_eq!(bundle.spring.angular_frequency, 8.0);
+        assert_eq!(bundle.spring.damping_ratio, 0.5);
+        assert_eq!(bundle.easing, EasingCurve::EaseOut);
+        assert_eq!(bundle.state, AnimationState::Idle);
     }
 
-    // ==================== NaturaTarget Tests ====================
-
     #[test]
-    fn test_natura_target_new() {
-        let target = NaturaTarget::new(10.0, 20.0, 30.0);
-        assert_eq!(target.x, 10.0);
-        assert_eq!(target.y, 20.0);
-        assert_eq!(target.z, 30.0);
+    fn test_natura_spring_bundle_includes_state_and_easing() {
+        let bundle = NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.7));
+        
+        // Bundle should include default state and easing
+        assert_eq!(bundle.state, AnimationState::Idle);
+        assert_eq!(bundle.easing, EasingCurve::None);
     }
 
+    // ==================== Global Pause Resource Tests ====================
+
     #[test]
-    fn test_natura_target_new_2d() {
-        let target = NaturaTarget::new_2d(10.0, 20.0);
-        assert_eq!(target.x, 10.0);
-        assert_eq!(target.y, 20.0);
-        assert_eq!(target.z, 0.0);
+    #[allow(clippy::default_constructed_unit_structs)]
+    fn test_global_animation_paused_default() {
+        let _paused = GlobalAnimationPaused::default();
+        // Just ensure it can be created
     }
 
+    // ==================== Animation Paused Component Tests ====================
+
     #[test]
-    fn test_natura_target_default() {
-        let target = NaturaTarget::default();
-        assert_eq!(target.x, 0.0);
-        assert_eq!(target.y, 0.0);
-        assert_eq!(target.z, 0.0);
+    #[allow(clippy::default_constructed_unit_structs)]
+    fn test_animation_paused_default() {
+        let _paused = AnimationPaused::default();
+        // Just ensure it can be created
     }
 
-    #[test]
-    fn test_natura_target_clone() {
-        let target = NaturaTarget::new(1.0, 2.0, 3.0);
-        let cloned = target.clone();
-        assert_eq!(target.x, cloned.x);
-        assert_eq!(target.y, cloned.y);
-        assert_eq!(target.z, cloned.z);
+    // ==================== Particle Tests ====================
+
+    fn particle_app(emitter: Emitter) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(NaturaParticlePlugin).init_resource::<Time>();
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), NaturaParticles::new(ParticleSystem::new(1).with_emitter(emitter))))
+            .id();
+        (app, entity)
     }
 
-    // ==================== NaturaSpring Tests ====================
+    fn advance(app: &mut App, seconds: f64) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f64(seconds));
+        app.update();
+    }
 
-    #[test]
-    fn test_natura_spring_new() {
-        let spring = NaturaSpring::new(AngularFrequency(8.0), DampingRatio(0.5));
-        assert_eq!(spring.angular_frequency, 8.0);
-        assert_eq!(spring.damping_ratio, 0.5);
+    fn drain_events<E: Event>(app: &mut App) -> usize {
+        app.world_mut().resource_mut::<Events<E>>().drain().count()
     }
 
-    #[test]
-    fn test_natura_spring_default() {
-        let spring = NaturaSpring::default();
-        assert_eq!(spring.angular_frequency, 6.0);
-        assert_eq!(spring.damping_ratio, 0.7);
+    fn animation_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(NaturaAnimationPlugin).init_resource::<Time>();
+        app
+    }
+
+    fn particle_count(app: &mut App) -> usize {
+        app.world_mut().query::<&NaturaParticle>().iter(app.world()).count()
     }
 
     #[test]
-    fn test_natura_spring_clone() {
-        let spring = NaturaSpring::new(AngularFrequency(10.0), DampingRatio(0.3));
-        let cloned = spring.clone();
-        assert_eq!(spring.angular_frequency, cloned.angular_frequency);
-        assert_eq!(spring.damping_ratio, cloned.damping_ratio);
+    fn test_particle_plugin_spawns_and_despawns_entities() {
+        let emitter = Emitter::new(natura::Point::default(), EmitterShape::Point, EmissionMode::Burst { count: 8 })
+            .with_speed(10.0, 10.0)
+            .with_lifetime(0.1, 0.1);
+        let (mut app, parent) = particle_app(emitter);
+
+        advance(&mut app, 0.016);
+        assert_eq!(particle_count(&mut app), 8);
+        let children = app.world().get::<Children>(parent).unwrap();
+        assert_eq!(children.len(), 8);
+
+        // Particles move away from the emitter.
+        advance(&mut app, 0.016);
+        let mut query = app.world_mut().query::<(&NaturaParticle, &Transform)>();
+        for (particle, transform) in query.iter(app.world()) {
+            assert!(particle.age > 0.0);
+            assert!((transform.translation.length() - 0.16).abs() < 1e-4);
+        }
+
+        advance(&mut app, 0.1);
+        assert_eq!(particle_count(&mut app), 0);
+        assert!(app.world().get::<NaturaParticles>(parent).unwrap().entities().is_empty());
     }
 
     #[test]
-    fn test_natura_spring_update() {
-        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
-        let delta_seconds = 1.0 / 60.0; // 60 FPS
-        
-        // Starting at position 0 with no velocity, target at 100
-        let (new_pos, new_vel) = spring.update(0.0, 0.0, 100.0, delta_seconds);
-        
-        // Position should move towards target
-        assert!(new_pos > 0.0);
-        // Velocity should be positive (moving towards target)
-        assert!(new_vel > 0.0);
+    fn test_particle_plugin_respects_pause() {
+        let emitter = Emitter::new(natura::Point::default(), EmitterShape::Point, EmissionMode::Rate { per_second: 100.0 });
+        let (mut app, parent) = particle_app(emitter);
+        app.world_mut().entity_mut(parent).insert(AnimationPaused);
+
+        advance(&mut app, 0.1);
+        assert_eq!(particle_count(&mut app), 0);
+
+        app.world_mut().entity_mut(parent).remove::<AnimationPaused>();
+        advance(&mut app, 0.1);
+        assert_eq!(particle_count(&mut app), 10);
+
+        let mut paused_groups = PausedGroups::default();
+        paused_groups.pause(3);
+        app.world_mut().insert_resource(paused_groups);
+        app.world_mut().entity_mut(parent).insert(AnimationGroup(3));
+        advance(&mut app, 0.1);
+        assert_eq!(particle_count(&mut app), 10);
     }
 
     #[test]
-    fn test_natura_spring_update_at_target() {
-        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(1.0));
-        let delta_seconds = 1.0 / 60.0;
-        
-        // Already at target with no velocity
-        let (new_pos, new_vel) = spring.update(100.0, 0.0, 100.0, delta_seconds);
-        
-        // Should stay at target
-        assert!((new_pos - 100.0).abs() < 0.001);
-        assert!(new_vel.abs() < 0.001);
+    fn test_natura_particle_progress() {
+        let particle = NaturaParticle { index: 0, age: 0.25, lifetime: 1.0 };
+        assert!((particle.progress() - 0.25).abs() < f64::EPSILON);
+        assert_eq!(NaturaParticle::default().progress(), 1.0);
     }
 
-    // ==================== NaturaSpringBundle Tests ====================
+    // ==================== Rotation Tests ====================
 
     #[test]
-    fn test_natura_spring_bundle_new() {
-        let bundle = NaturaSpringBundle::new(AngularFrequency(8.0), DampingRatio(0.5));
-        
-        // Check spring parameters
-        assert_eq!(bundle.spring.angular_frequency, 8.0);
-        assert_eq!(bundle.spring.damping_ratio, 0.5);
-        
-        // Check sprite is at default position
-        assert_eq!(bundle.sprite.x, 0.0);
-        assert_eq!(bundle.sprite.y, 0.0);
-        assert_eq!(bundle.sprite.z, 0.0);
+    fn test_rotation_converges_and_emits_events() {
+        let target = Quat::from_euler(EulerRot::XYZ, 0.3, -1.2, 2.0);
+        let mut app = animation_app();
+        let entity = app.world_mut().spawn((Transform::default(), NaturaRotationTarget::new(target))).id();
+        assert!(app.world().get::<NaturaRotation>(entity).is_some());
+
+        let (mut started, mut completed) = (0, 0);
+        for _ in 0..300 {
+            advance(&mut app, 1.0 / 60.0);
+            started += drain_events::<RotationStarted>(&mut app);
+            completed += drain_events::<RotationCompleted>(&mut app);
+        }
+
+        let rotation = app.world().get::<Transform>(entity).unwrap().rotation;
+        assert!(rotation.angle_between(target) < 1e-3);
+        assert_eq!((started, completed), (1, 1));
     }
 
     #[test]
-    fn test_natura_spring_bundle_with_position() {
-        let bundle = NaturaSpringBundle::with_position(
-            AngularFrequency(8.0),
-            DampingRatio(0.5),
-            10.0,
-            20.0,
-            30.0,
-        );
-        
-        // Check spring parameters
-        assert_eq!(bundle.spring.angular_frequency, 8.0);
-        assert_eq!(bundle.spring.damping_ratio, 0.5);
-        
-        // Check sprite position
-        assert_eq!(bundle.sprite.x, 10.0);
-        assert_eq!(bundle.sprite.y, 20.0);
-        assert_eq!(bundle.sprite.z, 30.0);
+    fn test_rotation_takes_shortest_arc() {
+        // From 170° to -170° around z is 20° through 180°, not 340° through 0°
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_rotation(Quat::from_rotation_z(170f32.to_radians())),
+                NaturaRotationTarget::new(Quat::from_rotation_z(-170f32.to_radians())),
+            ))
+            .id();
+
+        for _ in 0..120 {
+            advance(&mut app, 1.0 / 60.0);
+            let rotation = app.world().get::<Transform>(entity).unwrap().rotation;
+            assert!(rotation.angle_between(Quat::from_rotation_z(std::f32::consts::PI)) < 30f32.to_radians());
+        }
     }
 
     #[test]
-    fn test_natura_spring_bundle_default() {
-        let bundle = NaturaSpringBundle::default();
-        
-        // Check default spring parameters
-        assert_eq!(bundle.spring.angular_frequency, 6.0);
-        assert_eq!(bundle.spring.damping_ratio, 0.7);
-        
-        // Check sprite is at origin
-        assert_eq!(bundle.sprite.x, 0.0);
-        assert_eq!(bundle.sprite.y, 0.0);
-        assert_eq!(bundle.sprite.z, 0.0);
+    fn test_rotation_respects_pause_and_groups() {
+        let target = Quat::from_rotation_z(1.0);
+        let mut app = animation_app();
+        let entity = app.world_mut().spawn((Transform::default(), NaturaRotationTarget::new(target))).id();
+        app.world_mut().entity_mut(entity).insert((AnimationPaused, AnimationGroup(2)));
+
+        advance(&mut app, 0.016);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().rotation, Quat::IDENTITY);
+
+        app.world_mut().entity_mut(entity).remove::<AnimationPaused>();
+        app.world_mut().resource_mut::<PausedGroups>().pause(2);
+        advance(&mut app, 0.016);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().rotation, Quat::IDENTITY);
+
+        app.world_mut().resource_mut::<PausedGroups>().resume(2);
+        advance(&mut app, 0.016);
+        assert_ne!(app.world().get::<Transform>(entity).unwrap().rotation, Quat::IDENTITY);
     }
 
-    // ==================== Spring Animation Behavior Tests ====================
+    // ==================== Scale Tests ====================
 
     #[test]
-    fn test_spring_converges_to_target() {
-        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
-        let delta_seconds = 1.0 / 60.0;
-        let target = 100.0;
-        
-        let mut pos = 0.0;
-        let mut vel = 0.0;
-        
-        // Simulate 5 seconds (300 frames at 60 FPS)
+    fn test_scale_pops_in_and_emits_events() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_scale(Vec3::ZERO),
+                NaturaScale::new(AngularFrequency(10.0), DampingRatio(0.4)),
+                NaturaScaleTarget::uniform(1.0),
+            ))
+            .id();
+
+        let (mut started, mut completed, mut max_scale) = (0, 0, 0.0_f32);
         for _ in 0..300 {
-            let (new_pos, new_vel) = spring.update(pos, vel, target, delta_seconds);
-            pos = new_pos;
-            vel = new_vel;
+            advance(&mut app, 1.0 / 60.0);
+            max_scale = max_scale.max(app.world().get::<Transform>(entity).unwrap().scale.x);
+            started += drain_events::<AnimationStarted>(&mut app);
+            completed += drain_events::<AnimationCompleted>(&mut app);
         }
-        
-        // Should be very close to target after 5 seconds
-        assert!((pos - target).abs() < 1.0);
+
+        // The under-damped spring overshoots before settling, which gives the pop
+        assert!(max_scale > 1.1);
+        assert!((app.world().get::<Transform>(entity).unwrap().scale - Vec3::ONE).abs().max_element() < 1e-3);
+        assert_eq!((started, completed), (1, 1));
+        assert_eq!(app.world().get::<NaturaScale>(entity).unwrap().state, AnimationState::Idle);
     }
 
     #[test]
-    fn test_under_damped_spring_oscillates() {
-        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.3));
-        let delta_seconds = 1.0 / 60.0;
-        let target = 100.0;
-        
-        let mut pos = 0.0;
-        let mut vel = 0.0;
-        let mut max_pos = 0.0;
-        
-        // Simulate for a while and track max position
-        for _ in 0..120 {
-            let (new_pos, new_vel) = spring.update(pos, vel, target, delta_seconds);
-            pos = new_pos;
-            vel = new_vel;
-            if pos > max_pos {
-                max_pos = pos;
-            }
+    fn test_scale_per_axis_and_pause() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaScale::new(AngularFrequency(10.0), DampingRatio(0.4)),
+                NaturaScaleTarget::new_2d(2.0, 0.5),
+            ))
+            .id();
+        app.world_mut().entity_mut(entity).insert(AnimationPaused);
+        advance(&mut app, 0.016);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().scale, Vec3::ONE);
+
+        app.world_mut().entity_mut(entity).remove::<AnimationPaused>();
+        for _ in 0..300 {
+            advance(&mut app, 1.0 / 60.0);
         }
-        
-        // Under-damped spring should overshoot the target
-        assert!(max_pos > target);
+        let scale = app.world().get::<Transform>(entity).unwrap().scale;
+        assert!((scale - Vec3::new(2.0, 0.5, 1.0)).abs().max_element() < 1e-3);
+    }
+
+    // ==================== Lens Tests ====================
+
+    #[derive(Component, Reflect, Default)]
+    struct Appearance {
+        color: Color,
+        size: Vec2,
+        volume: f32,
     }
 
     #[test]
-    fn test_critically_damped_no_overshoot() {
-        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(1.0));
-        let delta_seconds = 1.0 / 60.0;
-        let target = 100.0;
-        
-        let mut pos = 0.0;
-        let mut vel = 0.0;
-        let mut max_pos = 0.0;
-        
-        // Simulate for a while
+    fn test_lens_springs_f32_field_and_emits_events() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), Appearance::default(), NaturaLens::new::<Appearance>("volume", 0.8)))
+            .id();
+
+        let (mut started, mut completed) = (0, 0);
         for _ in 0..300 {
-            let (new_pos, new_vel) = spring.update(pos, vel, target, delta_seconds);
-            pos = new_pos;
-            vel = new_vel;
-            if pos > max_pos {
-                max_pos = pos;
-            }
+            advance(&mut app, 1.0 / 60.0);
+            started += drain_events::<AnimationStarted>(&mut app);
+            completed += drain_events::<AnimationCompleted>(&mut app);
         }
-        
-        // Critically damped spring should not significantly overshoot
-        assert!(max_pos <= target + 0.5);
+
+        assert!((app.world().get::<Appearance>(entity).unwrap().volume - 0.8).abs() < 1e-3);
+        assert_eq!((started, completed), (1, 1));
     }
 
-    // ==================== From Trait Tests ====================
+    #[test]
+    fn test_lens_at_rest_does_not_mark_component_changed() {
+        #[derive(Resource, Default)]
+        struct ChangedFrames(usize);
+
+        let mut app = animation_app();
+        app.init_resource::<ChangedFrames>().add_systems(
+            Last,
+            |query: Query<(), Changed<Appearance>>, mut frames: ResMut<ChangedFrames>| {
+                frames.0 += query.iter().count();
+            },
+        );
+        app.world_mut().spawn((Appearance::default(), NaturaLens::new::<Appearance>("volume", 0.8)));
+        app.world_mut()
+            .spawn((Appearance::default(), NaturaLens::new::<Appearance>("color", Color::srgb(1.0, 0.2, 0.2))));
+
+        for _ in 0..600 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        app.world_mut().resource_mut::<ChangedFrames>().0 = 0;
+        for _ in 0..10 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+
+        assert_eq!(app.world().resource::<ChangedFrames>().0, 0);
+    }
 
     #[test]
-    fn test_natura_sprite_from_natura_sprite_core() {
-        let core_sprite = natura::Sprite {
-            x: 10.0,
-            x_velocity: 1.0,
-            y: 20.0,
-            y_velocity: 2.0,
-        };
-        
-        let sprite: NaturaSprite = core_sprite.into();
-        
-        assert_eq!(sprite.x, 10.0);
-        assert_eq!(sprite.x_velocity, 1.0);
-        assert_eq!(sprite.y, 20.0);
-        assert_eq!(sprite.y_velocity, 2.0);
-        assert_eq!(sprite.z, 0.0);
-        assert_eq!(sprite.z_velocity, 0.0);
+    fn test_lens_springs_vectors_and_nested_paths() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                Appearance::default(),
+                NaturaLens::new::<Appearance>("size", Vec2::new(100.0, 40.0)),
+            ))
+            .id();
+        app.world_mut()
+            .spawn((Transform::default(), NaturaLens::new::<Transform>("translation.y", 12.0)));
+
+        for _ in 0..300 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+
+        let size = app.world().get::<Appearance>(entity).unwrap().size;
+        assert!((size - Vec2::new(100.0, 40.0)).abs().max_element() < 1e-2);
+        let mut transforms = app.world_mut().query_filtered::<&Transform, With<NaturaLens>>();
+        assert!(transforms.iter(app.world()).any(|transform| (transform.translation.y - 12.0).abs() < 1e-2));
+    }
+
+    #[test]
+    fn test_lens_springs_colors_in_their_color_space() {
+        let target = Color::srgb(1.0, 0.2, 0.2);
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), Appearance::default(), NaturaLens::new::<Appearance>("color", target)))
+            .id();
+        app.world_mut().get_mut::<Appearance>(entity).unwrap().color = Color::srgba(0.0, 0.0, 1.0, 0.5);
+
+        advance(&mut app, 1.0 / 60.0);
+        assert!(matches!(app.world().get::<Appearance>(entity).unwrap().color, Color::Srgba(_)));
+
+        for _ in 0..300 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        let color = app.world().get::<Appearance>(entity).unwrap().color.to_srgba();
+        let target = target.to_srgba();
+        assert!((color.red - target.red).abs() < 1e-2 && (color.green - target.green).abs() < 1e-2);
+        assert!((color.alpha - 1.0).abs() < 1e-2);
     }
 
-    // ==================== Debug Trait Tests ====================
+    #[test]
+    fn test_lens_ignores_mismatched_fields_and_respects_pause() {
+        // A Vec2 target for an f32 field is ignored
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), Appearance::default(), NaturaLens::new::<Appearance>("volume", Vec2::ONE)))
+            .id();
+        advance(&mut app, 0.016);
+        assert_eq!(app.world().get::<Appearance>(entity).unwrap().volume, 0.0);
+
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), Appearance::default(), NaturaLens::new::<Appearance>("volume", 1.0)))
+            .id();
+        app.world_mut().insert_resource(GlobalAnimationPaused);
+        advance(&mut app, 0.016);
+        assert_eq!(app.world().get::<Appearance>(entity).unwrap().volume, 0.0);
+
+        app.world_mut().remove_resource::<GlobalAnimationPaused>();
+        app.world_mut().entity_mut(entity).insert(AnimationPaused);
+        advance(&mut app, 0.016);
+        assert_eq!(app.world().get::<Appearance>(entity).unwrap().volume, 0.0);
+    }
+
+    // ==================== Timestep Tests ====================
 
     #[test]
-    fn test_natura_sprite_debug() {
-        let sprite = NaturaSprite::new(1.0, 2.0, 3.0);
-        let debug_str = format!("{:?}", sprite);
-        assert!(debug_str.contains("NaturaSprite"));
+    fn test_fixed_timestep_catches_up_after_lag_spike() {
+        // The variable timestep skips frames longer than 100 ms
+        let mut app = animation_app();
+        app.insert_resource(NaturaTimestep::Variable);
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.5)),
+                NaturaTarget::new_2d(100.0, 0.0),
+            ))
+            .id();
+        advance(&mut app, 0.5);
+        assert_eq!(app.world().get::<NaturaSprite>(entity).unwrap().x, 0.0);
+
+        // Sub-stepping catches up, but at most max_steps steps
+        let mut app = animation_app();
+        app.insert_resource(NaturaTimestep::fixed_hz(60.0, 8));
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.5)),
+                NaturaTarget::new_2d(100.0, 0.0),
+            ))
+            .id();
+        advance(&mut app, 0.5);
+
+        let mut spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.5));
+        let (mut x, mut velocity) = (0.0, 0.0);
+        for _ in 0..8 {
+            (x, velocity) = spring.update(x, velocity, 100.0, 1.0 / 60.0);
+        }
+        let sprite = app.world().get::<NaturaSprite>(entity).unwrap();
+        assert!((sprite.x - x).abs() < 1e-9);
+        assert!((sprite.x_velocity - velocity).abs() < 1e-9);
     }
 
     #[test]
-    fn test_natura_spring_debug() {
-        let spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.7));
-        let debug_str = format!("{:?}", spring);
-        assert!(debug_str.contains("NaturaSpring"));
-        assert!(debug_str.contains("angular_frequency"));
-        assert!(debug_str.contains("damping_ratio"));
+    fn test_fixed_timestep_renders_between_steps() {
+        let mut app = animation_app();
+        app.insert_resource(NaturaTimestep::fixed_hz(30.0, 4));
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.5)),
+                NaturaTarget::new_2d(100.0, 0.0),
+            ))
+            .id();
+
+        // A frame shorter than a step moves the transform but not the simulation
+        advance(&mut app, 1.0 / 120.0);
+        assert_eq!(app.world().get::<NaturaSprite>(entity).unwrap().x, 0.0);
+        let first = app.world().get::<Transform>(entity).unwrap().translation.x;
+        assert!(first > 0.0);
+
+        advance(&mut app, 1.0 / 120.0);
+        assert_eq!(app.world().get::<NaturaSprite>(entity).unwrap().x, 0.0);
+        assert!(app.world().get::<Transform>(entity).unwrap().translation.x > first);
     }
 
     #[test]
-    fn test_natura_target_debug() {
-        let target = NaturaTarget::new(1.0, 2.0, 3.0);
-        let debug_str = format!("{:?}", target);
-        assert!(debug_str.contains("NaturaTarget"));
+    fn test_fixed_timestep_is_frame_rate_independent() {
+        let rendered_after_one_second = |frame: f64| {
+            let mut app = animation_app();
+            app.insert_resource(NaturaTimestep::fixed_hz(60.0, 8));
+            let entity = app
+                .world_mut()
+                .spawn((
+                    Transform::default(),
+                    NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.5)),
+                    NaturaTarget::new_2d(100.0, 0.0),
+                ))
+                .id();
+            let (rotating, scaling) = (
+                app.world_mut().spawn((Transform::default(), NaturaRotationTarget::from_rotation_z(1.0))).id(),
+                app.world_mut().spawn((Transform::default(), NaturaScaleTarget::uniform(2.0))).id(),
+            );
+            for _ in 0..(1.0 / frame).round() as usize {
+                advance(&mut app, frame);
+            }
+            let transform = |entity| *app.world().get::<Transform>(entity).unwrap();
+            (
+                transform(entity).translation.x,
+                transform(rotating).rotation,
+                transform(scaling).scale.x,
+            )
+        };
+
+        let (x_30, rotation_30, scale_30) = rendered_after_one_second(1.0 / 30.0);
+        let (x_144, rotation_144, scale_144) = rendered_after_one_second(1.0 / 144.0);
+        assert!((x_30 - x_144).abs() < 1e-3);
+        assert!(rotation_30.angle_between(rotation_144) < 1e-4);
+        assert!((scale_30 - scale_144).abs() < 1e-4);
     }
 
-    // ==================== Easing Curve Tests ====================
+    // ==================== Tween Tests ====================
 
     #[test]
-    fn test_easing_curve_none() {
-        let easing = EasingCurve::None;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert_eq!(easing.apply(0.5), 0.5);
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_tween_follows_easing_curve_over_duration() {
+        let tween = NaturaTween::new(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0), 1.0).with_easing(EasingCurve::EaseIn);
+        let mut app = animation_app();
+        let entity = app.world_mut().spawn((Transform::default(), tween)).id();
+
+        advance(&mut app, 0.5);
+        let x = app.world().get::<Transform>(entity).unwrap().translation.x;
+        assert!((x - 25.0).abs() < 1e-4);
+
+        advance(&mut app, 0.25);
+        let x = app.world().get::<Transform>(entity).unwrap().translation.x;
+        assert!((x - 56.25).abs() < 1e-4);
+
+        advance(&mut app, 0.5);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().translation.x, 100.0);
+        assert!(app.world().get::<NaturaTween>(entity).unwrap().is_finished());
     }
 
     #[test]
-    fn test_easing_curve_ease_in() {
-        let easing = EasingCurve::EaseIn;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert!(easing.apply(0.5) < 0.5); // Should be slower at start
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_tween_delay_and_events() {
+        let tween = NaturaTween::new(Vec3::new(10.0, 0.0, 0.0), Vec3::new(20.0, 0.0, 0.0), 0.2).with_delay(0.1);
+        let mut app = animation_app();
+        let entity = app.world_mut().spawn((Transform::default(), tween)).id();
+
+        // Held at the start during the delay
+        advance(&mut app, 0.05);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().translation.x, 10.0);
+        assert_eq!(drain_events::<AnimationStarted>(&mut app), 0);
+
+        advance(&mut app, 0.1);
+        assert_eq!(drain_events::<AnimationStarted>(&mut app), 1);
+        assert_eq!(app.world().get::<NaturaTween>(entity).unwrap().state, AnimationState::Animating);
+
+        let mut completed = 0;
+        for _ in 0..10 {
+            advance(&mut app, 0.05);
+            completed += drain_events::<AnimationCompleted>(&mut app);
+        }
+        assert_eq!(completed, 1);
+        assert_eq!(drain_events::<AnimationStarted>(&mut app), 0);
+        assert_eq!(app.world().get::<NaturaTween>(entity).unwrap().state, AnimationState::Idle);
+
+        // Restarting plays it again
+        app.world_mut().get_mut::<NaturaTween>(entity).unwrap().restart();
+        advance(&mut app, 0.05);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().translation.x, 10.0);
     }
 
     #[test]
-    fn test_easing_curve_ease_out() {
-        let easing = EasingCurve::EaseOut;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert!(easing.apply(0.5) > 0.5); // Should be faster at start
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_tween_without_duration_jumps_to_target() {
+        for duration in [0.0, -1.0] {
+            let mut app = animation_app();
+            let tween = NaturaTween::new(Vec3::ZERO, Vec3::new(5.0, 0.0, 0.0), duration);
+            let entity = app.world_mut().spawn((Transform::default(), tween)).id();
+
+            // A first frame without elapsed time still applies the tween.
+            app.update();
+            assert_eq!(app.world().get::<Transform>(entity).unwrap().translation.x, 5.0);
+            assert_eq!(drain_events::<AnimationStarted>(&mut app), 1);
+            assert_eq!(drain_events::<AnimationCompleted>(&mut app), 1);
+
+            for _ in 0..3 {
+                advance(&mut app, 0.05);
+            }
+            assert_eq!(drain_events::<AnimationStarted>(&mut app), 0);
+            assert_eq!(drain_events::<AnimationCompleted>(&mut app), 0);
+            assert_eq!(app.world().get::<NaturaTween>(entity).unwrap().state, AnimationState::Idle);
+        }
     }
 
     #[test]
-    fn test_easing_curve_ease_in_out() {
-        let easing = EasingCurve::EaseInOut;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert!((easing.apply(0.5) - 0.5).abs() < 0.01); // Middle should be close to 0.5
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_tween_respects_pause_and_groups() {
+        let mut app = animation_app();
+        let entity = app.world_mut().spawn((Transform::default(), NaturaTween::new(Vec3::ZERO, Vec3::X, 1.0))).id();
+        app.world_mut().entity_mut(entity).insert(AnimationGroup(7));
+        app.world_mut().resource_mut::<PausedGroups>().pause(7);
+        advance(&mut app, 0.5);
+        assert_eq!(app.world().get::<NaturaTween>(entity).unwrap().elapsed, 0.0);
+
+        app.world_mut().resource_mut::<PausedGroups>().resume(7);
+        app.world_mut().insert_resource(GlobalAnimationPaused);
+        advance(&mut app, 0.5);
+        assert_eq!(app.world().get::<NaturaTween>(entity).unwrap().elapsed, 0.0);
+
+        app.world_mut().remove_resource::<GlobalAnimationPaused>();
+        advance(&mut app, 0.5);
+        assert_eq!(app.world().get::<NaturaTween>(entity).unwrap().progress(), 0.5);
     }
 
+    // ==================== Easing Tests ====================
+
     #[test]
-    fn test_easing_curve_quadratic_in() {
-        let easing = EasingCurve::QuadraticIn;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert_eq!(easing.apply(0.5), 0.25); // 0.5^2 = 0.25
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_easing_aliases_match_natura() {
+        let aliases = [
+            (EasingCurve::None, Easing::Linear),
+            (EasingCurve::EaseIn, Easing::QuadIn),
+            (EasingCurve::QuadraticOut, Easing::QuadOut),
+            (EasingCurve::EaseInOut, Easing::QuadInOut),
+            (EasingCurve::Elastic, Easing::ElasticOut),
+            (EasingCurve::Bounce, Easing::BounceOut),
+            (EasingCurve::QuinticInOut, Easing::QuintInOut),
+            (EasingCurve::ExponentialOut, Easing::ExpoOut),
+        ];
+        for (curve, easing) in aliases {
+            assert_eq!(Easing::from(curve), easing);
+        }
+        assert_eq!(EasingCurve::EaseIn.apply(0.3), EasingCurve::QuadraticIn.apply(0.3));
     }
 
     #[test]
-    fn test_easing_curve_quadratic_out() {
-        let easing = EasingCurve::QuadraticOut;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert_eq!(easing.apply(0.5), 0.75); // 0.5 * (2 - 0.5) = 0.75
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_easing_in_out_families() {
+        let curves = [
+            EasingCurve::SineIn,
+            EasingCurve::QuarticOut,
+            EasingCurve::CircularInOut,
+            EasingCurve::BackOut,
+            EasingCurve::ElasticIn,
+            EasingCurve::ElasticInOut,
+            EasingCurve::BounceIn,
+            EasingCurve::BounceInOut,
+        ];
+        for curve in curves {
+            assert!(curve.apply(0.0).abs() < 0.001);
+            assert!((curve.apply(1.0) - 1.0).abs() < 0.001);
+        }
+        assert!(EasingCurve::BackOut.apply(0.5) > 1.0);
     }
 
     #[test]
-    fn test_easing_curve_cubic_in() {
-        let easing = EasingCurve::CubicIn;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert_eq!(easing.apply(0.5), 0.125); // 0.5^3 = 0.125
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_easing_css_and_spring_curves() {
+        let ease = EasingCurve::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+        assert!((ease.apply(0.5) - 0.8024).abs() < 0.001);
+
+        let steps = EasingCurve::Steps(4);
+        assert_eq!(steps.apply(0.3), 0.25);
+        assert_eq!(steps.apply(1.0), 1.0);
+
+        let spring = EasingCurve::spring(DurationBounce { duration: 0.5, bounce: 0.3 });
+        let EasingCurve::Spring { duration, .. } = spring else {
+            panic!("expected a spring curve");
+        };
+        assert!(duration > 0.5);
+        assert_eq!(spring.apply(1.0), 1.0);
+        assert!((0..100).any(|i| spring.apply(f64::from(i) / 100.0) > 1.0));
     }
 
     #[test]
-    fn test_easing_curve_cubic_out() {
-        let easing = EasingCurve::CubicOut;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert!((easing.apply(0.5) - 0.875).abs() < 0.001);
-        assert_eq!(easing.apply(1.0), 1.0);
+    fn test_easing_curve_does_not_affect_springs() {
+        let mut plain = animation_app();
+        plain.insert_resource(NaturaTimestep::fixed_hz(120.0, 8));
+        let plain_entity = plain
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.5)),
+                NaturaTarget::new_2d(100.0, 0.0),
+            ))
+            .id();
+        let mut eased = animation_app();
+        eased.insert_resource(NaturaTimestep::fixed_hz(120.0, 8));
+        let eased_entity = eased
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.5)),
+                NaturaTarget::new_2d(100.0, 0.0),
+            ))
+            .id();
+        eased.world_mut().entity_mut(eased_entity).insert(EasingCurve::BackOut);
+
+        for _ in 0..60 {
+            advance(&mut plain, 1.0 / 60.0);
+            advance(&mut eased, 1.0 / 60.0);
+            let plain_sprite = plain.world().get::<NaturaSprite>(plain_entity).unwrap();
+            let eased_sprite = eased.world().get::<NaturaSprite>(eased_entity).unwrap();
+            assert_eq!((plain_sprite.x, plain_sprite.x_velocity), (eased_sprite.x, eased_sprite.x_velocity));
+        }
     }
 
-    #[test]
-    fn test_easing_curve_elastic() {
-        let easing = EasingCurve::Elastic;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert_eq!(easing.apply(1.0), 1.0);
-        // Elastic should overshoot
-        assert!(easing.apply(0.9) > 1.0 || easing.apply(0.8) > 1.0);
+    // ==================== Timeline Tests ====================
+
+    fn target_of(app: &App, entity: Entity) -> Vec3 {
+        let target = app.world().get::<NaturaTarget>(entity).unwrap();
+        Vec3::new(target.x, target.y, target.z)
     }
 
     #[test]
-    fn test_easing_curve_bounce() {
-        let easing = EasingCurve::Bounce;
-        assert_eq!(easing.apply(0.0), 0.0);
-        assert!((easing.apply(1.0) - 1.0).abs() < 0.001);
-        // Bounce should have specific values at certain points
-        assert!(easing.apply(0.5) > 0.0);
+    fn test_timeline_sequence_visits_keyframes_in_order() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(20.0), DampingRatio(1.0)),
+                NaturaTimeline::sequence([
+                    Keyframe::translation(Vec3::new(100.0, 0.0, 0.0)),
+                    Keyframe::translation(Vec3::new(100.0, 50.0, 0.0))
+                        .with_spring(DurationBounce { duration: 0.4, bounce: 0.2 }),
+                ]),
+            ))
+            .id();
+
+        advance(&mut app, 1.0 / 60.0);
+        assert_eq!(target_of(&app, entity), Vec3::new(100.0, 0.0, 0.0));
+
+        let mut completed = 0;
+        let mut visited = vec![target_of(&app, entity)];
+        for _ in 0..600 {
+            advance(&mut app, 1.0 / 60.0);
+            completed += drain_events::<TimelineCompleted>(&mut app);
+            if visited.last() != Some(&target_of(&app, entity)) {
+                visited.push(target_of(&app, entity));
+            }
+        }
+
+        assert_eq!(visited, [Vec3::new(100.0, 0.0, 0.0), Vec3::new(100.0, 50.0, 0.0)]);
+        assert_eq!(completed, 1);
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert!((transform.translation - Vec3::new(100.0, 50.0, 0.0)).length() < 0.1);
+
+        let spring = app.world().get::<NaturaSpring>(entity).unwrap();
+        assert!((spring.params().to_duration_bounce().bounce - 0.2).abs() < 1e-6);
+        assert!(app.world().get::<NaturaTimeline>(entity).unwrap().is_finished());
     }
 
     #[test]
-    fn test_easing_curve_clamps_input() {
-        let easing = EasingCurve::None;
-        // Values outside 0-1 should be clamped
-        assert_eq!(easing.apply(-0.5), 0.0);
-        assert_eq!(easing.apply(1.5), 1.0);
+    fn test_timeline_eases_translation_keyframes() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(20.0), DampingRatio(1.0)),
+                NaturaTimeline::sequence([
+                    Keyframe::translation(Vec3::new(100.0, 0.0, 0.0))
+                        .with_easing(EasingCurve::Steps(2))
+                        .with_duration(1.0),
+                    Keyframe::translation(Vec3::ZERO).with_easing(EasingCurve::None),
+                ]),
+            ))
+            .id();
+        let translation = |app: &App| app.world().get::<Transform>(entity).unwrap().translation;
+        let play = |app: &mut App, frames: usize, frame_time: f64| {
+            for _ in 0..frames {
+                advance(app, frame_time);
+            }
+        };
+
+        // The keyframe starts from the current position and follows the curve
+        advance(&mut app, 1.0 / 60.0);
+        play(&mut app, 4, 0.05);
+        assert_eq!(translation(&app), Vec3::ZERO);
+        play(&mut app, 8, 0.05);
+        assert_eq!(translation(&app), Vec3::new(50.0, 0.0, 0.0));
+        assert_eq!(target_of(&app, entity), Vec3::new(50.0, 0.0, 0.0));
+        play(&mut app, 8, 0.05);
+        assert_eq!(translation(&app), Vec3::new(100.0, 0.0, 0.0));
+        assert!(app.world().get::<NaturaSprite>(entity).unwrap().is_at_rest(REST_VELOCITY_THRESHOLD));
+
+        // Without a duration, the motion lasts as long as the spring settles
+        let duration = SpringEasing::new(NaturaSpring::new(AngularFrequency(20.0), DampingRatio(1.0)).params()).duration();
+        play(&mut app, 5, duration / 10.0);
+        assert!((translation(&app) - Vec3::new(50.0, 0.0, 0.0)).length() < 1e-3);
+        play(&mut app, 6, duration / 10.0);
+        assert_eq!(translation(&app), Vec3::ZERO);
+        assert_eq!(drain_events::<TimelineCompleted>(&mut app), 1);
     }
 
-    // ==================== Animation Group Tests ====================
+    #[test]
+    fn test_timeline_holds_at_keyframes() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(20.0), DampingRatio(1.0)),
+                NaturaTimeline::sequence([
+                    Keyframe::translation(Vec3::ZERO).with_hold(1.0),
+                    Keyframe::translation(Vec3::X * 10.0),
+                ]),
+            ))
+            .id();
+
+        // The first keyframe is reached at once and held for a second
+        for _ in 0..50 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        assert_eq!(target_of(&app, entity), Vec3::ZERO);
+
+        for _ in 0..20 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        assert_eq!(target_of(&app, entity), Vec3::X * 10.0);
+    }
 
     #[test]
-    fn test_animation_group_new() {
-        let group = AnimationGroup::new(42);
-        assert_eq!(group.0, 42);
+    fn test_timeline_parallel_and_stagger() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(20.0), DampingRatio(1.0)),
+                NaturaTimeline::new(TimelineStep::stagger(
+                    0.5,
+                    [
+                        TimelineStep::parallel([
+                            Keyframe::translation(Vec3::new(20.0, 0.0, 0.0)),
+                            Keyframe::rotation(Quat::from_rotation_z(1.0)),
+                        ]),
+                        Keyframe::scale(Vec3::splat(2.0)).with_spring(HalfLife(0.05)).into(),
+                    ],
+                )),
+            ))
+            .id();
+
+        advance(&mut app, 0.25);
+        assert_eq!(target_of(&app, entity), Vec3::new(20.0, 0.0, 0.0));
+        assert_eq!(app.world().get::<NaturaRotationTarget>(entity).unwrap().0, Quat::from_rotation_z(1.0));
+        assert!(app.world().get::<NaturaScaleTarget>(entity).is_none());
+
+        // The stagger interval counts from the frame the group started
+        advance(&mut app, 0.3);
+        assert!(app.world().get::<NaturaScaleTarget>(entity).is_none());
+        advance(&mut app, 0.3);
+        assert_eq!(app.world().get::<NaturaScaleTarget>(entity).unwrap().0, Vec3::splat(2.0));
+        let scale = app.world().get::<NaturaScale>(entity).unwrap();
+        assert_eq!(scale.spring.params(), NaturaSpring::with_params(HalfLife(0.05)).params());
+
+        let mut completed = 0;
+        for _ in 0..600 {
+            advance(&mut app, 1.0 / 60.0);
+            completed += drain_events::<TimelineCompleted>(&mut app);
+        }
+        assert_eq!(completed, 1);
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert!((transform.scale - Vec3::splat(2.0)).abs().max_element() < 1e-2);
+        assert!(transform.rotation.angle_between(Quat::from_rotation_z(1.0)) < 1e-2);
     }
 
     #[test]
-    fn test_animation_group_equality() {
-        let group1 = AnimationGroup::new(1);
-        let group2 = AnimationGroup::new(1);
-        let group3 = AnimationGroup::new(2);
-        assert_eq!(group1, group2);
-        assert_ne!(group1, group3);
+    fn test_timeline_ping_pong_and_repeat() {
+        let a = Vec3::new(10.0, 0.0, 0.0);
+        let b = Vec3::new(10.0, 10.0, 0.0);
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(20.0), DampingRatio(1.0)),
+                NaturaTimeline::sequence([Keyframe::translation(a), Keyframe::translation(b)])
+                    .ping_pong()
+                    .with_repeat(3),
+            ))
+            .id();
+
+        let mut completed = 0;
+        let mut visited = vec![];
+        for _ in 0..1200 {
+            advance(&mut app, 1.0 / 60.0);
+            completed += drain_events::<TimelineCompleted>(&mut app);
+            if visited.last() != Some(&target_of(&app, entity)) {
+                visited.push(target_of(&app, entity));
+            }
+        }
+
+        // Forwards, backwards, then forwards again
+        assert_eq!(visited, [a, b, a, b]);
+        assert_eq!(completed, 1);
+        assert_eq!(app.world().get::<NaturaTimeline>(entity).unwrap().iteration(), 3);
     }
 
     #[test]
-    fn test_paused_groups_pause_resume() {
-        let mut paused = PausedGroups::default();
-        assert!(!paused.is_paused(1));
-        
-        paused.pause(1);
-        assert!(paused.is_paused(1));
-        assert!(!paused.is_paused(2));
-        
-        paused.resume(1);
-        assert!(!paused.is_paused(1));
+    fn test_timeline_looping_never_completes() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(20.0), DampingRatio(1.0)),
+                NaturaTimeline::sequence([Keyframe::translation(Vec3::X * 10.0), Keyframe::translation(Vec3::ZERO)])
+                    .looping(),
+            ))
+            .id();
+
+        let mut completed = 0;
+        for _ in 0..1200 {
+            advance(&mut app, 1.0 / 60.0);
+            completed += drain_events::<TimelineCompleted>(&mut app);
+        }
+
+        let timeline = app.world().get::<NaturaTimeline>(entity).unwrap();
+        assert_eq!(completed, 0);
+        assert!(timeline.iteration() > 1);
+        assert!(!timeline.is_finished());
     }
 
     #[test]
-    fn test_paused_groups_multiple() {
-        let mut paused = PausedGroups::default();
-        paused.pause(1);
-        paused.pause(2);
-        paused.pause(3);
-        
-        assert!(paused.is_paused(1));
-        assert!(paused.is_paused(2));
-        assert!(paused.is_paused(3));
-        assert!(!paused.is_paused(4));
-        
-        paused.resume(2);
-        assert!(paused.is_paused(1));
-        assert!(!paused.is_paused(2));
-        assert!(paused.is_paused(3));
+    fn test_timeline_honors_pausing() {
+        let mut app = animation_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                NaturaSpringBundle::new(AngularFrequency(20.0), DampingRatio(1.0)),
+                NaturaTimeline::sequence([
+                    Keyframe::translation(Vec3::ZERO).with_hold(0.5),
+                    Keyframe::translation(Vec3::X),
+                ]),
+            ))
+            .id();
+        app.world_mut().entity_mut(entity).insert(AnimationPaused);
+        for _ in 0..60 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        assert!(app.world().get::<NaturaTarget>(entity).is_none());
+
+        app.world_mut().entity_mut(entity).remove::<AnimationPaused>();
+        for _ in 0..60 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        assert_eq!(target_of(&app, entity), Vec3::X);
     }
 
-    // ==================== Animation State Tests ====================
+    // ==================== Group Orchestration Tests ====================
 
     #[test]
-    fn test_animation_state_default() {
-        let state = AnimationState::default();
-        assert_eq!(state, AnimationState::Idle);
+    fn test_group_target_moves_all_members_and_completes_once() {
+        let mut app = animation_app();
+        let entities = [(0.0, 0.0), (10.0, 0.0), (0.0, 10.0)].map(|(x, y)| {
+            app.world_mut()
+                .spawn((
+                    Transform::default(),
+                    NaturaSpringBundle::with_position(AngularFrequency(20.0), DampingRatio(1.0), x, y, 0.0),
+                    AnimationGroup(1),
+                ))
+                .id()
+        });
+        let outsider = app
+            .world_mut()
+            .spawn((Transform::default(), NaturaSpringBundle::default(), AnimationGroup(2)))
+            .id();
+        app.world_mut().send_event(AnimateGroup::target(1, Vec3::new(50.0, 50.0, 0.0)));
+
+        let mut completed = vec![];
+        for _ in 0..300 {
+            advance(&mut app, 1.0 / 60.0);
+            completed.extend(
+                app.world_mut()
+                    .resource_mut::<Events<GroupAnimationCompleted>>()
+                    .drain()
+                    .map(|event| event.group),
+            );
+        }
+
+        assert_eq!(completed, [1]);
+        for entity in entities {
+            let transform = app.world().get::<Transform>(entity).unwrap();
+            assert!((transform.translation - Vec3::new(50.0, 50.0, 0.0)).length() < 0.1);
+        }
+        assert!(app.world().get::<NaturaTarget>(outsider).is_none());
     }
 
     #[test]
-    fn test_animation_state_equality() {
-        assert_eq!(AnimationState::Idle, AnimationState::Idle);
-        assert_eq!(AnimationState::Animating, AnimationState::Animating);
-        assert_eq!(AnimationState::JustCompleted, AnimationState::JustCompleted);
-        assert_ne!(AnimationState::Idle, AnimationState::Animating);
-    }
+    fn test_group_offset_and_spring_override() {
+        let mut app = animation_app();
+        let entities = [(0.0, 0.0), (10.0, 0.0)].map(|(x, y)| {
+            app.world_mut()
+                .spawn((
+                    Transform::default(),
+                    NaturaSpringBundle::with_position(AngularFrequency(20.0), DampingRatio(1.0), x, y, 0.0),
+                    AnimationGroup(1),
+                ))
+                .id()
+        });
+        app.world_mut().entity_mut(entities[1]).insert(NaturaTarget::new(20.0, 0.0, 0.0));
+        let params = DurationBounce { duration: 0.3, bounce: 0.0 };
+        app.world_mut().send_event(AnimateGroup::offset(1, Vec3::Y * 5.0).with_spring(params));
+        advance(&mut app, 1.0 / 60.0);
 
-    // ==================== Event Tests ====================
+        // Offsets start from the current target, or the position without one
+        assert_eq!(target_of(&app, entities[0]), Vec3::new(0.0, 5.0, 0.0));
+        assert_eq!(target_of(&app, entities[1]), Vec3::new(20.0, 5.0, 0.0));
+        for entity in entities {
+            let spring = app.world().get::<NaturaSpring>(entity).unwrap();
+            assert_eq!(spring.params(), NaturaSpring::with_params(params).params());
+        }
+    }
 
     #[test]
-    fn test_animation_started_event() {
-        let event = AnimationStarted {
-            entity: Entity::from_raw(42),
-            target: Vec3::new(100.0, 200.0, 0.0),
+    fn test_group_stagger_by_index_and_custom_order() {
+        let mut app = animation_app();
+        let entities = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].map(|(x, y)| {
+            app.world_mut()
+                .spawn((
+                    Transform::default(),
+                    NaturaSpringBundle::with_position(AngularFrequency(20.0), DampingRatio(1.0), x, y, 0.0),
+                    AnimationGroup(1),
+                ))
+                .id()
+        });
+        app.world_mut()
+            .send_event(AnimateGroup::target(1, Vec3::Z).with_stagger(GroupStagger::ByIndex { interval: 0.1 }));
+        let started = |app: &App| {
+            entities.iter().map(|entity| app.world().get::<NaturaTarget>(*entity).is_some()).collect::<Vec<_>>()
         };
-        assert_eq!(event.target, Vec3::new(100.0, 200.0, 0.0));
+
+        advance(&mut app, 0.01);
+        assert_eq!(started(&app), [true, false, false]);
+        advance(&mut app, 0.1);
+        assert_eq!(started(&app), [true, true, false]);
+        advance(&mut app, 0.1);
+        assert_eq!(started(&app), [true, true, true]);
+
+        let order = GroupStagger::Custom { order: vec![entities[2]], interval: 1.0 };
+        let members: Vec<_> = entities.iter().map(|entity| (*entity, Vec3::ZERO)).collect();
+        assert_eq!(order.delays(&members), [1.0, 2.0, 0.0]);
     }
 
     #[test]
-    fn test_animation_completed_event() {
-        let event = AnimationCompleted {
-            entity: Entity::from_raw(42),
-            final_position: Vec3::new(100.0, 200.0, 0.0),
-        };
-        assert_eq!(event.final_position, Vec3::new(100.0, 200.0, 0.0));
-    }
+    fn test_group_stagger_from_point_honors_pausing() {
+        let mut app = animation_app();
+        let entities = [(0.0, 0.0), (100.0, 0.0)].map(|(x, y)| {
+            app.world_mut()
+                .spawn((
+                    Transform::default(),
+                    NaturaSpringBundle::with_position(AngularFrequency(20.0), DampingRatio(1.0), x, y, 0.0),
+                    AnimationGroup(1),
+                ))
+                .id()
+        });
+        let stagger = GroupStagger::FromPoint { origin: Vec3::ZERO, delay_per_unit: 0.01 };
+        app.world_mut().send_event(AnimateGroup::target(1, Vec3::Y * 10.0).with_stagger(stagger));
+        advance(&mut app, 0.01);
+        assert!(app.world().get::<NaturaTarget>(entities[0]).is_some());
+        assert!(app.world().get::<NaturaTarget>(entities[1]).is_none());
 
-    // ==================== Bundle with Easing Tests ====================
+        // The far member waits while its group is paused
+        app.world_mut().resource_mut::<PausedGroups>().pause(1);
+        advance(&mut app, 0.1);
+        advance(&mut app, 0.1);
+        assert!(app.world().get::<NaturaTarget>(entities[1]).is_none());
 
-    #[test]
-    fn test_natura_spring_bundle_with_easing() {
-        let bundle = NaturaSpringBundle::with_easing(
-            AngularFrequency(8.0),
-            DampingRatio(0.5),
-            EasingCurve::EaseOut,
-        );
-        
-        assert_eq!(bundle.spring.angular_frequency, 8.0);
-        assert_eq!(bundle.spring.damping_ratio, 0.5);
-        assert_eq!(bundle.easing, EasingCurve::EaseOut);
-        assert_eq!(bundle.state, AnimationState::Idle);
+        app.world_mut().resource_mut::<PausedGroups>().resume(1);
+        let mut completed = 0;
+        for _ in 0..300 {
+            advance(&mut app, 1.0 / 60.0);
+            completed += drain_events::<GroupAnimationCompleted>(&mut app);
+        }
+        assert!(app.world().get::<NaturaTarget>(entities[1]).is_some());
+        assert_eq!(completed, 1);
     }
 
+    // ==================== Serde Tests ====================
+
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_natura_spring_bundle_includes_state_and_easing() {
-        let bundle = NaturaSpringBundle::new(AngularFrequency(6.0), DampingRatio(0.7));
-        
-        // Bundle should include default state and easing
-        assert_eq!(bundle.state, AnimationState::Idle);
-        assert_eq!(bundle.easing, EasingCurve::None);
+    fn test_serde_round_trip() {
+        let spring = NaturaSpring::new(AngularFrequency(6.0), DampingRatio(0.5));
+        let json = serde_json::to_string(&spring).unwrap();
+        assert_eq!(json, r#"{"angular_frequency":6.0,"damping_ratio":0.5}"#);
+        let back: NaturaSpring = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.angular_frequency, 6.0);
+        assert_eq!(back.damping_ratio, 0.5);
+
+        let target = NaturaTarget::new(1.0, 2.0, 3.0);
+        let back: NaturaTarget = serde_json::from_str(&serde_json::to_string(&target).unwrap()).unwrap();
+        assert_eq!((back.x, back.y, back.z), (1.0, 2.0, 3.0));
+
+        let easing = EasingCurve::EaseInOut;
+        let back: EasingCurve = serde_json::from_str(&serde_json::to_string(&easing).unwrap()).unwrap();
+        assert_eq!(back, easing);
+
+        let easing = EasingCurve::cubic_bezier(0.42, 0.0, 0.58, 1.0);
+        let back: EasingCurve = serde_json::from_str(&serde_json::to_string(&easing).unwrap()).unwrap();
+        assert_eq!(back, easing);
+
+        let timeline = NaturaTimeline::sequence([Keyframe::translation(Vec3::X).with_hold(0.5)]).ping_pong().looping();
+        let back: NaturaTimeline = serde_json::from_str(&serde_json::to_string(&timeline).unwrap()).unwrap();
+        assert_eq!(back, timeline);
+
+        let mut groups = PausedGroups::default();
+        groups.pause(3);
+        let back: PausedGroups = serde_json::from_str(&serde_json::to_string(&groups).unwrap()).unwrap();
+        assert!(back.is_paused(3));
     }
 
-    // ==================== Global Pause Resource Tests ====================
+    // ==================== Preset Tests ====================
 
+    #[cfg(feature = "presets")]
     #[test]
-    fn test_global_animation_paused_default() {
-        let _paused = GlobalAnimationPaused::default();
-        // Just ensure it can be created
-    }
+    fn test_presets_loader_parses_ron_and_toml() {
+        let presets = NaturaPresetsLoader::parse(b"{ \"gentle\": (duration: 0.3, bounce: 0.1) }", "ron").unwrap();
+        assert_eq!(presets.get("gentle"), Some(SpringParams::from(DurationBounce { duration: 0.3, bounce: 0.1 })));
+        assert_eq!(presets.get("wobbly"), Some(SpringPreset::Wobbly.params()));
 
-    // ==================== Animation Paused Component Tests ====================
+        let presets = NaturaPresetsLoader::parse(b"button = \"snappy\"", "toml").unwrap();
+        assert_eq!(presets.get("button"), Some(SpringPreset::Snappy.params()));
+
+        assert!(matches!(
+            NaturaPresetsLoader::parse(b"button = 1.0", "toml"),
+            Err(NaturaPresetsLoaderError::Toml(_))
+        ));
+    }
 
+    #[cfg(feature = "presets")]
     #[test]
-    fn test_animation_paused_default() {
-        let _paused = AnimationPaused::default();
-        // Just ensure it can be created
+    fn test_spring_preset_follows_asset_changes() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default(), NaturaAnimationPlugin, NaturaPresetsPlugin))
+            .init_resource::<Time>();
+
+        let presets = NaturaPresetsLoader::parse(b"button = { angular_frequency = 9.0, damping_ratio = 0.8 }", "toml").unwrap();
+        let handle = app.world_mut().resource_mut::<Assets<NaturaPresets>>().add(presets);
+        let entity = app
+            .world_mut()
+            .spawn((NaturaSpring::default(), NaturaSpringPreset::new(handle.clone(), "button")))
+            .id();
+
+        app.update();
+        let spring = app.world().get::<NaturaSpring>(entity).unwrap();
+        assert_eq!((spring.angular_frequency, spring.damping_ratio), (9.0, 0.8));
+
+        // Modifying the asset, e.g. when the file is hot-reloaded, updates the spring
+        app.world_mut()
+            .resource_mut::<Assets<NaturaPresets>>()
+            .get_mut(&handle)
+            .unwrap()
+            .insert("button", SpringPreset::Stiff);
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().get::<NaturaSpring>(entity).unwrap().params(),
+            NaturaSpring::with_params(SpringPreset::Stiff).params()
+        );
+
+        // Switching to another preset by name applies it right away
+        app.world_mut().get_mut::<NaturaSpringPreset>(entity).unwrap().name = "molasses".to_string();
+        app.update();
+        assert_eq!(
+            app.world().get::<NaturaSpring>(entity).unwrap().params(),
+            NaturaSpring::with_params(SpringPreset::Molasses).params()
+        );
     }
 }
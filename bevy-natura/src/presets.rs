@@ -0,0 +1,190 @@
+//! Spring presets loaded from RON or TOML asset files, with hot reloading.
+//!
+//! Enabled with the `presets` feature. Enable Bevy's `file_watcher` feature
+//! to reload the presets while the app is running.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use natura::SpringPresets;
+
+use crate::{natura_animation_system, NaturaSpring};
+
+/// Plugin that loads `NaturaPresets` assets from `.springs.ron` and
+/// `.springs.toml` files and keeps `NaturaSpring`s with a `NaturaSpringPreset`
+/// in sync with them, including when the file is hot-reloaded.
+///
+/// Requires Bevy's `AssetPlugin`.
+///
+/// # Usage
+///
+/// ```rust,ignore
+/// use bevy::prelude::*;
+/// use bevy_natura::{NaturaPresetsPlugin, NaturaPresets, NaturaSpring, NaturaSpringPreset};
+///
+/// // assets/ui.springs.toml:
+/// // button = { duration = 0.3, bounce = 0.1 }
+/// // dialog = "gentle"
+///
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let presets: Handle<NaturaPresets> = asset_server.load("ui.springs.toml");
+///     commands.spawn((
+///         NaturaSpring::default(),
+///         NaturaSpringPreset::new(presets, "button"),
+///     ));
+/// }
+/// ```
+pub struct NaturaPresetsPlugin;
+
+/// Asset holding named spring presets. Loaded files extend the built-in
+/// presets (`gentle`, `wobbly`, `stiff`, `slow`, `molasses`, `snappy`), and
+/// override them when they use the same name.
+#[derive(Asset, TypePath, Default, Debug, Clone, PartialEq, Deref, DerefMut)]
+pub struct NaturaPresets(pub SpringPresets);
+
+impl From<SpringPresets> for NaturaPresets {
+    fn from(presets: SpringPresets) -> Self {
+        NaturaPresets(presets)
+    }
+}
+
+/// Component that sets the parameters of the entity's `NaturaSpring` from a
+/// named preset, and updates them whenever the presets asset changes.
+#[derive(Component, Debug, Clone)]
+pub struct NaturaSpringPreset {
+    /// The presets asset to look the name up in
+    pub presets: Handle<NaturaPresets>,
+    /// Name of the preset
+    pub name: String,
+}
+
+impl NaturaSpringPreset {
+    /// Creates a new component using the preset `name` from `presets`.
+    #[must_use]
+    pub fn new(presets: Handle<NaturaPresets>, name: impl Into<String>) -> Self {
+        NaturaSpringPreset {
+            presets,
+            name: name.into(),
+        }
+    }
+}
+
+/// Loader for `NaturaPresets` assets. Files ending in `.ron` are parsed as
+/// RON, all others as TOML.
+#[derive(Default)]
+pub struct NaturaPresetsLoader;
+
+/// Error returned when a presets file can't be loaded.
+#[derive(Debug)]
+pub enum NaturaPresetsLoaderError {
+    /// The file couldn't be read
+    Io(std::io::Error),
+    /// The file isn't valid UTF-8
+    Utf8(std::str::Utf8Error),
+    /// The file isn't a valid RON presets file
+    Ron(ron::error::SpannedError),
+    /// The file isn't a valid TOML presets file
+    Toml(toml::de::Error),
+}
+
+impl std::fmt::Display for NaturaPresetsLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NaturaPresetsLoaderError::Io(error) => write!(f, "could not read spring presets: {error}"),
+            NaturaPresetsLoaderError::Utf8(error) => write!(f, "spring presets are not valid UTF-8: {error}"),
+            NaturaPresetsLoaderError::Ron(error) => write!(f, "invalid RON spring presets: {error}"),
+            NaturaPresetsLoaderError::Toml(error) => write!(f, "invalid TOML spring presets: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for NaturaPresetsLoaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NaturaPresetsLoaderError::Io(error) => Some(error),
+            NaturaPresetsLoaderError::Utf8(error) => Some(error),
+            NaturaPresetsLoaderError::Ron(error) => Some(error),
+            NaturaPresetsLoaderError::Toml(error) => Some(error),
+        }
+    }
+}
+
+impl NaturaPresetsLoader {
+    /// Parses presets from the contents of a file, as RON if `extension` is
+    /// `ron` and as TOML otherwise, on top of the built-in presets.
+    pub fn parse(bytes: &[u8], extension: &str) -> Result<NaturaPresets, NaturaPresetsLoaderError> {
+        let source = std::str::from_utf8(bytes).map_err(NaturaPresetsLoaderError::Utf8)?;
+        let loaded = if extension == "ron" {
+            SpringPresets::from_ron(source).map_err(NaturaPresetsLoaderError::Ron)?
+        } else {
+            SpringPresets::from_toml(source).map_err(NaturaPresetsLoaderError::Toml)?
+        };
+
+        let mut presets = SpringPresets::default();
+        presets.extend(loaded);
+        Ok(NaturaPresets(presets))
+    }
+}
+
+impl AssetLoader for NaturaPresetsLoader {
+    type Asset = NaturaPresets;
+    type Settings = ();
+    type Error = NaturaPresetsLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.map_err(NaturaPresetsLoaderError::Io)?;
+        let extension = load_context
+            .path()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+        Self::parse(&bytes, extension)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["springs.ron", "springs.toml"]
+    }
+}
+
+/// System that applies presets to springs when a `NaturaSpringPreset` is
+/// added or changed, and when its presets asset is loaded or modified.
+fn apply_spring_presets(
+    mut events: EventReader<AssetEvent<NaturaPresets>>,
+    presets: Res<Assets<NaturaPresets>>,
+    mut query: Query<(Ref<NaturaSpringPreset>, &mut NaturaSpring)>,
+) {
+    let changed_assets: Vec<AssetId<NaturaPresets>> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (preset, mut spring) in query.iter_mut() {
+        if !preset.is_changed() && !changed_assets.contains(&preset.presets.id()) {
+            continue;
+        }
+
+        let Some(params) = presets.get(&preset.presets).and_then(|presets| presets.get(&preset.name)) else {
+            continue;
+        };
+        if spring.params() != params {
+            *spring = NaturaSpring::with_params(params);
+        }
+    }
+}
+
+impl Plugin for NaturaPresetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<NaturaPresets>()
+            .init_asset_loader::<NaturaPresetsLoader>()
+            .add_systems(Update, apply_spring_presets.before(natura_animation_system));
+    }
+}
@@ -47,16 +47,14 @@ impl Game for NaturaExample {
     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
         frame.clear(Color::BLACK);
         let mut mesh = Mesh::new();
-        let (sprite_x, sprite_x_velocity) =
-            self.spring
-                .update(self.sprite.x, self.sprite.x_velocity, TARGET_X);
+        let ([sprite_x, sprite_y], [sprite_x_velocity, sprite_y_velocity]) = self.spring.update_value(
+            [self.sprite.x, self.sprite.y],
+            [self.sprite.x_velocity, self.sprite.y_velocity],
+            [TARGET_X, TARGET_Y],
+        );
         self.sprite.x = sprite_x;
-        self.sprite.x_velocity = sprite_x_velocity;
-
-        let (sprite_y, sprite_y_velocity) =
-            self.spring
-                .update(self.sprite.y, self.sprite.y_velocity, TARGET_Y);
         self.sprite.y = sprite_y;
+        self.sprite.x_velocity = sprite_x_velocity;
         self.sprite.y_velocity = sprite_y_velocity;
 
         mesh.fill(
@@ -17,12 +17,15 @@ fn main() {
     let mut spring = Spring::new(DeltaTime(fps), AngularFrequency(6.0), DampingRatio(0.5));
 
     loop {
-        let (sprite_x, sprite_x_velocity) = spring.update(sprite.x, sprite.x_velocity, TARGET_X);
+        // Update both axes in a single call.
+        let ((sprite_x, sprite_y), (sprite_x_velocity, sprite_y_velocity)) = spring.update_value(
+            (sprite.x, sprite.y),
+            (sprite.x_velocity, sprite.y_velocity),
+            (TARGET_X, TARGET_Y),
+        );
         sprite.x = sprite_x;
-        sprite.x_velocity = sprite_x_velocity;
-
-        let (sprite_y, sprite_y_velocity) = spring.update(sprite.y, sprite.y_velocity, TARGET_Y);
         sprite.y = sprite_y;
+        sprite.x_velocity = sprite_x_velocity;
         sprite.y_velocity = sprite_y_velocity;
 
         sleep(Duration::from_millis(10000));
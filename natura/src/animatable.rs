@@ -0,0 +1,325 @@
+//! This file defines the `Animatable` trait, which lets a single [Spring]
+//! advance vector-valued state (points, arrays, tuples, or your own math types)
+//! in one call instead of once per axis.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Spring, Point, DeltaTime, AngularFrequency, DampingRatio};
+//! let mut spring = Spring::new(DeltaTime(natura::fps(60)), AngularFrequency(6.0), DampingRatio(0.5));
+//!
+//! let pos = Point { x: 0.0, y: 0.0, z: 0.0 };
+//! let vel = Point::default();
+//! let target = Point { x: 50.0, y: 100.0, z: 0.0 };
+//!
+//! let (pos, vel) = spring.update_value(pos, vel, target);
+//! ```
+//!
+//! [Spring]: crate::Spring
+use crate::{Float, Point, Vector};
+
+/// Animatable is implemented by any value a [Spring](crate::Spring) can move.
+/// Springs only need to add values together and scale them by a coefficient,
+/// so implementing `add` and `scale` is enough to animate a custom type.
+///
+/// `F` is the [Float] type of the coefficients, i.e. the precision of the
+/// spring that moves the value. It defaults to `f64`; a `Spring<f32>` moves
+/// `Animatable<f32>` values without converting them to `f64`.
+///
+/// Implementations are provided for every [Float] type, [Point], [Vector],
+/// `[T; N]` and tuples of up to four animatable values. The `glam`,
+/// `nalgebra` and `mint` cargo features add implementations for the vector
+/// types of those crates.
+///
+/// # Example:
+///
+/// ```
+/// use natura::Animatable;
+///
+/// #[derive(Clone, Copy)]
+/// struct Size {
+///     width: f64,
+///     height: f64,
+/// }
+///
+/// impl Animatable for Size {
+///     fn add(self, other: Self) -> Self {
+///         Size { width: self.width + other.width, height: self.height + other.height }
+///     }
+///
+///     fn scale(self, factor: f64) -> Self {
+///         Size { width: self.width * factor, height: self.height * factor }
+///     }
+/// }
+/// ```
+pub trait Animatable<F: Float = f64>: Copy {
+    /// add returns the component-wise sum of two values.
+    fn add(self, other: Self) -> Self;
+
+    /// scale returns the value with every component multiplied by `factor`.
+    fn scale(self, factor: F) -> Self;
+
+    /// sub returns the component-wise difference of two values.
+    fn sub(self, other: Self) -> Self {
+        self.add(other.scale(-F::ONE))
+    }
+}
+
+impl<F: Float> Animatable<F> for F {
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    #[inline(always)]
+    fn scale(self, factor: F) -> Self {
+        self * factor
+    }
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+}
+
+impl<F: Float> Animatable<F> for Point<F> {
+    fn add(self, other: Self) -> Self {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+
+    fn scale(self, factor: F) -> Self {
+        Point {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+}
+
+impl<F: Float> Animatable<F> for Vector<F> {
+    fn add(self, other: Self) -> Self {
+        Vector {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+
+    fn scale(self, factor: F) -> Self {
+        Vector {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+}
+
+impl<F: Float, T: Animatable<F>, const N: usize> Animatable<F> for [T; N] {
+    fn add(mut self, other: Self) -> Self {
+        for (a, b) in self.iter_mut().zip(other) {
+            *a = a.add(b);
+        }
+        self
+    }
+
+    fn scale(mut self, factor: F) -> Self {
+        for a in self.iter_mut() {
+            *a = a.scale(factor);
+        }
+        self
+    }
+}
+
+macro_rules! impl_animatable_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<F: Float, $($name: Animatable<F>),+> Animatable<F> for ($($name,)+) {
+            fn add(self, other: Self) -> Self {
+                ($(self.$idx.add(other.$idx),)+)
+            }
+
+            fn scale(self, factor: F) -> Self {
+                ($(self.$idx.scale(factor),)+)
+            }
+        }
+    };
+}
+
+impl_animatable_tuple!(A 0, B 1);
+impl_animatable_tuple!(A 0, B 1, C 2);
+impl_animatable_tuple!(A 0, B 1, C 2, D 3);
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::Animatable;
+    use glam::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec3A, Vec4};
+
+    /// Implements [Animatable] for math types that already support `+` and
+    /// multiplication by their own scalar type.
+    macro_rules! impl_animatable_ops {
+        ($scalar:ty => $($ty:ty),+) => {
+            $(
+                impl Animatable<$scalar> for $ty {
+                    #[inline(always)]
+                    fn add(self, other: Self) -> Self {
+                        self + other
+                    }
+
+                    #[inline(always)]
+                    fn scale(self, factor: $scalar) -> Self {
+                        self * factor
+                    }
+
+                    #[inline(always)]
+                    fn sub(self, other: Self) -> Self {
+                        self - other
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_animatable_ops!(f32 => Vec2, Vec3, Vec3A, Vec4);
+    impl_animatable_ops!(f64 => DVec2, DVec3, DVec4);
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use super::Animatable;
+    use nalgebra::SVector;
+
+    impl<const D: usize> Animatable<f64> for SVector<f64, D> {
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            self + other
+        }
+
+        #[inline(always)]
+        fn scale(self, factor: f64) -> Self {
+            self * factor
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            self - other
+        }
+    }
+
+    impl<const D: usize> Animatable<f32> for SVector<f32, D> {
+        #[inline(always)]
+        fn add(self, other: Self) -> Self {
+            self + other
+        }
+
+        #[inline(always)]
+        fn scale(self, factor: f32) -> Self {
+            self * factor
+        }
+
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self {
+            self - other
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_impls {
+    use super::Animatable;
+    use crate::Float;
+
+    macro_rules! impl_animatable_mint {
+        ($($ty:ident { $($field:ident),+ }),+) => {
+            $(
+                impl<F: Float, T: Animatable<F>> Animatable<F> for mint::$ty<T> {
+                    fn add(self, other: Self) -> Self {
+                        mint::$ty { $($field: self.$field.add(other.$field)),+ }
+                    }
+
+                    fn scale(self, factor: F) -> Self {
+                        mint::$ty { $($field: self.$field.scale(factor)),+ }
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_animatable_mint!(
+        Vector2 { x, y },
+        Vector3 { x, y, z },
+        Vector4 { x, y, z, w },
+        Point2 { x, y },
+        Point3 { x, y, z }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AngularFrequency, Animatable, DampingRatio, DeltaTime, Point, Spring};
+
+    #[test]
+    fn test_array_matches_scalar_updates() {
+        let mut spring = Spring::new(
+            DeltaTime(crate::fps(60)),
+            AngularFrequency(6.0),
+            DampingRatio(0.5),
+        );
+
+        let mut pos = [0.0, 10.0, -5.0];
+        let mut vel = [0.0, 1.0, 2.0];
+        let target = [50.0, 100.0, 0.0];
+
+        let mut scalars = [(0.0, 0.0), (10.0, 1.0), (-5.0, 2.0)];
+
+        for _ in 0..60 {
+            (pos, vel) = spring.update_value(pos, vel, target);
+
+            for (i, (p, v)) in scalars.iter_mut().enumerate() {
+                (*p, *v) = spring.update(*p, *v, target[i]);
+            }
+        }
+
+        for (i, (p, v)) in scalars.iter().enumerate() {
+            assert!(relative_eq!(pos[i], *p, epsilon = 1e-9));
+            assert!(relative_eq!(vel[i], *v, epsilon = 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_point_converges_to_target() {
+        let mut spring = Spring::new(
+            DeltaTime(crate::fps(60)),
+            AngularFrequency(6.0),
+            DampingRatio(1.0),
+        );
+
+        let mut pos = Point::default();
+        let mut vel = Point::default();
+        let target = Point {
+            x: 50.0,
+            y: 100.0,
+            z: 25.0,
+        };
+
+        for _ in 0..600 {
+            (pos, vel) = spring.update_value(pos, vel, target);
+        }
+
+        assert!(relative_eq!(pos.x, target.x, epsilon = 1e-3));
+        assert!(relative_eq!(pos.y, target.y, epsilon = 1e-3));
+        assert!(relative_eq!(pos.z, target.z, epsilon = 1e-3));
+    }
+
+    #[test]
+    fn test_tuple_add_scale_sub() {
+        let a = (1.0, 2.0_f32);
+        let b = (3.0, 4.0_f32);
+
+        assert_eq!(a.add(b), (4.0, 6.0));
+        assert_eq!(a.scale(2.0), (2.0, 4.0));
+        assert_eq!(b.sub(a), (2.0, 2.0));
+    }
+}
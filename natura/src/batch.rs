@@ -0,0 +1,286 @@
+//! This file defines batch updates that advance thousands of values sharing
+//! the same [Spring] in one call. Values are processed in chunks as wide as a
+//! SIMD register (four `f64` or eight `f32`), written as portable code the
+//! compiler vectorizes. On x86_64 the widest instruction set available at
+//! runtime (AVX2 or the SSE2 baseline) is picked automatically when the `std`
+//! feature is enabled.
+//!
+//! For `f64` values, batch updates give bit-for-bit the same results as
+//! [Spring::update].
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Spring, SpringBatch, DeltaTime, AngularFrequency, DampingRatio};
+//! let spring = Spring::new(DeltaTime(natura::fps(60)), AngularFrequency(6.0), DampingRatio(0.5));
+//!
+//! // Positions, velocities and targets as separate slices:
+//! let mut positions = vec![0.0_f32; 10_000];
+//! let mut velocities = vec![0.0_f32; 10_000];
+//! let targets = vec![100.0_f32; 10_000];
+//! spring.update_batch(&mut positions, &mut velocities, &targets);
+//!
+//! // Or kept together in a struct of arrays:
+//! let mut batch = SpringBatch::new();
+//! batch.push(0.0, 0.0, 50.0);
+//! batch.push(10.0, 0.0, 50.0);
+//! batch.update(&spring);
+//! ```
+//!
+//! [Spring]: crate::Spring
+//! [Spring::update]: crate::Spring::update
+use crate::{Float, Spring};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+/// BatchScalar is implemented by the float types [Spring::update_batch] can
+/// advance: `f32` and `f64`.
+pub trait BatchScalar:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + private::Sealed
+{
+    /// LANES is the number of values processed together, matching the width
+    /// of a 256-bit SIMD register.
+    const LANES: usize;
+
+    /// from_f64 converts a spring coefficient to this type.
+    fn from_f64(value: f64) -> Self;
+}
+
+impl BatchScalar for f64 {
+    const LANES: usize = 4;
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+impl BatchScalar for f32 {
+    const LANES: usize = 8;
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for f64 {}
+    impl Sealed for f32 {}
+}
+
+/// SpringBatch stores positions, velocities and targets of many values as a
+/// struct of arrays, ready to be advanced by a single [Spring].
+#[cfg(feature = "alloc")]
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringBatch<T> {
+    pub positions: Vec<T>,
+    pub velocities: Vec<T>,
+    pub targets: Vec<T>,
+}
+
+impl<F: Float> Spring<F> {
+    /// update_batch updates every position and velocity against the target
+    /// at the same index, giving the same results as calling [Spring::update]
+    /// for each of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices do not have the same length.
+    pub fn update_batch<T: BatchScalar>(
+        &self,
+        positions: &mut [T],
+        velocities: &mut [T],
+        targets: &[T],
+    ) {
+        assert_eq!(positions.len(), velocities.len());
+        assert_eq!(positions.len(), targets.len());
+
+        let coefficients = self.coefficients().map(T::from_f64);
+
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // SAFETY: the CPU supports AVX2, checked above.
+                unsafe { update_avx2(coefficients, positions, velocities, targets) };
+                return;
+            }
+        }
+
+        update_portable(coefficients, positions, velocities, targets);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BatchScalar> SpringBatch<T> {
+    /// new creates an empty batch.
+    pub fn new() -> Self {
+        SpringBatch {
+            positions: Vec::new(),
+            velocities: Vec::new(),
+            targets: Vec::new(),
+        }
+    }
+
+    /// with_capacity creates an empty batch with room for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SpringBatch {
+            positions: Vec::with_capacity(capacity),
+            velocities: Vec::with_capacity(capacity),
+            targets: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// push adds a value and returns its index.
+    pub fn push(&mut self, position: T, velocity: T, target: T) -> usize {
+        self.positions.push(position);
+        self.velocities.push(velocity);
+        self.targets.push(target);
+        self.positions.len() - 1
+    }
+
+    /// len returns the number of values in the batch.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// is_empty reports whether the batch holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// update advances every value in the batch with the given spring.
+    pub fn update<F: Float>(&mut self, spring: &Spring<F>) {
+        spring.update_batch(&mut self.positions, &mut self.velocities, &self.targets);
+    }
+}
+
+/// update_avx2 is [update_portable] compiled with AVX2 enabled, so the
+/// compiler emits 256-bit instructions for it.
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn update_avx2<T: BatchScalar>(
+    coefficients: [T; 4],
+    positions: &mut [T],
+    velocities: &mut [T],
+    targets: &[T],
+) {
+    update_portable(coefficients, positions, velocities, targets);
+}
+
+/// update_portable advances values in chunks of [BatchScalar::LANES], which
+/// the compiler turns into SIMD instructions, then handles the remainder one
+/// by one.
+#[inline(always)]
+fn update_portable<T: BatchScalar>(
+    coefficients: [T; 4],
+    positions: &mut [T],
+    velocities: &mut [T],
+    targets: &[T],
+) {
+    let split = positions.len() - positions.len() % T::LANES;
+    let (pos_chunks, pos_rest) = positions.split_at_mut(split);
+    let (vel_chunks, vel_rest) = velocities.split_at_mut(split);
+    let (target_chunks, target_rest) = targets.split_at(split);
+
+    for ((pos, vel), target) in pos_chunks
+        .chunks_exact_mut(T::LANES)
+        .zip(vel_chunks.chunks_exact_mut(T::LANES))
+        .zip(target_chunks.chunks_exact(T::LANES))
+    {
+        for i in 0..T::LANES {
+            (pos[i], vel[i]) = step(coefficients, pos[i], vel[i], target[i]);
+        }
+    }
+
+    for ((pos, vel), target) in pos_rest.iter_mut().zip(vel_rest).zip(target_rest) {
+        (*pos, *vel) = step(coefficients, *pos, *vel, *target);
+    }
+}
+
+/// step advances a single value, in the same order of operations as
+/// [Spring::update_value].
+#[inline(always)]
+fn step<T: BatchScalar>(
+    [pos_pos, pos_vel, vel_pos, vel_vel]: [T; 4],
+    pos: T,
+    vel: T,
+    target: T,
+) -> (T, T) {
+    let old_pos = pos - target;
+    let new_pos = old_pos * pos_pos + vel * pos_vel + target;
+    let new_vel = old_pos * vel_pos + vel * vel_vel;
+    (new_pos, new_vel)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AngularFrequency, DampingRatio, DeltaTime, Spring, SpringBatch};
+
+    #[test]
+    fn test_batch_matches_scalar_updates() {
+        let mut spring = Spring::new(
+            DeltaTime(crate::fps(60)),
+            AngularFrequency(6.0),
+            DampingRatio(0.4),
+        );
+
+        // Not a multiple of the lane count, to exercise the remainder.
+        let count = 37;
+        let mut positions: Vec<f64> = (0..count).map(|i| i as f64 * 3.0).collect();
+        let mut velocities: Vec<f64> = (0..count).map(|i| (i % 5) as f64 - 2.0).collect();
+        let targets: Vec<f64> = (0..count).map(|i| 100.0 - i as f64).collect();
+
+        let mut expected: Vec<(f64, f64)> = positions
+            .iter()
+            .zip(&velocities)
+            .map(|(p, v)| (*p, *v))
+            .collect();
+
+        for _ in 0..120 {
+            spring.update_batch(&mut positions, &mut velocities, &targets);
+            for (i, (p, v)) in expected.iter_mut().enumerate() {
+                (*p, *v) = spring.update(*p, *v, targets[i]);
+            }
+        }
+
+        for (i, (p, v)) in expected.iter().enumerate() {
+            assert_eq!(positions[i], *p);
+            assert_eq!(velocities[i], *v);
+        }
+    }
+
+    #[test]
+    fn test_f32_batch_converges() {
+        let spring = Spring::new(
+            DeltaTime(crate::fps(60)),
+            AngularFrequency(8.0),
+            DampingRatio(1.0),
+        );
+
+        let mut batch = SpringBatch::with_capacity(1001);
+        for i in 0..1001 {
+            assert_eq!(batch.push(i as f32, 0.0, 500.0), i);
+        }
+        assert_eq!(batch.len(), 1001);
+
+        for _ in 0..600 {
+            batch.update(&spring);
+        }
+
+        for (pos, vel) in batch.positions.iter().zip(&batch.velocities) {
+            assert!((pos - 500.0).abs() < 1e-3);
+            assert!(vel.abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_length_mismatch_panics() {
+        let spring: Spring = Spring::default();
+        spring.update_batch(&mut [0.0, 1.0], &mut [0.0], &[0.0, 0.0]);
+    }
+}
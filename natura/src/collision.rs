@@ -0,0 +1,581 @@
+//! This file defines colliders that projectiles and particles bounce off.
+//! Collisions are detected continuously: the motion of every step is swept
+//! against the colliders, so fast projectiles cannot tunnel through thin
+//! walls.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Projectile, Collider, Point, Vector, DeltaTime, GRAVITY};
+//!
+//! let ground = Collider::plane(Point::default(), Vector { x: 0.0, y: 1.0, z: 0.0 })
+//!     .with_restitution(0.6)
+//!     .with_friction(0.2);
+//!
+//! let mut ball = Projectile::launch(Point { x: 0.0, y: 2.0, z: 0.0 }, Vector { x: 1.0, y: 0.0, z: 0.0 }, GRAVITY)
+//!     .with_radius(0.1)
+//!     .with_collider(ground);
+//!
+//! # fn some_update_loop(mut f: impl FnMut()) { f() }
+//! // Update on every frame.
+//! some_update_loop(|| {
+//!     ball.update(DeltaTime(natura::fps(60)));
+//!     for contact in ball.contacts() {
+//!         // play a sound based on contact.impact_velocity
+//!     }
+//! });
+//! ```
+use crate::projectile::translate;
+use crate::{Animatable, DeltaTime, Float, Point, ProjectileState, Vector};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Maximum number of bounces resolved within a single step.
+const MAX_BOUNCES: usize = 4;
+
+/// Distance a projectile is kept away from a surface after a bounce, so the
+/// next sweep does not find the same contact again.
+const CONTACT_OFFSET: f64 = 1e-7;
+
+/// Collider is a static shape with the surface properties used when a
+/// projectile bounces off it. Like [Projectile](crate::Projectile), it works
+/// with any [Float] type and defaults to `f64`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Collider<F = f64> {
+    /// geometry of the collider
+    pub shape: ColliderShape<F>,
+    /// fraction of the normal speed kept after a bounce: `0.0` stops, `1.0`
+    /// bounces back at full speed
+    pub restitution: F,
+    /// Coulomb friction coefficient slowing down motion along the surface
+    pub friction: F,
+}
+
+/// ColliderShape is the geometry of a [Collider].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColliderShape<F = f64> {
+    /// Infinite one-sided plane through `point`. Projectiles on the side
+    /// `normal` points to bounce off it; projectiles behind it pass through.
+    Plane { point: Point<F>, normal: Vector<F> },
+    /// Axis-aligned box between `min` and `max`.
+    Aabb { min: Point<F>, max: Point<F> },
+    /// Sphere around `center`.
+    Sphere { center: Point<F>, radius: F },
+    /// Line segment in the x/y plane, for 2D games. The z coordinate is
+    /// ignored, and both sides are solid.
+    Segment { start: Point<F>, end: Point<F> },
+}
+
+/// Contact describes a collision that happened during an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contact<F = f64> {
+    /// position of the projectile's center when it touched the collider
+    pub point: Point<F>,
+    /// surface normal at the contact, pointing towards the projectile
+    pub normal: Vector<F>,
+    /// velocity of the projectile right before the bounce
+    pub impact_velocity: Vector<F>,
+    /// time in seconds since the projectile was created
+    pub time: F,
+    /// index of the collider that was hit
+    pub collider: usize,
+}
+
+/// Hit is the earliest intersection of a swept point with a shape.
+struct Hit<F> {
+    /// fraction of the motion travelled before the hit, in `[0, 1]`
+    fraction: F,
+    /// surface normal at the hit
+    normal: Vector<F>,
+}
+
+impl<F: Float> Hit<F> {
+    /// earliest orders hits by how far the motion got before them.
+    fn earliest(&self, other: &Self) -> Ordering {
+        self.fraction
+            .partial_cmp(&other.fraction)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<F: Float> Collider<F> {
+    /// new creates a collider with the given shape, a restitution of `0.5`
+    /// and no friction.
+    pub fn new(shape: ColliderShape<F>) -> Self {
+        Collider {
+            shape,
+            restitution: F::from_f64(0.5),
+            friction: F::ZERO,
+        }
+    }
+
+    /// plane creates a one-sided plane collider through `point`, facing
+    /// `normal`.
+    pub fn plane(point: Point<F>, normal: Vector<F>) -> Self {
+        Collider::new(ColliderShape::Plane {
+            point,
+            normal: normal.normalized(),
+        })
+    }
+
+    /// aabb creates an axis-aligned box collider.
+    pub fn aabb(min: Point<F>, max: Point<F>) -> Self {
+        Collider::new(ColliderShape::Aabb { min, max })
+    }
+
+    /// sphere creates a sphere collider.
+    pub fn sphere(center: Point<F>, radius: F) -> Self {
+        Collider::new(ColliderShape::Sphere { center, radius })
+    }
+
+    /// segment creates a 2D line segment collider in the x/y plane.
+    pub fn segment(start: Point<F>, end: Point<F>) -> Self {
+        Collider::new(ColliderShape::Segment { start, end })
+    }
+
+    /// with_restitution returns the collider with the given restitution.
+    pub fn with_restitution(mut self, restitution: F) -> Self {
+        self.restitution = restitution;
+        self
+    }
+
+    /// with_friction returns the collider with the given friction
+    /// coefficient.
+    pub fn with_friction(mut self, friction: F) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    /// bounce returns `vector` after bouncing off a surface with the given
+    /// normal: the normal part is reflected and scaled by the restitution,
+    /// and the tangential part loses speed to friction.
+    fn bounce(&self, vector: Vector<F>, normal: Vector<F>) -> Vector<F> {
+        let normal_speed = vector.dot(normal);
+        if normal_speed >= F::ZERO {
+            return vector;
+        }
+
+        let normal_part = normal.scale(normal_speed);
+        let tangent = vector.sub(normal_part);
+        let tangent_speed = tangent.length();
+
+        // The friction impulse is limited by the normal impulse.
+        let normal_impulse = (F::ONE + self.restitution) * -normal_speed;
+        let tangent_scale = if tangent_speed > F::ZERO {
+            (F::ONE - self.friction * normal_impulse / tangent_speed).max(F::ZERO)
+        } else {
+            F::ZERO
+        };
+
+        tangent
+            .scale(tangent_scale)
+            .sub(normal_part.scale(self.restitution))
+    }
+}
+
+impl<F: Float> ColliderShape<F> {
+    /// sweep returns the earliest hit of a sphere with the given radius
+    /// moving from `from` along `motion`, ignoring shapes it starts inside of
+    /// or moves away from.
+    fn sweep(&self, from: Point<F>, motion: Vector<F>, radius: F) -> Option<Hit<F>> {
+        match *self {
+            ColliderShape::Plane { point, normal } => {
+                let approach = motion.dot(normal);
+                let distance = point.to(from).dot(normal) - radius;
+                if approach >= F::ZERO || distance < -F::from_f64(CONTACT_OFFSET) {
+                    return None;
+                }
+
+                let fraction = distance.max(F::ZERO) / -approach;
+                (fraction <= F::ONE).then_some(Hit { fraction, normal })
+            }
+            ColliderShape::Sphere { center, radius: r } => {
+                sweep_sphere(from, motion, center, r + radius)
+            }
+            ColliderShape::Aabb { min, max } => sweep_aabb(from, motion, min, max, radius),
+            ColliderShape::Segment { start, end } => {
+                sweep_segment(from, motion, start, end, radius)
+            }
+        }
+    }
+}
+
+/// sweep_sphere returns the earliest hit of a point moving along `motion`
+/// with a sphere.
+fn sweep_sphere<F: Float>(
+    from: Point<F>,
+    motion: Vector<F>,
+    center: Point<F>,
+    radius: F,
+) -> Option<Hit<F>> {
+    let two = F::from_f64(2.0);
+    let offset = center.to(from);
+    let a = motion.dot(motion);
+    let b = two * motion.dot(offset);
+    let c = offset.dot(offset) - radius * radius;
+    if a == F::ZERO || b >= F::ZERO || c < -F::from_f64(CONTACT_OFFSET) * radius {
+        return None;
+    }
+
+    let discriminant = b * b - F::from_f64(4.0) * a * c;
+    if discriminant < F::ZERO {
+        return None;
+    }
+
+    let fraction = ((-b - discriminant.sqrt()) / (two * a)).max(F::ZERO);
+    (fraction <= F::ONE).then(|| Hit {
+        fraction,
+        normal: offset.add(motion.scale(fraction)).normalized(),
+    })
+}
+
+/// sweep_aabb returns the earliest hit of a point moving along `motion` with
+/// a box grown by `radius` on every side.
+fn sweep_aabb<F: Float>(
+    from: Point<F>,
+    motion: Vector<F>,
+    min: Point<F>,
+    max: Point<F>,
+    radius: F,
+) -> Option<Hit<F>> {
+    let axes = [
+        (from.x, motion.x, min.x, max.x),
+        (from.y, motion.y, min.y, max.y),
+        (from.z, motion.z, min.z, max.z),
+    ];
+
+    // Latest entry and earliest exit over the moving axes; `None` stands for
+    // the unbounded time of an axis that is not moving.
+    let mut enter: Option<F> = None;
+    let mut exit: Option<F> = None;
+    let mut normal = Vector::default();
+
+    for (axis, (start, delta, low, high)) in axes.into_iter().enumerate() {
+        let (low, high) = (low - radius, high + radius);
+        if delta == F::ZERO {
+            if start < low || start > high {
+                return None;
+            }
+            continue;
+        }
+
+        let (near, far, sign) = if delta > F::ZERO {
+            ((low - start) / delta, (high - start) / delta, -F::ONE)
+        } else {
+            ((high - start) / delta, (low - start) / delta, F::ONE)
+        };
+
+        if enter.is_none_or(|enter| near > enter) {
+            enter = Some(near);
+            normal = Vector::default();
+            match axis {
+                0 => normal.x = sign,
+                1 => normal.y = sign,
+                _ => normal.z = sign,
+            }
+        }
+        exit = Some(exit.map_or(far, |exit| exit.min(far)));
+    }
+
+    // Not moving, starting inside the box, or missing it.
+    let (enter, exit) = enter.zip(exit)?;
+    if enter < -F::from_f64(CONTACT_OFFSET) || enter > exit || enter > F::ONE {
+        return None;
+    }
+
+    Some(Hit {
+        fraction: enter.max(F::ZERO),
+        normal,
+    })
+}
+
+/// sweep_segment returns the earliest hit of a point moving along `motion`
+/// with a 2D segment thickened by `radius`.
+fn sweep_segment<F: Float>(
+    from: Point<F>,
+    motion: Vector<F>,
+    start: Point<F>,
+    end: Point<F>,
+    radius: F,
+) -> Option<Hit<F>> {
+    let flatten = |v: Vector<F>| Vector {
+        x: v.x,
+        y: v.y,
+        z: F::ZERO,
+    };
+    let from = Point { z: F::ZERO, ..from };
+    let start = Point {
+        z: F::ZERO,
+        ..start
+    };
+    let end = Point { z: F::ZERO, ..end };
+    let motion = flatten(motion);
+
+    let along = start.to(end);
+    let length = along.length();
+    if length == F::ZERO {
+        return sweep_sphere(from, motion, start, radius);
+    }
+
+    let mut normal = Vector {
+        x: -along.y / length,
+        y: along.x / length,
+        z: F::ZERO,
+    };
+    let mut distance = start.to(from).dot(normal);
+    if distance < F::ZERO {
+        normal = normal.scale(-F::ONE);
+        distance = -distance;
+    }
+
+    let approach = motion.dot(normal);
+    if approach < F::ZERO && distance >= radius - F::from_f64(CONTACT_OFFSET) {
+        let fraction = (distance - radius).max(F::ZERO) / -approach;
+        let hit = from.add(Point {
+            x: motion.x * fraction,
+            y: motion.y * fraction,
+            z: F::ZERO,
+        });
+        let projection = start.to(hit).dot(along) / (length * length);
+        if fraction <= F::ONE && projection >= F::ZERO && projection <= F::ONE {
+            return Some(Hit { fraction, normal });
+        }
+    }
+
+    // Rounded ends of the thickened segment.
+    if radius > F::ZERO {
+        let ends = [
+            sweep_sphere(from, motion, start, radius),
+            sweep_sphere(from, motion, end, radius),
+        ];
+        return ends.into_iter().flatten().min_by(Hit::earliest);
+    }
+
+    None
+}
+
+/// resolve_collisions moves a projectile that was at `start` before the step
+/// to where it would end up after bouncing off the colliders, and records
+/// the contacts. `time` is the time at the start of the step.
+pub(crate) fn resolve_collisions<F: Float>(
+    colliders: &[Collider<F>],
+    radius: F,
+    start: Point<F>,
+    state: &mut ProjectileState<F>,
+    delta_time: DeltaTime<F>,
+    time: F,
+    contacts: &mut Vec<Contact<F>>,
+) {
+    let mut from = start;
+    let mut motion = start.to(state.position);
+    let mut velocity = state.velocity;
+    let mut travelled = F::ZERO;
+
+    for _ in 0..MAX_BOUNCES {
+        let hit = colliders
+            .iter()
+            .enumerate()
+            .filter_map(|(i, collider)| Some((i, collider.shape.sweep(from, motion, radius)?)))
+            .min_by(|(_, a), (_, b)| a.earliest(b));
+
+        let Some((index, hit)) = hit else {
+            state.position = translate(from, motion, F::ONE);
+            state.velocity = velocity;
+            return;
+        };
+
+        let collider = &colliders[index];
+        let point = translate(from, motion, hit.fraction);
+        travelled = travelled + (F::ONE - travelled) * hit.fraction;
+
+        contacts.push(Contact {
+            point,
+            normal: hit.normal,
+            impact_velocity: velocity,
+            time: time + travelled * delta_time.0,
+            collider: index,
+        });
+
+        velocity = collider.bounce(velocity, hit.normal);
+        motion = collider.bounce(motion.scale(F::ONE - hit.fraction), hit.normal);
+        from = translate(point, hit.normal, F::from_f64(CONTACT_OFFSET));
+    }
+
+    // Out of bounces: stay at the last contact rather than risk tunneling.
+    state.position = from;
+    state.velocity = velocity;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Collider, DeltaTime, Integrator, Point, Projectile, Vector};
+
+    const UP: Vector = Vector {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+
+    fn point(x: f64, y: f64, z: f64) -> Point {
+        Point { x, y, z }
+    }
+
+    fn vector(x: f64, y: f64, z: f64) -> Vector {
+        Vector { x, y, z }
+    }
+
+    #[test]
+    fn test_plane_bounce_with_restitution() {
+        let ground = Collider::plane(Point::default(), UP).with_restitution(0.5);
+        let mut projectile = Projectile::launch(
+            point(0.0, 1.0, 0.0),
+            vector(0.0, -10.0, 0.0),
+            Vector::default(),
+        )
+        .with_collider(ground);
+
+        projectile.update(DeltaTime(0.2));
+
+        // Hits after 0.1s, then travels back up for 0.1s at half the speed.
+        assert!(relative_eq!(projectile.position().y, 0.5, epsilon = 1e-6));
+        assert!(relative_eq!(projectile.velocity().y, 5.0));
+
+        let contacts = projectile.contacts();
+        assert_eq!(contacts.len(), 1);
+        assert!(relative_eq!(contacts[0].point.y, 0.0));
+        assert!(relative_eq!(contacts[0].time, 0.1));
+        assert_eq!(contacts[0].normal, UP);
+        assert_eq!(contacts[0].impact_velocity, vector(0.0, -10.0, 0.0));
+        assert_eq!(contacts[0].collider, 0);
+    }
+
+    #[test]
+    fn test_fast_projectile_does_not_tunnel() {
+        // A thin wall, crossed many times over in a single step.
+        let wall =
+            Collider::aabb(point(5.0, -10.0, -10.0), point(5.01, 10.0, 10.0)).with_restitution(1.0);
+        let mut projectile = Projectile::launch(
+            Point::default(),
+            vector(1000.0, 0.0, 0.0),
+            Vector::default(),
+        )
+        .with_collider(wall);
+
+        projectile.update(DeltaTime(0.1));
+
+        assert!(projectile.position().x < 5.0);
+        assert!(relative_eq!(projectile.velocity().x, -1000.0));
+        assert_eq!(projectile.contacts()[0].normal, vector(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_friction_slows_sliding() {
+        let ground = Collider::plane(Point::default(), UP)
+            .with_restitution(0.0)
+            .with_friction(0.5);
+        let mut projectile = Projectile::launch(
+            point(0.0, 0.5, 0.0),
+            vector(4.0, -1.0, 0.0),
+            Vector::default(),
+        )
+        .with_collider(ground);
+
+        projectile.update(DeltaTime(1.0));
+
+        // Normal impulse 1, friction impulse 0.5.
+        assert!(relative_eq!(projectile.velocity().x, 3.5));
+        assert!(relative_eq!(projectile.velocity().y, 0.0));
+        assert!(projectile.position().y >= 0.0);
+
+        // Friction never reverses motion.
+        let sticky = Collider::plane(Point::default(), UP)
+            .with_restitution(0.0)
+            .with_friction(10.0);
+        let mut projectile = Projectile::launch(
+            point(0.0, 0.5, 0.0),
+            vector(1.0, -1.0, 0.0),
+            Vector::default(),
+        )
+        .with_collider(sticky);
+        projectile.update(DeltaTime(1.0));
+        assert_eq!(projectile.velocity(), Vector::default());
+    }
+
+    #[test]
+    fn test_sphere_collider_normal() {
+        let sphere = Collider::sphere(Point::default(), 1.0).with_restitution(1.0);
+        let mut projectile = Projectile::launch(
+            point(-3.0, 0.0, 0.0),
+            vector(4.0, 0.0, 0.0),
+            Vector::default(),
+        )
+        .with_radius(0.5)
+        .with_collider(sphere);
+
+        projectile.update(DeltaTime(1.0));
+
+        let contact = projectile.contacts()[0];
+        assert!(relative_eq!(contact.point.x, -1.5));
+        assert!(relative_eq!(contact.normal.x, -1.0));
+        assert!(relative_eq!(projectile.velocity().x, -4.0));
+        assert!(relative_eq!(projectile.position().x, -4.0, epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_segment_collider_both_sides() {
+        let segment =
+            Collider::segment(point(0.0, -1.0, 0.0), point(0.0, 1.0, 0.0)).with_restitution(1.0);
+
+        for direction in [1.0, -1.0] {
+            let mut projectile = Projectile::launch(
+                point(-direction, 0.5, 0.0),
+                vector(4.0 * direction, 0.0, 0.0),
+                Vector::default(),
+            )
+            .with_collider(segment);
+            projectile.update(DeltaTime(1.0));
+
+            assert!(relative_eq!(
+                projectile.position().x,
+                -3.0 * direction,
+                epsilon = 1e-6
+            ));
+            assert!(relative_eq!(projectile.contacts()[0].normal.x, -direction));
+        }
+
+        // Passes by the end of the segment.
+        let mut miss = Projectile::launch(
+            point(-1.0, 1.5, 0.0),
+            vector(4.0, 0.0, 0.0),
+            Vector::default(),
+        )
+        .with_collider(segment);
+        miss.update(DeltaTime(1.0));
+        assert!(miss.contacts().is_empty());
+    }
+
+    #[test]
+    fn test_ball_settles_on_ground() {
+        let ground = Collider::plane(Point::default(), UP)
+            .with_restitution(0.5)
+            .with_friction(0.3);
+        let mut ball =
+            Projectile::launch(point(0.0, 5.0, 0.0), vector(2.0, 0.0, 0.0), crate::GRAVITY)
+                .with_integrator(Integrator::SemiImplicitEuler)
+                .with_radius(0.25)
+                .with_collider(ground);
+
+        let mut bounced = false;
+        for _ in 0..600 {
+            ball.update(DeltaTime(crate::fps(60)));
+            bounced |= !ball.contacts().is_empty();
+            assert!(ball.position().y >= 0.25 - 1e-6);
+        }
+
+        assert!(bounced);
+        assert!(ball.position().y < 0.3);
+    }
+}
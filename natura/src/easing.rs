@@ -0,0 +1,716 @@
+//! This file defines easing curves for duration-based animation: the Robert
+//! Penner family (sine, quad, cubic, quart, quint, expo, circ, back, elastic
+//! and bounce, each with in, out and in-out variants), CSS-compatible
+//! `cubic-bezier(x1, y1, x2, y2)` and `steps(n)` timing functions, and curves
+//! shaped like the motion of a spring.
+//!
+//! An easing maps the normalized time of an animation (0.0 at the start, 1.0
+//! at the end) to its normalized progress.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Easing, CubicBezier, StepPosition, SpringEasing, SpringParams, DurationBounce};
+//!
+//! let eased = Easing::CubicOut.apply(0.5);
+//!
+//! // CSS `ease-in-out` and `cubic-bezier(0.34, 1.56, 0.64, 1)`:
+//! let css = Easing::from(CubicBezier::EASE_IN_OUT);
+//! let overshoot = Easing::cubic_bezier(0.34, 1.56, 0.64, 1.0);
+//!
+//! // CSS `steps(4, jump-end)`:
+//! let steps = Easing::steps(4, StepPosition::JumpEnd);
+//! assert_eq!(steps.apply(0.3), 0.25);
+//!
+//! // A curve that moves like a bouncy spring, lasting until it settles:
+//! let spring = SpringEasing::new(DurationBounce { duration: 0.5, bounce: 0.3 });
+//! let duration = spring.duration();
+//! let progress = Easing::from(spring).apply(0.5);
+//! ```
+#[cfg(not(feature = "std"))]
+use crate::{math::Math, Float};
+use crate::{Spring, SpringParams, SpringTrajectory};
+use core::f64::consts::{LN_2, PI};
+
+/// Distance from the end, relative to the whole motion, within which a
+/// spring easing is considered settled.
+const SPRING_SETTLING_TOLERANCE: f64 = 0.001;
+
+/// Number of undamped periods a spring easing lasts when it never settles.
+const UNDAMPED_PERIODS: f64 = 4.0;
+
+/// Tolerance of the cubic bézier solver.
+const BEZIER_EPSILON: f64 = 1e-7;
+
+/// Overshoot of the back easings, the constant from Robert Penner's
+/// equations.
+const BACK_OVERSHOOT: f64 = 1.70158;
+
+/// Easing is a curve that shapes the progress of a duration-based
+/// animation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    SineIn,
+    SineOut,
+    SineInOut,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    /// Pulls back slightly before moving forward.
+    BackIn,
+    /// Overshoots slightly before settling.
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// A CSS `cubic-bezier()` timing function.
+    CubicBezier(CubicBezier),
+    /// A CSS `steps()` timing function.
+    Steps(Steps),
+    /// The motion of a spring released from rest.
+    Spring(SpringEasing),
+}
+
+/// CubicBezier is a CSS `cubic-bezier(x1, y1, x2, y2)` timing function: a
+/// cubic bézier curve from (0, 0) to (1, 1) with control points (x1, y1)
+/// and (x2, y2). The x coordinates are clamped to [0, 1] so the curve is a
+/// function of time; the y coordinates may exceed it to overshoot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+
+/// StepPosition is where the jumps of a CSS `steps()` timing function happen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepPosition {
+    /// The first jump happens at the start (CSS `jump-start` or `start`).
+    JumpStart,
+    /// The last jump happens at the end (CSS `jump-end` or `end`).
+    #[default]
+    JumpEnd,
+    /// No jump at the start or end; the curve holds 0 and 1 for one step each
+    /// (CSS `jump-none`).
+    JumpNone,
+    /// Jumps at both the start and the end (CSS `jump-both`).
+    JumpBoth,
+}
+
+/// Steps is a CSS `steps(count, position)` timing function, which divides
+/// the animation into `count` equal intervals of constant progress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Steps {
+    pub count: u32,
+    pub position: StepPosition,
+}
+
+/// SpringEasing is an easing shaped like the motion of a spring released
+/// from rest at 0 towards 1, compressed so that `duration` seconds of motion
+/// fit into the normalized time. Under-damped springs overshoot past 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringEasing {
+    params: SpringParams,
+    duration: f64,
+}
+
+impl Easing {
+    /// apply returns the progress at normalized time `t`, which is clamped
+    /// to [0, 1].
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - power_out(t, 2),
+            Easing::QuadInOut => power_in_out(t, 2),
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - power_out(t, 3),
+            Easing::CubicInOut => power_in_out(t, 3),
+            Easing::QuartIn => t * t * t * t,
+            Easing::QuartOut => 1.0 - power_out(t, 4),
+            Easing::QuartInOut => power_in_out(t, 4),
+            Easing::QuintIn => t * t * t * t * t,
+            Easing::QuintOut => 1.0 - power_out(t, 5),
+            Easing::QuintInOut => power_in_out(t, 5),
+            Easing::ExpoIn => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    exp2(10.0 * t - 10.0)
+                }
+            }
+            Easing::ExpoOut => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - exp2(-10.0 * t)
+                }
+            }
+            Easing::ExpoInOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t < 0.5 {
+                    exp2(20.0 * t - 10.0) / 2.0
+                } else {
+                    (2.0 - exp2(-20.0 * t + 10.0)) / 2.0
+                }
+            }
+            Easing::CircIn => 1.0 - (1.0 - t * t).sqrt(),
+            Easing::CircOut => (1.0 - (t - 1.0) * (t - 1.0)).sqrt(),
+            Easing::CircInOut => {
+                if t < 0.5 {
+                    (1.0 - (1.0 - 4.0 * t * t).sqrt()) / 2.0
+                } else {
+                    let u = -2.0 * t + 2.0;
+                    ((1.0 - u * u).sqrt() + 1.0) / 2.0
+                }
+            }
+            Easing::BackIn => {
+                let c1 = BACK_OVERSHOOT;
+                (c1 + 1.0) * t * t * t - c1 * t * t
+            }
+            Easing::BackOut => {
+                let c1 = BACK_OVERSHOOT;
+                let u = t - 1.0;
+                1.0 + (c1 + 1.0) * u * u * u + c1 * u * u
+            }
+            Easing::BackInOut => {
+                let c2 = BACK_OVERSHOOT * 1.525;
+                if t < 0.5 {
+                    let u = 2.0 * t;
+                    u * u * ((c2 + 1.0) * u - c2) / 2.0
+                } else {
+                    let u = 2.0 * t - 2.0;
+                    (u * u * ((c2 + 1.0) * u + c2) + 2.0) / 2.0
+                }
+            }
+            Easing::ElasticIn => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    -exp2(10.0 * t - 10.0) * ((10.0 * t - 10.75) * (2.0 * PI / 3.0)).sin()
+                }
+            }
+            Easing::ElasticOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    exp2(-10.0 * t) * ((10.0 * t - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+                }
+            }
+            Easing::ElasticInOut => {
+                let c5 = 2.0 * PI / 4.5;
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t < 0.5 {
+                    -(exp2(20.0 * t - 10.0) * ((20.0 * t - 11.125) * c5).sin()) / 2.0
+                } else {
+                    exp2(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * c5).sin() / 2.0 + 1.0
+                }
+            }
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => {
+                if t < 0.5 {
+                    (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0
+                } else {
+                    (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0
+                }
+            }
+            Easing::CubicBezier(bezier) => bezier.apply(t),
+            Easing::Steps(steps) => steps.apply(t),
+            Easing::Spring(spring) => spring.apply(t),
+        }
+    }
+
+    /// cubic_bezier creates a CSS `cubic-bezier(x1, y1, x2, y2)` easing.
+    pub fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Easing::CubicBezier(CubicBezier::new(x1, y1, x2, y2))
+    }
+
+    /// steps creates a CSS `steps(count, position)` easing.
+    pub fn steps(count: u32, position: StepPosition) -> Self {
+        Easing::Steps(Steps { count, position })
+    }
+
+    /// spring creates an easing shaped like the motion of the given spring,
+    /// lasting until it settles. See [SpringEasing::new].
+    pub fn spring(params: impl Into<SpringParams>) -> Self {
+        Easing::Spring(SpringEasing::new(params))
+    }
+}
+
+impl CubicBezier {
+    /// EASE is the CSS `ease` keyword.
+    pub const EASE: CubicBezier = CubicBezier {
+        x1: 0.25,
+        y1: 0.1,
+        x2: 0.25,
+        y2: 1.0,
+    };
+
+    /// EASE_IN is the CSS `ease-in` keyword.
+    pub const EASE_IN: CubicBezier = CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 1.0,
+        y2: 1.0,
+    };
+
+    /// EASE_OUT is the CSS `ease-out` keyword.
+    pub const EASE_OUT: CubicBezier = CubicBezier {
+        x1: 0.0,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    };
+
+    /// EASE_IN_OUT is the CSS `ease-in-out` keyword.
+    pub const EASE_IN_OUT: CubicBezier = CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    };
+
+    /// new creates a cubic bézier timing function, clamping `x1` and `x2` to
+    /// [0, 1] like CSS requires.
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        CubicBezier {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// apply returns the y coordinate of the curve at the x coordinate `t`.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        let x = BezierAxis::new(self.x1.clamp(0.0, 1.0), self.x2.clamp(0.0, 1.0));
+        let y = BezierAxis::new(self.y1, self.y2);
+        y.sample(x.solve(t))
+    }
+}
+
+/// BezierAxis holds the polynomial coefficients of one coordinate of a
+/// cubic bézier curve from 0 to 1.
+struct BezierAxis {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl BezierAxis {
+    fn new(p1: f64, p2: f64) -> Self {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        BezierAxis {
+            a: 1.0 - c - b,
+            b,
+            c,
+        }
+    }
+
+    fn sample(&self, s: f64) -> f64 {
+        ((self.a * s + self.b) * s + self.c) * s
+    }
+
+    fn derivative(&self, s: f64) -> f64 {
+        (3.0 * self.a * s + 2.0 * self.b) * s + self.c
+    }
+
+    /// solve returns the curve parameter at which this coordinate equals
+    /// `value`, with Newton's method falling back to bisection.
+    fn solve(&self, value: f64) -> f64 {
+        let mut s = value;
+        for _ in 0..8 {
+            let error = self.sample(s) - value;
+            if error.abs() < BEZIER_EPSILON {
+                return s;
+            }
+            let derivative = self.derivative(s);
+            if derivative.abs() < 1e-6 {
+                break;
+            }
+            s -= error / derivative;
+        }
+
+        // The coordinate increases monotonically on [0, 1].
+        let (mut low, mut high) = (0.0, 1.0);
+        s = value;
+        while high - low > BEZIER_EPSILON {
+            let sample = self.sample(s);
+            if (sample - value).abs() < BEZIER_EPSILON {
+                break;
+            }
+            if sample < value {
+                low = s;
+            } else {
+                high = s;
+            }
+            s = (low + high) / 2.0;
+        }
+        s
+    }
+}
+
+impl Steps {
+    /// apply returns the progress of the step that normalized time `t` falls
+    /// in, as defined by the CSS easing specification.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        let count = self.count.max(1);
+        let jumps = match self.position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => count.saturating_sub(1).max(1),
+            StepPosition::JumpBoth => count + 1,
+        };
+
+        let mut step = (t * f64::from(count)).floor();
+        if matches!(
+            self.position,
+            StepPosition::JumpStart | StepPosition::JumpBoth
+        ) {
+            step += 1.0;
+        }
+
+        step.min(f64::from(jumps)) / f64::from(jumps)
+    }
+}
+
+impl SpringEasing {
+    /// new creates an easing shaped like the motion of a spring with the
+    /// given parameters, lasting until it stays within 0.1% of the end. A
+    /// spring that never settles (zero damping) lasts four periods.
+    pub fn new(params: impl Into<SpringParams>) -> Self {
+        let params = params.into();
+        let trajectory = SpringTrajectory::new(
+            params.angular_frequency,
+            params.damping_ratio,
+            0.0,
+            0.0,
+            1.0,
+        );
+        let duration = trajectory
+            .settling_time(SPRING_SETTLING_TOLERANCE)
+            .unwrap_or(UNDAMPED_PERIODS * params.response());
+
+        SpringEasing { params, duration }
+    }
+
+    /// with_duration creates an easing shaped like the first `duration`
+    /// seconds of the motion of a spring with the given parameters.
+    pub fn with_duration(params: impl Into<SpringParams>, duration: f64) -> Self {
+        SpringEasing {
+            params: params.into(),
+            duration,
+        }
+    }
+
+    /// params returns the parameters of the spring.
+    pub fn params(&self) -> SpringParams {
+        self.params
+    }
+
+    /// duration returns how many seconds of spring motion the easing covers,
+    /// which is a natural duration for an animation using it.
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// apply returns the position of the spring at normalized time `t`,
+    /// ending exactly at 1.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        if t == 1.0 {
+            return 1.0;
+        }
+
+        SpringTrajectory::new(
+            self.params.angular_frequency,
+            self.params.damping_ratio,
+            0.0,
+            0.0,
+            1.0,
+        )
+        .position_at(t * self.duration)
+    }
+}
+
+impl From<CubicBezier> for Easing {
+    fn from(bezier: CubicBezier) -> Self {
+        Easing::CubicBezier(bezier)
+    }
+}
+
+impl From<Steps> for Easing {
+    fn from(steps: Steps) -> Self {
+        Easing::Steps(steps)
+    }
+}
+
+impl From<SpringEasing> for Easing {
+    fn from(spring: SpringEasing) -> Self {
+        Easing::Spring(spring)
+    }
+}
+
+impl From<&Spring> for SpringEasing {
+    fn from(spring: &Spring) -> Self {
+        SpringEasing::new(spring.params())
+    }
+}
+
+/// exp2 returns 2 raised to the power `x`.
+fn exp2(x: f64) -> f64 {
+    (x * LN_2).exp()
+}
+
+/// power_out returns `(1 - t)^n`, the remaining distance of a power ease out.
+fn power_out(t: f64, n: i32) -> f64 {
+    powi(1.0 - t, n)
+}
+
+/// power_in_out returns the in-out variant of the power easing `t^n`.
+fn power_in_out(t: f64, n: i32) -> f64 {
+    if t < 0.5 {
+        powi(2.0, n - 1) * powi(t, n)
+    } else {
+        1.0 - powi(-2.0 * t + 2.0, n) / 2.0
+    }
+}
+
+/// powi returns `x^n` for a small positive `n`.
+fn powi(x: f64, n: i32) -> f64 {
+    (0..n).fold(1.0, |product, _| product * x)
+}
+
+/// bounce_out returns the bounce ease out at normalized time `t`.
+fn bounce_out(t: f64) -> f64 {
+    let n1 = 7.5625;
+    let d1 = 2.75;
+    if t < 1.0 / d1 {
+        n1 * t * t
+    } else if t < 2.0 / d1 {
+        let t = t - 1.5 / d1;
+        n1 * t * t + 0.75
+    } else if t < 2.5 / d1 {
+        let t = t - 2.25 / d1;
+        n1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d1;
+        n1 * t * t + 0.984375
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AngularFrequency, CubicBezier, DampingRatio, DeltaTime, Easing, Spring, SpringEasing,
+        SpringParams, StepPosition,
+    };
+
+    const PENNER: [Easing; 31] = [
+        Easing::Linear,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::QuartIn,
+        Easing::QuartOut,
+        Easing::QuartInOut,
+        Easing::QuintIn,
+        Easing::QuintOut,
+        Easing::QuintInOut,
+        Easing::ExpoIn,
+        Easing::ExpoOut,
+        Easing::ExpoInOut,
+        Easing::CircIn,
+        Easing::CircOut,
+        Easing::CircInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+    ];
+
+    #[test]
+    fn test_penner_easings_start_and_end() {
+        for easing in PENNER {
+            assert!(relative_eq!(easing.apply(0.0), 0.0, epsilon = 1e-3));
+            assert!(relative_eq!(easing.apply(1.0), 1.0, epsilon = 1e-3));
+            // Input is clamped.
+            assert_eq!(easing.apply(-1.0), easing.apply(0.0));
+            assert_eq!(easing.apply(2.0), easing.apply(1.0));
+        }
+    }
+
+    #[test]
+    fn test_in_out_easings_are_symmetric() {
+        let families = [
+            (Easing::SineIn, Easing::SineOut, Easing::SineInOut),
+            (Easing::QuadIn, Easing::QuadOut, Easing::QuadInOut),
+            (Easing::CubicIn, Easing::CubicOut, Easing::CubicInOut),
+            (Easing::QuartIn, Easing::QuartOut, Easing::QuartInOut),
+            (Easing::QuintIn, Easing::QuintOut, Easing::QuintInOut),
+            (Easing::CircIn, Easing::CircOut, Easing::CircInOut),
+            (Easing::BackIn, Easing::BackOut, Easing::BackInOut),
+            (Easing::BounceIn, Easing::BounceOut, Easing::BounceInOut),
+        ];
+
+        for (ease_in, ease_out, ease_in_out) in families {
+            for t in [0.1, 0.3, 0.5, 0.7, 0.9] {
+                // Out is in mirrored in time and progress.
+                assert!(relative_eq!(
+                    ease_out.apply(t),
+                    1.0 - ease_in.apply(1.0 - t),
+                    epsilon = 1e-9
+                ));
+                assert!(relative_eq!(
+                    ease_in_out.apply(t) + ease_in_out.apply(1.0 - t),
+                    1.0,
+                    epsilon = 1e-9
+                ));
+            }
+            assert!(relative_eq!(ease_in_out.apply(0.5), 0.5, epsilon = 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        assert!(relative_eq!(Easing::QuadIn.apply(0.5), 0.25));
+        assert!(relative_eq!(Easing::CubicInOut.apply(0.25), 0.0625));
+        assert!(relative_eq!(Easing::SineOut.apply(0.5), 0.5_f64.sqrt()));
+        assert!(relative_eq!(Easing::ExpoIn.apply(0.5), 2f64.powi(-5)));
+        // Back pulls below zero, elastic overshoots past one.
+        assert!(Easing::BackIn.apply(0.2) < 0.0);
+        assert!(Easing::ElasticOut.apply(0.1) > 1.0);
+    }
+
+    #[test]
+    fn test_cubic_bezier_matches_css() {
+        // Linear control points give the identity.
+        let linear = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+        for t in [0.0, 0.2, 0.5, 0.8, 1.0] {
+            assert!(relative_eq!(linear.apply(t), t, epsilon = 1e-6));
+        }
+
+        // CSS `ease`, checked against a brute-force bisection of the curve.
+        let ease = Easing::from(CubicBezier::EASE);
+        assert!(relative_eq!(ease.apply(0.25), 0.408_510_6, epsilon = 1e-5));
+        assert!(relative_eq!(ease.apply(0.5), 0.802_403_4, epsilon = 1e-5));
+        assert_eq!(ease.apply(0.0), 0.0);
+        assert_eq!(ease.apply(1.0), 1.0);
+
+        // ease-in-out is symmetric, and y may overshoot.
+        let in_out = CubicBezier::EASE_IN_OUT;
+        assert!(relative_eq!(
+            in_out.apply(0.3) + in_out.apply(0.7),
+            1.0,
+            epsilon = 1e-6
+        ));
+        assert!(Easing::cubic_bezier(0.34, 1.56, 0.64, 1.0).apply(0.6) > 1.0);
+        assert_eq!(CubicBezier::new(-1.0, 0.0, 2.0, 1.0).x1, 0.0);
+    }
+
+    #[test]
+    fn test_steps_match_css() {
+        let end = Easing::steps(4, StepPosition::JumpEnd);
+        assert_eq!(
+            [0.0, 0.24, 0.25, 0.99, 1.0].map(|t| end.apply(t)),
+            [0.0, 0.0, 0.25, 0.75, 1.0]
+        );
+
+        let start = Easing::steps(4, StepPosition::JumpStart);
+        assert_eq!(
+            [0.0, 0.24, 0.75, 1.0].map(|t| start.apply(t)),
+            [0.25, 0.25, 1.0, 1.0]
+        );
+
+        let none = Easing::steps(5, StepPosition::JumpNone);
+        assert_eq!(
+            [0.0, 0.19, 0.2, 0.99, 1.0].map(|t| none.apply(t)),
+            [0.0, 0.0, 0.25, 1.0, 1.0]
+        );
+
+        let both = Easing::steps(3, StepPosition::JumpBoth);
+        assert_eq!([0.0, 0.5, 1.0].map(|t| both.apply(t)), [0.25, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_spring_easing_follows_spring() {
+        let params = SpringParams::new(AngularFrequency(10.0), DampingRatio(0.4));
+        let spring = Spring::with_params(DeltaTime(0.01), params);
+        let easing = SpringEasing::from(&spring);
+        assert_eq!(easing.params(), params);
+
+        // Stepping the spring over the easing's duration traces the curve.
+        let (mut pos, mut vel) = (0.0, 0.0);
+        let steps = 50;
+        let mut stepped = Spring::with_params(DeltaTime(easing.duration() / steps as f64), params);
+        for _ in 0..steps / 2 {
+            (pos, vel) = stepped.update(pos, vel, 1.0);
+        }
+        assert!(relative_eq!(
+            Easing::from(easing).apply(0.5),
+            pos,
+            epsilon = 1e-9
+        ));
+
+        // It ends at one, having overshot on the way.
+        assert_eq!(easing.apply(1.0), 1.0);
+        assert!((0..100).any(|i| easing.apply(i as f64 / 100.0) > 1.0));
+        assert!(relative_eq!(easing.apply(0.999), 1.0, epsilon = 2e-3));
+
+        // A spring without damping never settles and lasts four periods.
+        let undamped = SpringEasing::new(SpringParams::new(
+            AngularFrequency(2.0 * core::f64::consts::PI),
+            DampingRatio(0.0),
+        ));
+        assert!(relative_eq!(undamped.duration(), 4.0));
+    }
+}
@@ -0,0 +1,691 @@
+//! This file defines `Fixed`, a deterministic fixed-point number for lockstep
+//! simulations. Floating-point `exp`, `sin` and `cos` may differ in the last
+//! bits between platforms and math libraries; `Fixed` computes everything with
+//! integer arithmetic, so a [Spring], [ProjectileState] or projectile with
+//! drag, force fields and colliders built on it gives bit-identical results on
+//! every target. Build such springs from an angular frequency and damping
+//! ratio: [Spring::with_params] converts other descriptions with `f64` math.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Spring, Fixed, Float, DeltaTime, AngularFrequency, DampingRatio};
+//!
+//! let mut spring = Spring::new(
+//!     DeltaTime(Fixed::from_f64(1.0 / 60.0)),
+//!     AngularFrequency(Fixed::from_int(6)),
+//!     DampingRatio(Fixed::from_f64(0.5)),
+//! );
+//!
+//! # fn some_update_loop(mut f: impl FnMut()) { f() }
+//! let mut pos = Fixed::ZERO;
+//! let mut velocity = Fixed::ZERO;
+//! some_update_loop(|| {
+//!     (pos, velocity) = spring.update(pos, velocity, Fixed::from_int(100));
+//! });
+//! ```
+//!
+//! [Spring]: crate::Spring
+//! [ProjectileState]: crate::ProjectileState
+use crate::Float;
+use core::cmp::Ordering;
+use core::fmt;
+use core::fmt::Formatter;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Fixed is a signed Q32.32 fixed-point number: 32 integer bits and 32
+/// fractional bits, giving a range of about ±2.1 billion with a resolution of
+/// about 2.3e-10.
+///
+/// Arithmetic saturates instead of overflowing, and dividing by zero returns
+/// the largest value with the sign of the dividend. Conversions from `f64` are
+/// deterministic as well, so parameters can be written as float literals.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fixed(i64);
+
+/// FRAC_BITS is the number of fractional bits used while evaluating `exp`,
+/// `sin` and `cos`, to keep the rounding error below the resolution of
+/// [Fixed].
+const FRAC_BITS: u32 = 60;
+
+/// ONE is 1.0 with [FRAC_BITS] fractional bits.
+const ONE: i128 = 1 << FRAC_BITS;
+
+/// LN_2 is ln(2) with [FRAC_BITS] fractional bits.
+const LN_2: i128 = 799_144_290_325_165_979;
+
+/// HALF_PI is π/2 with [FRAC_BITS] fractional bits.
+const HALF_PI: i128 = 1_811_004_864_519_280_711;
+
+/// PI is π with [FRAC_BITS] fractional bits.
+const PI: i128 = 3_622_009_729_038_561_421;
+
+/// TWO_PI is 2π with [FRAC_BITS] fractional bits.
+const TWO_PI: i128 = 7_244_019_458_077_122_842;
+
+impl Fixed {
+    /// FRAC_BITS is the number of fractional bits of a Fixed.
+    pub const FRAC_BITS: u32 = 32;
+
+    /// MAX is the largest representable value.
+    pub const MAX: Fixed = Fixed(i64::MAX);
+
+    /// MIN is the smallest representable value.
+    pub const MIN: Fixed = Fixed(i64::MIN);
+
+    /// from_bits creates a Fixed from its raw representation, the value
+    /// multiplied by 2^32.
+    pub const fn from_bits(bits: i64) -> Self {
+        Fixed(bits)
+    }
+
+    /// to_bits returns the raw representation of the value, the value
+    /// multiplied by 2^32.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// from_int creates a Fixed from an integer.
+    pub const fn from_int(value: i32) -> Self {
+        Fixed((value as i64) << Self::FRAC_BITS)
+    }
+
+    /// wide returns the value with [FRAC_BITS] fractional bits.
+    fn wide(self) -> i128 {
+        (self.0 as i128) << (FRAC_BITS - Self::FRAC_BITS)
+    }
+
+    /// from_wide rounds a value with `frac_bits` fractional bits to a Fixed.
+    fn from_wide(value: i128, frac_bits: u32) -> Self {
+        if frac_bits <= Self::FRAC_BITS {
+            let shift = Self::FRAC_BITS - frac_bits;
+            return Fixed(saturate(value.saturating_mul(1 << shift)));
+        }
+        let shift = frac_bits - Self::FRAC_BITS;
+        if shift >= 127 {
+            return Fixed(0);
+        }
+        Fixed(saturate((value + (1 << (shift - 1))) >> shift))
+    }
+}
+
+/// saturate clamps a wide intermediate result to the range of a Fixed.
+fn saturate(value: i128) -> i64 {
+    value.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// mul_wide multiplies two values with [FRAC_BITS] fractional bits.
+fn mul_wide(a: i128, b: i128) -> i128 {
+    (a * b) >> FRAC_BITS
+}
+
+/// sin_wide returns the sine of `x`, with [FRAC_BITS] fractional bits for
+/// both, using a Taylor series after reducing `x` to [-π/2, π/2].
+fn sin_wide(x: i128) -> i128 {
+    let mut x = x.rem_euclid(TWO_PI);
+    if x > PI {
+        x -= TWO_PI;
+    }
+    if x > HALF_PI {
+        x = PI - x;
+    } else if x < -HALF_PI {
+        x = -PI - x;
+    }
+
+    let x_squared = mul_wide(x, x);
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1;
+    while term != 0 {
+        term = -mul_wide(term, x_squared) / ((2 * n) * (2 * n + 1));
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fixed(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Fixed(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Fixed(saturate(
+            (self.0 as i128 * other.0 as i128) >> Self::FRAC_BITS,
+        ))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if other.0 == 0 {
+            return match self.0.cmp(&0) {
+                Ordering::Less => Fixed::MIN,
+                Ordering::Equal => Fixed(0),
+                Ordering::Greater => Fixed::MAX,
+            };
+        }
+        Fixed(saturate(
+            ((self.0 as i128) << Self::FRAC_BITS) / other.0 as i128,
+        ))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        Fixed::from_int(value)
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl Float for Fixed {
+    const ZERO: Self = Fixed(0);
+    const ONE: Self = Fixed(1 << Fixed::FRAC_BITS);
+
+    fn from_f64(value: f64) -> Self {
+        // Scaling by a power of two is exact and `as` saturates (NaN becomes
+        // zero), so this gives the same result on every platform.
+        let scaled = value * (1u64 << Self::FRAC_BITS) as f64;
+        if scaled < 0.0 {
+            Fixed((scaled - 0.5) as i64)
+        } else {
+            Fixed((scaled + 0.5) as i64)
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << Self::FRAC_BITS) as f64
+    }
+
+    fn exp(self) -> Self {
+        // exp(x) = 2^k * exp(r) with x = k * ln(2) + r and 0 <= r < ln(2).
+        let x = self.wide();
+        let k = x.div_euclid(LN_2);
+        if k > 32 {
+            return Fixed::MAX;
+        }
+        if k < -40 {
+            return Fixed(0);
+        }
+        let r = x.rem_euclid(LN_2);
+
+        let mut term = ONE;
+        let mut sum = ONE;
+        let mut n = 1;
+        while term != 0 {
+            term = mul_wide(term, r) / n;
+            sum += term;
+            n += 1;
+        }
+
+        Fixed::from_wide(sum, (FRAC_BITS as i128 - k) as u32)
+    }
+
+    fn sin(self) -> Self {
+        Fixed::from_wide(sin_wide(self.wide()), FRAC_BITS)
+    }
+
+    fn cos(self) -> Self {
+        Fixed::from_wide(sin_wide(self.wide() + HALF_PI), FRAC_BITS)
+    }
+
+    fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Fixed(0);
+        }
+
+        // Integer square root of the value scaled by another 2^32, digit by
+        // digit.
+        let n = (self.0 as u128) << Self::FRAC_BITS;
+        let mut remainder = n;
+        let mut root = 0u128;
+        let mut bit = 1u128 << 126;
+        while bit > n {
+            bit >>= 2;
+        }
+        while bit != 0 {
+            if remainder >= root + bit {
+                remainder -= root + bit;
+                root = (root >> 1) + bit;
+            } else {
+                root >>= 1;
+            }
+            bit >>= 2;
+        }
+        Fixed(root as i64)
+    }
+
+    fn cbrt(self) -> Self {
+        // Integer cube root of the magnitude scaled by another 2^64, one
+        // binary digit at a time.
+        let mut remainder = (self.0.unsigned_abs() as u128) << (2 * Self::FRAC_BITS);
+        let mut root = 0u128;
+        for shift in (0..=126).rev().step_by(3) {
+            root <<= 1;
+            let step = 3 * root * (root + 1) + 1;
+            if remainder >> shift >= step {
+                remainder -= step << shift;
+                root += 1;
+            }
+        }
+
+        if self.0 < 0 {
+            Fixed(-(root as i64))
+        } else {
+            Fixed(root as i64)
+        }
+    }
+
+    fn abs(self) -> Self {
+        Fixed(self.0.saturating_abs())
+    }
+
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AngularFrequency, Animatable, Collider, DampingRatio, DeltaTime, DurationBounce,
+        EmissionMode, Emitter, EmitterShape, Fixed, Float, Integrator, ParticleSystem, Point,
+        PointAttractor, Projectile, ProjectileState, Spring, Turbulence, Vector, Vortex,
+    };
+
+    fn fixed_spring(damping_ratio: f64) -> Spring<Fixed> {
+        Spring::new(
+            DeltaTime(Fixed::from_f64(1.0 / 60.0)),
+            AngularFrequency(Fixed::from_int(6)),
+            DampingRatio(Fixed::from_f64(damping_ratio)),
+        )
+    }
+
+    #[test]
+    fn test_fixed_arithmetic() {
+        let a = Fixed::from_f64(2.5);
+        let b = Fixed::from_int(-4);
+
+        assert_eq!((a + b).to_f64(), -1.5);
+        assert_eq!((a - b).to_f64(), 6.5);
+        assert_eq!((a * b).to_f64(), -10.0);
+        assert!(relative_eq!((b / a).to_f64(), -1.6, epsilon = 1e-9));
+        assert_eq!(a / Fixed::ZERO, Fixed::MAX);
+        assert_eq!(Fixed::MAX + Fixed::ONE, Fixed::MAX);
+        assert_eq!(Fixed::from_f64(f64::NAN), Fixed::ZERO);
+    }
+
+    #[test]
+    fn test_fixed_functions_are_accurate() {
+        for i in -80..=80 {
+            let x = i as f64 * 0.173;
+            let fixed = Fixed::from_f64(x);
+
+            assert!(relative_eq!(fixed.sin().to_f64(), x.sin(), epsilon = 1e-9));
+            assert!(relative_eq!(fixed.cos().to_f64(), x.cos(), epsilon = 1e-9));
+            assert!(relative_eq!(
+                fixed.exp().to_f64(),
+                x.exp(),
+                epsilon = 1e-9,
+                max_relative = 1e-9
+            ));
+            assert!(relative_eq!(
+                fixed.abs().sqrt().to_f64(),
+                x.abs().sqrt(),
+                epsilon = 1e-9
+            ));
+            assert!(relative_eq!(
+                fixed.cbrt().to_f64(),
+                x.cbrt(),
+                epsilon = 1e-9
+            ));
+        }
+    }
+
+    #[test]
+    fn test_fixed_spring_tracks_f64_spring() {
+        for damping in [0.3, 1.0, 1.7] {
+            let mut fixed = fixed_spring(damping);
+            let mut float = Spring::new(
+                DeltaTime(1.0 / 60.0),
+                AngularFrequency(6.0),
+                DampingRatio(damping),
+            );
+
+            let (mut pos, mut vel) = (Fixed::ZERO, Fixed::ZERO);
+            let (mut float_pos, mut float_vel) = (0.0, 0.0);
+            for _ in 0..120 {
+                (pos, vel) = fixed.update(pos, vel, Fixed::from_int(100));
+                (float_pos, float_vel) = float.update(float_pos, float_vel, 100.0);
+            }
+
+            assert!(relative_eq!(pos.to_f64(), float_pos, epsilon = 1e-5));
+            assert!(relative_eq!(vel.to_f64(), float_vel, epsilon = 1e-5));
+        }
+    }
+
+    // The golden values below pin the exact bits of the deterministic mode.
+    // They must never change, or lockstep peers running different versions
+    // would desynchronize.
+
+    #[test]
+    fn test_fixed_spring_golden_values() {
+        let mut golden = Vec::new();
+        for damping in [0.3, 1.0, 1.7] {
+            let mut spring = fixed_spring(damping);
+            let (mut pos, mut vel) = (Fixed::ZERO, Fixed::ZERO);
+            for _ in 0..120 {
+                (pos, vel) = spring.update(pos, vel, Fixed::from_int(100));
+            }
+            golden.push((pos.to_bits(), vel.to_bits()));
+        }
+
+        assert_eq!(
+            golden,
+            vec![
+                (427694786375, -66409757353),
+                (429462423629, 190002239),
+                (419801034220, 18919828505),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fixed_spring_from_duration_bounce_golden_values() {
+        let mut golden = Vec::new();
+        for bounce in [0.3, 0.0, -0.4] {
+            let mut spring = Spring::with_params(
+                DeltaTime(Fixed::from_f64(1.0 / 60.0)),
+                DurationBounce {
+                    duration: 0.5,
+                    bounce,
+                },
+            );
+            let (mut pos, mut vel) = (Fixed::ZERO, Fixed::ZERO);
+            for _ in 0..60 {
+                (pos, vel) = spring.update(pos, vel, Fixed::from_int(100));
+            }
+            golden.push((pos.to_bits(), vel.to_bits()));
+        }
+
+        assert_eq!(
+            golden,
+            vec![
+                (429527480217, 497874319),
+                (429476409850, 236523502),
+                (422169430547, 30692518501),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fixed_projectile_golden_values() {
+        let mut golden = Vec::new();
+        for integrator in [
+            Integrator::ExplicitEuler,
+            Integrator::SemiImplicitEuler,
+            Integrator::VelocityVerlet,
+            Integrator::RungeKutta4,
+            Integrator::Analytic,
+        ] {
+            let mut state = ProjectileState {
+                position: Point {
+                    x: Fixed::ZERO,
+                    y: Fixed::ZERO,
+                    z: Fixed::ZERO,
+                },
+                velocity: Vector {
+                    x: Fixed::from_f64(5.5),
+                    y: Fixed::from_int(12),
+                    z: Fixed::from_f64(-0.25),
+                },
+                acceleration: Vector {
+                    x: Fixed::ZERO,
+                    y: Fixed::from_f64(-9.81),
+                    z: Fixed::ZERO,
+                },
+            };
+            for _ in 0..90 {
+                state.step(DeltaTime(Fixed::from_f64(1.0 / 60.0)), integrator);
+            }
+            golden.push([
+                state.position.x.to_bits(),
+                state.position.y.to_bits(),
+                state.position.z.to_bits(),
+                state.velocity.y.to_bits(),
+            ]);
+        }
+
+        assert_eq!(
+            golden,
+            vec![
+                [35433480060, 30435748842, -1610612730, -11660836038],
+                [35433480060, 29382408119, -1610612730, -11660836038],
+                [35433480060, 29909078652, -1610612730, -11660836038],
+                [35433478080, 29909079405, -1610612640, -11660832528],
+                [35433480060, 29909078652, -1610612730, -11660836038],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fixed_update_value_golden_values() {
+        let mut spring = fixed_spring(0.5);
+        let target = Point {
+            x: Fixed::from_int(100),
+            y: Fixed::from_f64(-2.5),
+            z: Fixed::from_f64(0.125),
+        };
+        let (mut pos, mut vel) = (Point::default(), Point::default());
+        let (mut x, mut x_vel) = (Fixed::ZERO, Fixed::ZERO);
+        for _ in 0..120 {
+            (pos, vel) = spring.update_value(pos, vel, target);
+            (x, x_vel) = spring.update(x, x_vel, target.x);
+        }
+
+        // Values are scaled in fixed point, exactly like the scalar update.
+        assert_eq!((pos.x, vel.x), (x, x_vel));
+        assert_eq!(
+            [pos.x, pos.y, pos.z, vel.x, vel.y, vel.z].map(Fixed::to_bits),
+            [
+                430606886918,
+                -10765172187,
+                538258598,
+                -6073923687,
+                151848155,
+                -7592347
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fixed_projectile_with_forces_golden_values() {
+        let point = |x: f64, y: f64, z: f64| Point {
+            x: Fixed::from_f64(x),
+            y: Fixed::from_f64(y),
+            z: Fixed::from_f64(z),
+        };
+        let vector = |x: f64, y: f64, z: f64| Vector {
+            x: Fixed::from_f64(x),
+            y: Fixed::from_f64(y),
+            z: Fixed::from_f64(z),
+        };
+
+        let mut golden = Vec::new();
+        for integrator in [Integrator::SemiImplicitEuler, Integrator::RungeKutta4] {
+            let mut projectile = Projectile::launch(
+                point(0.0, 4.0, 0.0),
+                vector(6.0, 3.0, -1.0),
+                vector(0.0, -9.81, 0.0),
+            )
+            .with_integrator(integrator)
+            .with_mass(Fixed::from_f64(0.5))
+            .with_drag(Fixed::from_f64(0.05), Fixed::from_f64(0.01))
+            .with_wind(vector(1.5, 0.0, 0.5))
+            .with_force_field(PointAttractor {
+                center: point(8.0, 0.0, 0.0),
+                strength: Fixed::from_f64(2.0),
+                min_distance: Fixed::ONE,
+            })
+            .with_force_field(Vortex {
+                center: point(4.0, 0.0, 0.0),
+                axis: vector(0.0, 0.0, 1.0),
+                strength: Fixed::from_f64(0.5),
+                min_distance: Fixed::from_f64(0.5),
+            })
+            .with_force_field(Turbulence {
+                strength: 0.3,
+                frequency: 0.7,
+                seed: 3,
+            })
+            .with_radius(Fixed::from_f64(0.25))
+            .with_collider(
+                Collider::plane(Point::default(), vector(0.0, 1.0, 0.0))
+                    .with_restitution(Fixed::from_f64(0.6))
+                    .with_friction(Fixed::from_f64(0.2)),
+            )
+            .with_collider(Collider::aabb(point(9.0, 0.0, -5.0), point(10.0, 3.0, 5.0)));
+
+            let mut contacts = 0;
+            for _ in 0..180 {
+                projectile.update(DeltaTime(Fixed::from_f64(1.0 / 60.0)));
+                contacts += projectile.contacts().len();
+            }
+
+            let state = projectile.state();
+            golden.push((
+                contacts,
+                [
+                    state.position.x,
+                    state.position.y,
+                    state.position.z,
+                    state.velocity.x,
+                    state.velocity.y,
+                    state.velocity.z,
+                ]
+                .map(Fixed::to_bits),
+            ));
+        }
+
+        assert_eq!(
+            golden,
+            vec![
+                (
+                    3,
+                    [
+                        36719605952,
+                        1335498622,
+                        -1204756812,
+                        -3640347508,
+                        -5078129952,
+                        4211196867
+                    ]
+                ),
+                (
+                    4,
+                    [
+                        36405034669,
+                        1947552801,
+                        -1432101155,
+                        -3113596410,
+                        -810527370,
+                        3656004602
+                    ]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fixed_particles_golden_values() {
+        let up = Vector {
+            x: Fixed::ZERO,
+            y: Fixed::ONE,
+            z: Fixed::ZERO,
+        };
+        let fountain = Emitter::new(
+            Point::default(),
+            EmitterShape::Cone {
+                direction: up,
+                half_angle: Fixed::from_f64(0.4),
+            },
+            EmissionMode::Rate {
+                per_second: Fixed::from_int(90),
+            },
+        )
+        .with_speed(Fixed::from_int(3), Fixed::from_int(6))
+        .with_lifetime(Fixed::from_f64(0.5), Fixed::from_f64(1.5))
+        .with_acceleration(up.scale(Fixed::from_f64(-9.81)));
+        let smoke = Emitter::new(
+            Point::default(),
+            EmitterShape::Sphere {
+                radius: Fixed::from_f64(0.75),
+            },
+            EmissionMode::Burst { count: 20 },
+        );
+
+        let mut system = ParticleSystem::new(11)
+            .with_emitter(fountain)
+            .with_emitter(smoke)
+            .with_physics(
+                Projectile::default()
+                    .with_drag(Fixed::from_f64(0.3), Fixed::ZERO)
+                    .with_collider(Collider::plane(Point::default(), up)),
+            );
+        for _ in 0..60 {
+            system.update(DeltaTime(Fixed::from_f64(1.0 / 60.0)));
+        }
+
+        // Sums of the raw bits, which change if any particle changes.
+        let checksum = |values: Vec<Fixed>| {
+            values
+                .into_iter()
+                .fold(0i64, |sum, value| sum.wrapping_add(value.to_bits()))
+        };
+        let positions = system.positions().iter().flat_map(|p| [p.x, p.y, p.z]);
+        let velocities = system.velocities().iter().flat_map(|v| [v.x, v.y, v.z]);
+        assert_eq!(
+            (
+                system.len(),
+                checksum(positions.collect()),
+                checksum(velocities.collect()),
+                checksum(system.ages().to_vec()),
+            ),
+            (103, 182729749005, 144527267035, 248535439936)
+        );
+    }
+}
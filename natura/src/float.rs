@@ -0,0 +1,296 @@
+//! This file defines the `Float` trait, which lets springs, sprites, points
+//! and vectors use the native precision of an engine (`f32` or `f64`) instead
+//! of converting to and from `f64` on every frame.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Spring, DeltaTime, AngularFrequency, DampingRatio};
+//!
+//! // An f32 spring, e.g. to drive a Bevy or glam `Vec3` component by component.
+//! let mut spring = Spring::new(DeltaTime(1.0_f32 / 60.0), AngularFrequency(6.0), DampingRatio(0.5));
+//! let (pos, vel): (f32, f32) = spring.update(0.0, 0.0, 100.0);
+//! ```
+use core::fmt::{Debug, Display};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Float is implemented by the number types natura can compute with. All
+/// types default to `f64`; `f32` is supported as well, and other number types
+/// (e.g. fixed-point numbers) can implement this trait to plug in.
+pub trait Float:
+    Copy
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// ZERO is the additive identity.
+    const ZERO: Self;
+
+    /// ONE is the multiplicative identity.
+    const ONE: Self;
+
+    /// from_f64 converts an `f64` to this type, rounding if needed.
+    fn from_f64(value: f64) -> Self;
+
+    /// to_f64 converts this value to an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// exp returns `e^self`.
+    fn exp(self) -> Self;
+
+    /// sin returns the sine of `self` in radians.
+    fn sin(self) -> Self;
+
+    /// cos returns the cosine of `self` in radians.
+    fn cos(self) -> Self;
+
+    /// sqrt returns the square root of `self`.
+    fn sqrt(self) -> Self;
+
+    /// abs returns the absolute value of `self`.
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// max returns the larger of two values.
+    fn max(self, other: Self) -> Self {
+        if self < other {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// min returns the smaller of two values.
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// cbrt returns the cube root of `self`. By default it is computed in
+    /// `f64`.
+    fn cbrt(self) -> Self {
+        #[cfg(feature = "std")]
+        {
+            Self::from_f64(self.to_f64().cbrt())
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Self::from_f64(libm::cbrt(self.to_f64()))
+        }
+    }
+}
+
+macro_rules! impl_float {
+    ($($ty:ident => $exp:ident, $sin:ident, $cos:ident, $sqrt:ident, $cbrt:ident);+) => {
+        $(
+            impl Float for $ty {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                #[inline(always)]
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+
+                #[inline(always)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline(always)]
+                fn exp(self) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        $ty::exp(self)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$exp(self)
+                    }
+                }
+
+                #[inline(always)]
+                fn sin(self) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        $ty::sin(self)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$sin(self)
+                    }
+                }
+
+                #[inline(always)]
+                fn cos(self) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        $ty::cos(self)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$cos(self)
+                    }
+                }
+
+                #[inline(always)]
+                fn sqrt(self) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        $ty::sqrt(self)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$sqrt(self)
+                    }
+                }
+
+                #[inline(always)]
+                fn cbrt(self) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        $ty::cbrt(self)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$cbrt(self)
+                    }
+                }
+
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    $ty::abs(self)
+                }
+
+                #[inline(always)]
+                fn max(self, other: Self) -> Self {
+                    $ty::max(self, other)
+                }
+
+                #[inline(always)]
+                fn min(self, other: Self) -> Self {
+                    $ty::min(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_float!(
+    f32 => expf, sinf, cosf, sqrtf, cbrtf;
+    f64 => exp, sin, cos, sqrt, cbrt
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AngularFrequency, Animatable, DampingRatio, DeltaTime, DynamicSpring, Point, Spring,
+        Sprite, Vector,
+    };
+
+    #[test]
+    fn test_f32_spring_tracks_f64_spring() {
+        for damping in [0.3, 1.0, 1.7] {
+            let mut spring32 = Spring::new(
+                DeltaTime(1.0_f32 / 60.0),
+                AngularFrequency(6.0_f32),
+                DampingRatio(damping as f32),
+            );
+            let mut spring64 = Spring::new(
+                DeltaTime(1.0 / 60.0),
+                AngularFrequency(6.0),
+                DampingRatio(damping),
+            );
+
+            let (mut pos32, mut vel32) = (0.0_f32, 0.0_f32);
+            let (mut pos64, mut vel64) = (0.0_f64, 0.0_f64);
+            for _ in 0..120 {
+                (pos32, vel32) = spring32.update(pos32, vel32, 100.0);
+                (pos64, vel64) = spring64.update(pos64, vel64, 100.0);
+
+                assert!(relative_eq!(pos32 as f64, pos64, epsilon = 1e-3));
+                assert!(relative_eq!(vel32 as f64, vel64, epsilon = 1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_dynamic_spring() {
+        let mut spring =
+            DynamicSpring::with_cache_capacity(AngularFrequency(8.0_f32), DampingRatio(1.0_f32), 2);
+
+        let (mut pos, mut vel) = (0.0_f32, 0.0_f32);
+        for _ in 0..300 {
+            (pos, vel) = spring.update(pos, vel, 10.0, DeltaTime(0.016));
+        }
+
+        assert!((pos - 10.0).abs() < 1e-3);
+        assert!(vel.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_f32_update_value_matches_update() {
+        let mut spring = Spring::new(
+            DeltaTime(1.0_f32 / 60.0),
+            AngularFrequency(6.0_f32),
+            DampingRatio(0.4_f32),
+        );
+
+        let (mut pos, mut vel) = ([0.0_f32, -3.0], [0.0_f32, 1.5]);
+        let mut scalars = [(0.0_f32, 0.0_f32), (-3.0, 1.5)];
+        for _ in 0..120 {
+            (pos, vel) = spring.update_value(pos, vel, [100.0, 7.0]);
+            for ((p, v), target) in scalars.iter_mut().zip([100.0, 7.0]) {
+                (*p, *v) = spring.update(*p, *v, target);
+            }
+        }
+
+        // Both paths compute in f32, so they agree to the last bit.
+        assert_eq!(pos, [scalars[0].0, scalars[1].0]);
+        assert_eq!(vel, [scalars[0].1, scalars[1].1]);
+    }
+
+    #[test]
+    fn test_f32_geometry() {
+        let a = Point {
+            x: 1.0_f32,
+            y: 2.0,
+            z: 2.0,
+        };
+        let b = Point {
+            x: 4.0_f32,
+            y: 6.0,
+            z: 2.0,
+        };
+        let offset: Vector<f32> = a.to(b);
+
+        assert_eq!(offset.length(), 5.0);
+        assert_eq!(offset.normalized().x, 0.6);
+        assert_eq!(
+            a.add(b).scale(0.5),
+            Point {
+                x: 2.5,
+                y: 4.0,
+                z: 2.0
+            }
+        );
+
+        let sprite: Sprite<f32> = Sprite::default();
+        assert_eq!(sprite.to_string(), "Sprite x:0, y:0, x_vel:0, y_vel:0");
+    }
+}
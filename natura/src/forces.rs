@@ -0,0 +1,338 @@
+//! This file defines force fields that act on projectiles in addition to their
+//! constant acceleration: point attractors, vortices and turbulence, and the
+//! [ForceField] trait to write your own.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Projectile, PointAttractor, Turbulence, Point, Vector, DeltaTime, GRAVITY};
+//!
+//! let mut leaf = Projectile::launch(Point { x: 0.0, y: 10.0, z: 0.0 }, Vector::default(), GRAVITY)
+//!     .with_mass(0.01)
+//!     .with_drag(0.05, 0.01)
+//!     .with_wind(Vector { x: 2.0, y: 0.0, z: 0.0 })
+//!     .with_force_field(Turbulence { strength: 0.02, frequency: 0.5, seed: 7 })
+//!     .with_force_field(PointAttractor {
+//!         center: Point { x: 10.0, y: 0.0, z: 0.0 },
+//!         strength: 0.5,
+//!         min_distance: 1.0,
+//!     });
+//!
+//! let pos = leaf.update(DeltaTime(natura::fps(60)));
+//! ```
+#[cfg(not(feature = "std"))]
+use crate::math::Math;
+use crate::{Animatable, Float, Point, Vector};
+use core::fmt::Debug;
+
+/// ForceField computes a force acting on a projectile from its position and
+/// velocity. Forces are divided by the mass of the projectile to obtain an
+/// acceleration, and are evaluated on every integration step.
+///
+/// Like [Projectile](crate::Projectile), a force field works with any [Float]
+/// type and defaults to `f64`.
+///
+/// # Example:
+///
+/// ```
+/// use natura::{ForceField, Point, Vector};
+///
+/// /// Pushes everything above y = 0 back down, like the surface of water.
+/// #[derive(Debug)]
+/// struct Buoyancy(f64);
+///
+/// impl ForceField for Buoyancy {
+///     fn force(&self, position: Point, _velocity: Vector, _time: f64) -> Vector {
+///         let depth = f64::min(position.y, 0.0);
+///         Vector { x: 0.0, y: -depth * self.0, z: 0.0 }
+///     }
+/// }
+/// ```
+pub trait ForceField<F: Float = f64>: Debug + Send + Sync {
+    /// force returns the force applied to a projectile at `position`, moving
+    /// with `velocity`, `time` seconds after it was created.
+    fn force(&self, position: Point<F>, velocity: Vector<F>, time: F) -> Vector<F>;
+}
+
+/// PointAttractor pulls projectiles towards `center` with a force that falls
+/// off with the square of the distance, like gravity between two bodies.
+/// A negative strength repels instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointAttractor<F = f64> {
+    /// center of attraction
+    pub center: Point<F>,
+    /// force at a distance of one unit
+    pub strength: F,
+    /// distance below which the force stops growing, to avoid singularities
+    pub min_distance: F,
+}
+
+impl<F: Float + Send + Sync> ForceField<F> for PointAttractor<F> {
+    fn force(&self, position: Point<F>, _velocity: Vector<F>, _time: F) -> Vector<F> {
+        let offset = position.to(self.center);
+        let distance = offset.length();
+        if distance == F::ZERO {
+            return Vector::default();
+        }
+
+        let clamped = distance.max(self.min_distance);
+        offset.scale(self.strength / (clamped * clamped * distance))
+    }
+}
+
+/// Vortex swirls projectiles around an axis through `center`. The force is
+/// tangential and falls off with the distance from the axis, like a free
+/// vortex; positive strengths turn counter-clockwise around `axis`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vortex<F = f64> {
+    /// a point on the axis of rotation
+    pub center: Point<F>,
+    /// direction of the axis of rotation, e.g. `Vector { x: 0.0, y: 0.0, z: 1.0 }` in 2D
+    pub axis: Vector<F>,
+    /// force at a distance of one unit from the axis
+    pub strength: F,
+    /// distance below which the force stops growing, to avoid singularities
+    pub min_distance: F,
+}
+
+impl<F: Float + Send + Sync> ForceField<F> for Vortex<F> {
+    fn force(&self, position: Point<F>, _velocity: Vector<F>, _time: F) -> Vector<F> {
+        let axis = self.axis.normalized();
+        let tangent = axis.cross(self.center.to(position));
+
+        // |axis × r| is the distance from the axis.
+        let distance = tangent.length();
+        if distance == F::ZERO {
+            return Vector::default();
+        }
+
+        let clamped = distance.max(self.min_distance);
+        tangent.scale(self.strength / (clamped * distance))
+    }
+}
+
+/// Turbulence applies a smoothly varying pseudo-random force, useful for
+/// leaves, smoke and sparks. The same seed always produces the same field.
+/// The noise is computed in `f64` for every [Float] type; it only uses exact
+/// operations, so [Fixed](crate::Fixed) projectiles stay deterministic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Turbulence {
+    /// largest force applied along each axis
+    pub strength: f64,
+    /// how quickly the force changes over space and time; higher values give
+    /// smaller, faster eddies
+    pub frequency: f64,
+    /// seed of the noise
+    pub seed: u32,
+}
+
+impl<F: Float> ForceField<F> for Turbulence {
+    fn force(&self, position: Point<F>, _velocity: Vector<F>, time: F) -> Vector<F> {
+        let x = position.x.to_f64() * self.frequency;
+        let y = position.y.to_f64() * self.frequency;
+        let z = position.z.to_f64() * self.frequency + time.to_f64() * self.frequency;
+        let axis = |axis| F::from_f64(value_noise(x, y, z, self.seed, axis) * self.strength);
+
+        Vector {
+            x: axis(0),
+            y: axis(1),
+            z: axis(2),
+        }
+    }
+}
+
+/// value_noise returns smooth 3D noise in `[-1, 1]` for one axis of the
+/// force, interpolating random values placed on the integer lattice.
+fn value_noise(x: f64, y: f64, z: f64, seed: u32, axis: usize) -> f64 {
+    let (x0, y0, z0) = (x.floor(), y.floor(), z.floor());
+    let (tx, ty, tz) = (smooth(x - x0), smooth(y - y0), smooth(z - z0));
+    let (ix, iy, iz) = (x0 as i64, y0 as i64, z0 as i64);
+
+    let corner = |dx: i64, dy: i64, dz: i64| lattice_value(ix + dx, iy + dy, iz + dz, seed, axis);
+    let lerp = |a: f64, b: f64, t: f64| a + (b - a) * t;
+
+    let x00 = lerp(corner(0, 0, 0), corner(1, 0, 0), tx);
+    let x10 = lerp(corner(0, 1, 0), corner(1, 1, 0), tx);
+    let x01 = lerp(corner(0, 0, 1), corner(1, 0, 1), tx);
+    let x11 = lerp(corner(0, 1, 1), corner(1, 1, 1), tx);
+
+    lerp(lerp(x00, x10, ty), lerp(x01, x11, ty), tz)
+}
+
+/// smooth is the smoothstep curve, which removes visible lattice creases.
+fn smooth(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// lattice_value hashes lattice coordinates to a value in `[-1, 1]`.
+fn lattice_value(x: i64, y: i64, z: i64, seed: u32, axis: usize) -> f64 {
+    // The seed and axis are hashed together first, so every (seed, axis)
+    // pair, including seed 0, gets its own unrelated noise.
+    let key = mix(((axis as u64) << 32 | seed as u64).wrapping_add(0x9E37_79B9_7F4A_7C15));
+    let hash = mix((x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+        ^ (z as u64).wrapping_mul(0x1656_67B1_9E37_79F9)
+        ^ key);
+
+    (hash >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+}
+
+/// mix is the finalizer from SplitMix64, which spreads every input bit over
+/// the whole output.
+fn mix(mut hash: u64) -> u64 {
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ForceField, Point, PointAttractor, Turbulence, Vector, Vortex};
+
+    #[test]
+    fn test_point_attractor_inverse_square() {
+        let attractor = PointAttractor {
+            center: Point::default(),
+            strength: 8.0,
+            min_distance: 0.5,
+        };
+
+        let near = attractor.force(
+            Point {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            Vector::default(),
+            0.0,
+        );
+        let far = attractor.force(
+            Point {
+                x: 2.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            Vector::default(),
+            0.0,
+        );
+
+        assert!(relative_eq!(near.x, -8.0));
+        assert!(relative_eq!(far.x, -2.0));
+
+        // Inside min_distance the force is capped.
+        let inside = attractor.force(
+            Point {
+                x: 0.1,
+                y: 0.0,
+                z: 0.0,
+            },
+            Vector::default(),
+            0.0,
+        );
+        assert!(relative_eq!(inside.x, -32.0));
+    }
+
+    #[test]
+    fn test_vortex_is_tangential() {
+        let vortex = Vortex {
+            center: Point::default(),
+            axis: Vector {
+                x: 0.0,
+                y: 0.0,
+                z: 2.0,
+            },
+            strength: 3.0,
+            min_distance: 0.1,
+        };
+        let position = Point {
+            x: 2.0,
+            y: 0.0,
+            z: 5.0,
+        };
+        let force = vortex.force(position, Vector::default(), 0.0);
+
+        // Counter-clockwise around +z: +x turns towards +y.
+        assert!(relative_eq!(force.x, 0.0));
+        assert!(relative_eq!(force.y, 1.5));
+        assert!(relative_eq!(force.z, 0.0));
+    }
+
+    #[test]
+    fn test_turbulence_is_deterministic_and_bounded() {
+        let turbulence = Turbulence {
+            strength: 2.0,
+            frequency: 1.3,
+            seed: 42,
+        };
+        let other_seed = Turbulence {
+            seed: 43,
+            ..turbulence
+        };
+
+        let mut differs = false;
+        for i in 0..200 {
+            let position = Point {
+                x: i as f64 * 0.37,
+                y: i as f64 * -0.11,
+                z: 1.0,
+            };
+            let force = turbulence.force(position, Vector::default(), i as f64 * 0.05);
+
+            assert_eq!(
+                force,
+                turbulence.force(position, Vector::default(), i as f64 * 0.05)
+            );
+            assert!(force.x.abs() <= 2.0 && force.y.abs() <= 2.0 && force.z.abs() <= 2.0);
+            differs |= force != other_seed.force(position, Vector::default(), i as f64 * 0.05);
+        }
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_turbulence_axes_are_independent_of_adjacent_seeds() {
+        let turbulence = |seed| Turbulence {
+            strength: 1.0,
+            frequency: 0.9,
+            seed,
+        };
+
+        for i in 0..50 {
+            let position = Point {
+                x: i as f64 * 0.53,
+                y: 2.0,
+                z: i as f64 * 0.29,
+            };
+            let force = |seed| turbulence(seed).force(position, Vector::default(), 0.0);
+
+            // The y and z axes of one seed are not the x and y axes of the next.
+            assert_ne!(force(7).y, force(8).x);
+            assert_ne!(force(7).z, force(8).y);
+            assert_ne!(force(7).x, force(7).y);
+        }
+    }
+
+    #[test]
+    fn test_turbulence_seed_zero_axes_differ() {
+        let turbulence = Turbulence {
+            strength: 1.0,
+            frequency: 0.8,
+            seed: 0,
+        };
+
+        for i in 0..50 {
+            let position = Point {
+                x: i as f64 * 0.41,
+                y: i as f64 * -0.23,
+                z: 0.5,
+            };
+            let force = turbulence.force(position, Vector::default(), i as f64 * 0.1);
+
+            assert_ne!(force.x, force.y);
+            assert_ne!(force.y, force.z);
+            assert_ne!(force.x, force.z);
+        }
+    }
+}
@@ -10,6 +10,7 @@
 //! // Run once to initialize.
 //! let mut spring = Spring::new(DeltaTime(natura::fps(60)), AngularFrequency(6.0), DampingRatio(0.5));
 //!
+//! # fn some_update_loop(mut f: impl FnMut()) { f() }
 //! // Update on every frame.
 //! let mut pos = 0.0;
 //! let mut velocity = 0.0;
@@ -22,31 +23,74 @@
 //! Projectile usage:
 //!
 //! ```
-//! use natura::{Projectile, Point, Vector};
-//! use std::borrow::{BorrowMut};
-//! let fps = 60;
-//! let time = &crate::fps(fps);
-//! let mut initial_acceleration = Vector { x: 0.0, y: 9.81, z: 0.0 };
-//! let mut initial_position = Point { x: 0.0, y: 0.0, z: 0.0 };
-//! let mut initial_velocity = Vector { x: 5.0, y: 5.0, z: 0.0 };
-//! let mut projectile = Projectile::new(
-//!     time,
-//!     initial_position.borrow_mut(),
-//!     initial_velocity.borrow_mut(),
-//!     initial_acceleration.borrow_mut());
+//! use natura::{Projectile, Point, Vector, DeltaTime};
+//! let delta_time = DeltaTime(natura::fps(60));
+//! let mut projectile = Projectile::launch(
+//!     Point { x: 0.0, y: 0.0, z: 0.0 },
+//!     Vector { x: 5.0, y: 5.0, z: 0.0 },
+//!     Vector { x: 0.0, y: 9.81, z: 0.0 });
 //!
+//! # fn some_update_loop(mut f: impl FnMut()) { f() }
 //! // Update on every frame.
 //! some_update_loop(|| {
-//!     let pos:&Point = projectile.update();
+//!     let pos: Point = projectile.update(delta_time);
 //! });
 //! ```
+//!
+//! # Cargo features
+//!
+//! - `std` (default): use the standard library for float math.
+//! - `alloc`: types that need heap allocations, such as [Projectile],
+//!   [ParticleSystem], [DynamicSpring] and [SpringBatch]. Implied by `std`.
+//! - `libm`: float math from the `libm` crate. Disable default features and
+//!   enable `libm` to build for `no_std` targets, with or without `alloc`.
+//! - `serde`, `glam`, `nalgebra`, `mint`: integrations with those crates.
+//! - `ron`, `toml`: load [SpringPresets] from RON or TOML files.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("natura needs either the `std` or the `libm` feature for float math");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod animatable;
+mod batch;
+#[cfg(feature = "alloc")]
+mod collision;
+mod easing;
+mod fixed;
+mod float;
+mod forces;
+#[cfg(not(feature = "std"))]
+mod math;
+mod params;
+#[cfg(feature = "alloc")]
+mod particles;
+#[cfg(feature = "alloc")]
+mod presets;
 mod projectile;
 mod spring;
 mod sprite;
+mod trajectory;
 
+pub use animatable::*;
+pub use batch::*;
+#[cfg(feature = "alloc")]
+pub use collision::*;
+pub use easing::*;
+pub use fixed::*;
+pub use float::*;
+pub use forces::*;
+pub use params::*;
+#[cfg(feature = "alloc")]
+pub use particles::*;
+#[cfg(feature = "alloc")]
+pub use presets::*;
 pub use projectile::*;
 pub use spring::*;
 pub use sprite::*;
+pub use trajectory::*;
 
 #[cfg(test)]
 #[macro_use]
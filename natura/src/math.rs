@@ -0,0 +1,62 @@
+//! This file defines the float methods `core` lacks, backed by the `libm`
+//! crate. It is only compiled without the `std` feature; modules that call
+//! these methods (e.g. `x.floor()`) import [Math], and [Float] for `exp`,
+//! `sin`, `cos`, `sqrt` and `cbrt`, when `std` is disabled.
+//!
+//! [Float]: crate::Float
+
+/// Math provides the float methods missing from `core` and [Float].
+///
+/// [Float]: crate::Float
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+pub(crate) trait Math: Sized {
+    fn ln(self) -> Self;
+    fn asin(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn floor(self) -> Self;
+    fn rem_euclid(self, other: Self) -> Self;
+}
+
+macro_rules! impl_math {
+    ($ty:ident, $fmod:ident, $($name:ident($($arg:ident),*) => $libm:ident),+ $(,)?) => {
+        impl Math for $ty {
+            $(
+                #[inline(always)]
+                fn $name(self $(, $arg: Self)*) -> Self {
+                    libm::$libm(self $(, $arg)*)
+                }
+            )+
+
+            #[inline(always)]
+            fn rem_euclid(self, other: Self) -> Self {
+                let remainder = libm::$fmod(self, other);
+                if remainder < 0.0 {
+                    remainder + other.abs()
+                } else {
+                    remainder
+                }
+            }
+        }
+    };
+}
+
+impl_math!(
+    f64,
+    fmod,
+    ln() => log,
+    asin() => asin,
+    atan() => atan,
+    atan2(other) => atan2,
+    floor() => floor,
+);
+
+impl_math!(
+    f32,
+    fmodf,
+    ln() => logf,
+    asin() => asinf,
+    atan() => atanf,
+    atan2(other) => atan2f,
+    floor() => floorf,
+);
@@ -0,0 +1,340 @@
+//! This file defines alternative ways to describe a spring. Besides angular
+//! frequency and damping ratio, springs are often described by a perceptual
+//! duration and bounce (SwiftUI, Framer), by physical stiffness, damping and
+//! mass (react-spring, CSS-like physics), or by the half-life of the motion.
+//! All of them map onto [AngularFrequency] and [DampingRatio], and can be
+//! converted back for display in tools.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{Spring, SpringParams, DurationBounce, PhysicalParams, DeltaTime};
+//!
+//! // A half-second spring with a little bounce:
+//! let params = SpringParams::from(DurationBounce { duration: 0.5, bounce: 0.25 });
+//! let mut spring = Spring::with_params(DeltaTime(natura::fps(60)), params);
+//!
+//! // The same spring, expressed with physical parameters for a unit mass:
+//! let physical: PhysicalParams = params.to_physical(1.0);
+//! ```
+#[cfg(not(feature = "std"))]
+use crate::Float;
+use crate::{AngularFrequency, DampingRatio, SpringTrajectory};
+use core::f64::consts::PI;
+
+/// Time, in units of `1/ω`, at which a critically damped spring released
+/// from rest has covered half the distance to its target: the root of
+/// `(1 + u)·e^(-u) = 1/2`.
+const CRITICAL_HALF_DISTANCE: f64 = 1.678_346_990_016_661;
+
+/// Number of bisection steps used to find the half-distance time.
+const HALF_LIFE_ITERATIONS: usize = 64;
+
+/// SpringParams holds the angular frequency and damping ratio of a spring, and
+/// converts from and to the other common ways to describe a spring.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringParams {
+    pub angular_frequency: AngularFrequency,
+    pub damping_ratio: DampingRatio,
+}
+
+/// DurationBounce describes a spring the way SwiftUI and Framer do.
+///
+/// * `duration` — the perceptual duration of the animation in seconds, i.e.
+///   the period of one oscillation. Must be positive.
+/// * `bounce` — how bouncy the spring is: `0.0` is critically damped (no
+///   bounce), values up to `1.0` add more bounce, and negative values down to
+///   (but excluding) `-1.0` make the spring over-damped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DurationBounce {
+    pub duration: f64,
+    pub bounce: f64,
+}
+
+/// PhysicalParams describes a spring by the physical properties of a mass
+/// attached to it, like react-spring and most physics engines do.
+///
+/// * `stiffness` — the spring constant `k`.
+/// * `damping` — the damping coefficient `c`.
+/// * `mass` — the attached mass `m`. Must be positive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhysicalParams {
+    pub stiffness: f64,
+    pub damping: f64,
+    pub mass: f64,
+}
+
+/// HalfLife describes a critically damped spring by the time in seconds it
+/// takes, released from rest, to cover half the distance to its target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct HalfLife(pub f64);
+
+impl SpringParams {
+    /// new creates spring parameters from an angular frequency and a damping
+    /// ratio. See [Spring::new](crate::Spring::new) for their meaning.
+    pub fn new(angular_frequency: AngularFrequency, damping_ratio: DampingRatio) -> Self {
+        SpringParams {
+            angular_frequency,
+            damping_ratio,
+        }
+    }
+
+    /// from_response creates spring parameters from a SwiftUI-style response
+    /// (the period of one oscillation in seconds) and damping fraction, which
+    /// is the damping ratio.
+    pub fn from_response(response: f64, damping_fraction: f64) -> Self {
+        SpringParams::new(
+            AngularFrequency(2.0 * PI / response),
+            DampingRatio(damping_fraction),
+        )
+    }
+
+    /// response returns the period of one oscillation in seconds, also known
+    /// as the perceptual duration of the spring.
+    pub fn response(&self) -> f64 {
+        2.0 * PI / self.angular_frequency.0
+    }
+
+    /// to_duration_bounce returns the spring as a perceptual duration and
+    /// bounce.
+    pub fn to_duration_bounce(&self) -> DurationBounce {
+        let damping_ratio = self.damping_ratio.0;
+        let bounce = if damping_ratio <= 1.0 {
+            1.0 - damping_ratio
+        } else {
+            1.0 / damping_ratio - 1.0
+        };
+
+        DurationBounce {
+            duration: self.response(),
+            bounce,
+        }
+    }
+
+    /// to_physical returns the stiffness and damping coefficient that give a
+    /// spring with the given mass the same motion.
+    pub fn to_physical(&self, mass: f64) -> PhysicalParams {
+        let omega = self.angular_frequency.0;
+
+        PhysicalParams {
+            stiffness: omega * omega * mass,
+            damping: 2.0 * self.damping_ratio.0 * omega * mass,
+            mass,
+        }
+    }
+
+    /// half_life returns the time in seconds the spring takes, released from
+    /// rest, to first cover half the distance to its target. A spring without
+    /// frequency never moves and has an infinite half-life.
+    pub fn half_life(&self) -> HalfLife {
+        let omega = self.angular_frequency.0;
+        let zeta = self.damping_ratio.0;
+        if omega <= 0.0 {
+            return HalfLife(f64::INFINITY);
+        }
+
+        let trajectory =
+            SpringTrajectory::new(self.angular_frequency, self.damping_ratio, 1.0, 0.0, 0.0);
+        let is_past_half = |time: f64| trajectory.position_at(time) <= 0.5;
+
+        // The position falls monotonically until the first turning point,
+        // which under-damped springs reach after half a period.
+        let mut high = CRITICAL_HALF_DISTANCE / omega;
+        if zeta < 1.0 {
+            high = PI / (omega * (1.0 - zeta * zeta).sqrt());
+        }
+        while !is_past_half(high) {
+            high *= 2.0;
+        }
+
+        let mut low = 0.0;
+        for _ in 0..HALF_LIFE_ITERATIONS {
+            let mid = (low + high) / 2.0;
+            if is_past_half(mid) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        HalfLife(high)
+    }
+}
+
+impl From<(AngularFrequency, DampingRatio)> for SpringParams {
+    fn from((angular_frequency, damping_ratio): (AngularFrequency, DampingRatio)) -> Self {
+        SpringParams::new(angular_frequency, damping_ratio)
+    }
+}
+
+impl From<DurationBounce> for SpringParams {
+    fn from(params: DurationBounce) -> Self {
+        let damping_ratio = if params.bounce >= 0.0 {
+            1.0 - params.bounce
+        } else {
+            1.0 / (1.0 + params.bounce)
+        };
+
+        SpringParams::new(
+            AngularFrequency(2.0 * PI / params.duration),
+            DampingRatio(damping_ratio),
+        )
+    }
+}
+
+impl From<PhysicalParams> for SpringParams {
+    fn from(params: PhysicalParams) -> Self {
+        SpringParams::new(
+            AngularFrequency((params.stiffness / params.mass).sqrt()),
+            DampingRatio(params.damping / (2.0 * (params.stiffness * params.mass).sqrt())),
+        )
+    }
+}
+
+impl From<HalfLife> for SpringParams {
+    fn from(half_life: HalfLife) -> Self {
+        SpringParams::new(
+            AngularFrequency(CRITICAL_HALF_DISTANCE / half_life.0),
+            DampingRatio(1.0),
+        )
+    }
+}
+
+impl From<SpringParams> for DurationBounce {
+    fn from(params: SpringParams) -> Self {
+        params.to_duration_bounce()
+    }
+}
+
+impl From<SpringParams> for HalfLife {
+    fn from(params: SpringParams) -> Self {
+        params.half_life()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AngularFrequency, DampingRatio, DurationBounce, HalfLife, PhysicalParams, SpringParams,
+        SpringTrajectory,
+    };
+
+    #[test]
+    fn test_duration_bounce_round_trip() {
+        for bounce in [-0.5, 0.0, 0.3, 0.9] {
+            let original = DurationBounce {
+                duration: 0.4,
+                bounce,
+            };
+            let back = SpringParams::from(original).to_duration_bounce();
+
+            assert!(relative_eq!(
+                back.duration,
+                original.duration,
+                epsilon = 1e-12
+            ));
+            assert!(relative_eq!(back.bounce, original.bounce, epsilon = 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_duration_bounce_maps_to_damping_ratio() {
+        let bouncy = SpringParams::from(DurationBounce {
+            duration: 1.0,
+            bounce: 0.25,
+        });
+        assert!(relative_eq!(bouncy.damping_ratio.0, 0.75));
+        assert!(relative_eq!(
+            bouncy.angular_frequency.0,
+            2.0 * core::f64::consts::PI
+        ));
+
+        let sluggish = SpringParams::from(DurationBounce {
+            duration: 1.0,
+            bounce: -0.5,
+        });
+        assert!(relative_eq!(sluggish.damping_ratio.0, 2.0));
+    }
+
+    #[test]
+    fn test_physical_round_trip() {
+        let original = PhysicalParams {
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 2.0,
+        };
+        let back = SpringParams::from(original).to_physical(2.0);
+
+        assert!(relative_eq!(
+            back.stiffness,
+            original.stiffness,
+            epsilon = 1e-9
+        ));
+        assert!(relative_eq!(back.damping, original.damping, epsilon = 1e-9));
+    }
+
+    #[test]
+    fn test_half_life_halves_distance_from_rest() {
+        let params = SpringParams::from(HalfLife(0.2));
+        assert_eq!(params.damping_ratio, DampingRatio(1.0));
+        assert!(relative_eq!(params.half_life().0, 0.2, epsilon = 1e-9));
+
+        // Released from rest, the spring is half way to the target after 0.2s.
+        let trajectory = SpringTrajectory::new(
+            params.angular_frequency,
+            params.damping_ratio,
+            100.0,
+            0.0,
+            0.0,
+        );
+        assert!(relative_eq!(
+            trajectory.position_at(0.2),
+            50.0,
+            epsilon = 1e-6
+        ));
+    }
+
+    #[test]
+    fn test_half_life_of_any_damping() {
+        for damping_ratio in [0.0, 0.3, 1.0, 2.5] {
+            let params = SpringParams::new(AngularFrequency(7.0), DampingRatio(damping_ratio));
+            let half_life = params.half_life().0;
+
+            let trajectory = SpringTrajectory::new(
+                params.angular_frequency,
+                params.damping_ratio,
+                1.0,
+                0.0,
+                0.0,
+            );
+            assert!(relative_eq!(
+                trajectory.position_at(half_life),
+                0.5,
+                epsilon = 1e-9
+            ));
+            assert!(trajectory.position_at(half_life * 0.99) > 0.5);
+        }
+
+        let frozen = SpringParams::new(AngularFrequency(0.0), DampingRatio(1.0));
+        assert_eq!(frozen.half_life(), HalfLife(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_response_matches_swiftui_definition() {
+        let params = SpringParams::from_response(0.5, 0.8);
+        assert!(relative_eq!(params.response(), 0.5, epsilon = 1e-12));
+        assert_eq!(params.damping_ratio, DampingRatio(0.8));
+        assert_eq!(
+            SpringParams::from((AngularFrequency(6.0), DampingRatio(0.5))),
+            SpringParams::new(AngularFrequency(6.0), DampingRatio(0.5))
+        );
+    }
+}
@@ -0,0 +1,744 @@
+//! This file defines a particle system: many short-lived projectiles spawned
+//! by emitters and advanced together. Particles are stored as a struct of
+//! arrays, so updating thousands of them touches contiguous memory.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{ParticleSystem, Emitter, EmitterShape, EmissionMode, Point, Vector, DeltaTime, GRAVITY};
+//!
+//! let sparks = Emitter::new(
+//!     Point { x: 0.0, y: 0.0, z: 0.0 },
+//!     EmitterShape::Cone { direction: Vector { x: 0.0, y: 1.0, z: 0.0 }, half_angle: 0.4 },
+//!     EmissionMode::Rate { per_second: 200.0 },
+//! )
+//! .with_speed(4.0, 8.0)
+//! .with_lifetime(0.5, 1.0)
+//! .with_acceleration(GRAVITY);
+//!
+//! let mut particles = ParticleSystem::new(42).with_emitter(sparks);
+//!
+//! # fn some_update_loop(mut f: impl FnMut()) { f() }
+//! // Update on every frame.
+//! some_update_loop(|| {
+//!     particles.update(DeltaTime(natura::fps(60)));
+//!     for particle in particles.iter() {
+//!         // draw particle.position, fading with particle.progress()
+//!     }
+//! });
+//! ```
+#[cfg(not(feature = "std"))]
+use crate::math::Math;
+use crate::{Animatable, Contact, DeltaTime, Float, Point, Projectile, ProjectileState, Vector};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+/// ParticleSystem stores particles and the emitters that spawn them.
+///
+/// Every particle moves like the system's physics [Projectile]: its
+/// integrator, mass, drag, wind, force fields and colliders apply to all
+/// particles, while each particle has its own position, velocity,
+/// acceleration and lifetime. Particles are removed once their age exceeds
+/// their lifetime.
+///
+/// Removing a particle moves the last particle into its slot, so indices are
+/// only stable between updates.
+///
+/// Like [Projectile], a particle system can compute with any [Float] type.
+/// Random numbers are drawn as `f64` and converted, so a system of
+/// [Fixed](crate::Fixed) numbers gives bit-identical particles on every
+/// platform.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParticleSystem<F: Float = f64> {
+    positions: Vec<Point<F>>,
+    velocities: Vec<Vector<F>>,
+    accelerations: Vec<Vector<F>>,
+    ages: Vec<F>,
+    lifetimes: Vec<F>,
+    emitters: Vec<Emitter<F>>,
+    physics: Projectile<F>,
+    max_particles: usize,
+    rng: ParticleRng,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    contacts: Vec<Contact<F>>,
+    elapsed: F,
+}
+
+/// Particle is a snapshot of a single particle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Particle<F = f64> {
+    /// position of the particle
+    pub position: Point<F>,
+    /// velocity of the particle
+    pub velocity: Vector<F>,
+    /// seconds since the particle was spawned
+    pub age: F,
+    /// seconds the particle lives for
+    pub lifetime: F,
+}
+
+/// Emitter spawns particles from a shape, either continuously or in bursts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Emitter<F = f64> {
+    /// origin of the emitter shape
+    pub position: Point<F>,
+    /// where particles spawn and which direction they move in
+    pub shape: EmitterShape<F>,
+    /// when particles spawn
+    pub mode: EmissionMode<F>,
+    /// velocity added to every particle, e.g. the velocity of a moving emitter
+    pub velocity: Vector<F>,
+    /// constant acceleration of spawned particles, usually gravity
+    pub acceleration: Vector<F>,
+    /// range of initial speeds along the emission direction
+    pub speed: (F, F),
+    /// range of lifetimes in seconds
+    pub lifetime: (F, F),
+    /// whether the emitter spawns particles
+    pub enabled: bool,
+    pending: F,
+    burst_fired: bool,
+}
+
+/// EmitterShape describes where particles spawn and in which direction their
+/// initial speed points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmitterShape<F = f64> {
+    /// Spawns at the emitter position, moving in any direction.
+    Point,
+    /// Spawns at the emitter position, moving within `half_angle` radians of
+    /// `direction`.
+    Cone { direction: Vector<F>, half_angle: F },
+    /// Spawns inside a sphere around the emitter position, moving outwards.
+    Sphere { radius: F },
+    /// Spawns inside a box centered on the emitter position, moving in any
+    /// direction.
+    Box { half_extents: Vector<F> },
+}
+
+/// EmissionMode describes when an emitter spawns particles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmissionMode<F = f64> {
+    /// Spawns `count` particles on the next update, once. Call
+    /// [Emitter::reset] to fire again.
+    Burst { count: usize },
+    /// Spawns particles continuously at the given rate.
+    Rate { per_second: F },
+}
+
+/// ParticleRng is a small, fast, seedable random number generator
+/// (SplitMix64). The same seed always produces the same particles, on every
+/// platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParticleRng {
+    state: u64,
+}
+
+impl ParticleRng {
+    /// new creates a random number generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        ParticleRng { state: seed }
+    }
+
+    /// next_u64 returns the next random 64-bit number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// next_f64 returns a random number in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// range returns a random number in `[min, max)`.
+    pub fn range<F: Float>(&mut self, min: F, max: F) -> F {
+        min + (max - min) * F::from_f64(self.next_f64())
+    }
+
+    /// unit_vector returns a random direction, uniformly distributed over
+    /// the sphere.
+    pub fn unit_vector<F: Float>(&mut self) -> Vector<F> {
+        let z = self.range(-F::ONE, F::ONE);
+        let angle = self.range(F::ZERO, F::from_f64(2.0 * PI));
+        let radius = (F::ONE - z * z).sqrt();
+
+        Vector {
+            x: radius * angle.cos(),
+            y: radius * angle.sin(),
+            z,
+        }
+    }
+}
+
+impl<F: Float> Emitter<F> {
+    /// new creates an emitter at the given position. Particles start with a
+    /// speed of `1.0`, live for one second and have no acceleration.
+    pub fn new(position: Point<F>, shape: EmitterShape<F>, mode: EmissionMode<F>) -> Self {
+        Emitter {
+            position,
+            shape,
+            mode,
+            velocity: Vector::default(),
+            acceleration: Vector::default(),
+            speed: (F::ONE, F::ONE),
+            lifetime: (F::ONE, F::ONE),
+            enabled: true,
+            pending: F::ZERO,
+            burst_fired: false,
+        }
+    }
+
+    /// with_speed returns the emitter spawning particles with an initial
+    /// speed between `min` and `max`.
+    pub fn with_speed(mut self, min: F, max: F) -> Self {
+        self.speed = (min, max);
+        self
+    }
+
+    /// with_lifetime returns the emitter spawning particles that live between
+    /// `min` and `max` seconds.
+    pub fn with_lifetime(mut self, min: F, max: F) -> Self {
+        self.lifetime = (min, max);
+        self
+    }
+
+    /// with_acceleration returns the emitter spawning particles with the given
+    /// constant acceleration.
+    pub fn with_acceleration(mut self, acceleration: Vector<F>) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// with_velocity returns the emitter adding `velocity` to every particle.
+    pub fn with_velocity(mut self, velocity: Vector<F>) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// reset lets a burst emitter fire again and drops any fraction of a
+    /// particle a rate emitter has accumulated.
+    pub fn reset(&mut self) {
+        self.pending = F::ZERO;
+        self.burst_fired = false;
+    }
+
+    /// emission_count returns how many particles to spawn for a step of
+    /// `delta_time` seconds.
+    fn emission_count(&mut self, delta_time: F) -> usize {
+        if !self.enabled {
+            return 0;
+        }
+
+        match self.mode {
+            EmissionMode::Burst { count } => {
+                if self.burst_fired {
+                    0
+                } else {
+                    self.burst_fired = true;
+                    count
+                }
+            }
+            EmissionMode::Rate { per_second } => {
+                // Whole particles are exact in every float type, so taking
+                // the floor in `f64` keeps fixed-point emission deterministic.
+                self.pending = self.pending + per_second * delta_time;
+                let count = self.pending.to_f64().floor();
+                self.pending = self.pending - F::from_f64(count);
+                count as usize
+            }
+        }
+    }
+
+    /// sample returns the initial state and lifetime of a new particle.
+    fn sample(&self, rng: &mut ParticleRng) -> (ProjectileState<F>, F) {
+        let (offset, direction) = match self.shape {
+            EmitterShape::Point => (Vector::default(), rng.unit_vector()),
+            EmitterShape::Cone {
+                direction,
+                half_angle,
+            } => (
+                Vector::default(),
+                cone_direction(rng, direction, half_angle),
+            ),
+            EmitterShape::Sphere { radius } => {
+                let direction = rng.unit_vector();
+                let distance = radius * F::from_f64(rng.next_f64()).cbrt();
+                (direction.scale(distance), direction)
+            }
+            EmitterShape::Box { half_extents } => {
+                let offset = Vector {
+                    x: rng.range(-half_extents.x, half_extents.x),
+                    y: rng.range(-half_extents.y, half_extents.y),
+                    z: rng.range(-half_extents.z, half_extents.z),
+                };
+                (offset, rng.unit_vector())
+            }
+        };
+
+        let speed = rng.range(self.speed.0, self.speed.1);
+        let lifetime = rng.range(self.lifetime.0, self.lifetime.1);
+        let state = ProjectileState {
+            position: self.position.add(Point {
+                x: offset.x,
+                y: offset.y,
+                z: offset.z,
+            }),
+            velocity: direction.scale(speed).add(self.velocity),
+            acceleration: self.acceleration,
+        };
+
+        (state, lifetime)
+    }
+}
+
+/// cone_direction returns a random direction within `half_angle` radians of
+/// `axis`, uniformly distributed over the spherical cap.
+fn cone_direction<F: Float>(rng: &mut ParticleRng, axis: Vector<F>, half_angle: F) -> Vector<F> {
+    let axis = axis.normalized();
+    let cos_theta = rng.range(half_angle.cos(), F::ONE);
+    let sin_theta = (F::ONE - cos_theta * cos_theta).sqrt();
+    let phi = rng.range(F::ZERO, F::from_f64(2.0 * PI));
+
+    // Any vector not parallel to the axis gives an orthonormal basis.
+    let helper = if axis.x.abs() < F::from_f64(0.9) {
+        Vector {
+            x: F::ONE,
+            y: F::ZERO,
+            z: F::ZERO,
+        }
+    } else {
+        Vector {
+            x: F::ZERO,
+            y: F::ONE,
+            z: F::ZERO,
+        }
+    };
+    let u = axis.cross(helper).normalized();
+    let v = axis.cross(u);
+
+    axis.scale(cos_theta)
+        .add(u.scale(sin_theta * phi.cos()))
+        .add(v.scale(sin_theta * phi.sin()))
+}
+
+impl<F: Float> ParticleSystem<F> {
+    /// new creates an empty particle system whose random numbers are drawn
+    /// from the given seed.
+    pub fn new(seed: u64) -> Self {
+        ParticleSystem {
+            positions: Vec::new(),
+            velocities: Vec::new(),
+            accelerations: Vec::new(),
+            ages: Vec::new(),
+            lifetimes: Vec::new(),
+            emitters: Vec::new(),
+            physics: Projectile::default(),
+            max_particles: usize::MAX,
+            rng: ParticleRng::new(seed),
+            contacts: Vec::new(),
+            elapsed: F::ZERO,
+        }
+    }
+
+    /// with_emitter returns the particle system with an additional emitter.
+    pub fn with_emitter(mut self, emitter: Emitter<F>) -> Self {
+        self.emitters.push(emitter);
+        self
+    }
+
+    /// with_physics returns the particle system moving every particle like
+    /// the given projectile. Only the integrator, mass, drag, wind, force
+    /// fields, radius and colliders are used; the position, velocity and
+    /// acceleration of the projectile are ignored.
+    ///
+    /// ```
+    /// use natura::{ParticleSystem, Projectile, Integrator, Vector};
+    /// let smoke = ParticleSystem::new(7).with_physics(
+    ///     Projectile::default()
+    ///         .with_integrator(Integrator::SemiImplicitEuler)
+    ///         .with_drag(0.8, 0.0)
+    ///         .with_wind(Vector { x: 1.5, y: 0.5, z: 0.0 }),
+    /// );
+    /// ```
+    pub fn with_physics(mut self, physics: Projectile<F>) -> Self {
+        self.physics = physics;
+        self
+    }
+
+    /// with_max_particles returns the particle system limited to `max`
+    /// particles. Emitters stop spawning while the system is full.
+    pub fn with_max_particles(mut self, max: usize) -> Self {
+        self.max_particles = max;
+        self
+    }
+
+    /// emitters returns the emitters of the system.
+    pub fn emitters(&self) -> &[Emitter<F>] {
+        &self.emitters
+    }
+
+    /// emitters_mut returns the emitters of the system for modification, e.g.
+    /// to move them or switch them off.
+    pub fn emitters_mut(&mut self) -> &mut Vec<Emitter<F>> {
+        &mut self.emitters
+    }
+
+    /// physics returns the projectile every particle moves like.
+    pub fn physics(&self) -> &Projectile<F> {
+        &self.physics
+    }
+
+    /// physics_mut returns the projectile every particle moves like for
+    /// modification.
+    pub fn physics_mut(&mut self) -> &mut Projectile<F> {
+        &mut self.physics
+    }
+
+    /// spawn adds a single particle, unless the system is full. It returns
+    /// whether the particle was added.
+    pub fn spawn(&mut self, state: ProjectileState<F>, lifetime: F) -> bool {
+        if self.len() >= self.max_particles {
+            return false;
+        }
+
+        self.positions.push(state.position);
+        self.velocities.push(state.velocity);
+        self.accelerations.push(state.acceleration);
+        self.ages.push(F::ZERO);
+        self.lifetimes.push(lifetime);
+        true
+    }
+
+    /// update advances every particle by the given delta time, removes
+    /// particles that outlived their lifetime, and spawns new particles from
+    /// the emitters.
+    pub fn update(&mut self, delta_time: DeltaTime<F>) {
+        let dt = delta_time.0;
+        self.contacts.clear();
+
+        let mut i = 0;
+        while i < self.len() {
+            self.ages[i] = self.ages[i] + dt;
+            if self.ages[i] >= self.lifetimes[i] {
+                self.remove(i);
+                continue;
+            }
+
+            let mut state = ProjectileState {
+                position: self.positions[i],
+                velocity: self.velocities[i],
+                acceleration: self.accelerations[i],
+            };
+            self.physics
+                .advance(&mut state, delta_time, self.elapsed, &mut self.contacts);
+            self.positions[i] = state.position;
+            self.velocities[i] = state.velocity;
+            i += 1;
+        }
+
+        for e in 0..self.emitters.len() {
+            let count = self.emitters[e].emission_count(dt);
+            for _ in 0..count {
+                let (state, lifetime) = self.emitters[e].sample(&mut self.rng);
+                if !self.spawn(state, lifetime) {
+                    break;
+                }
+            }
+        }
+
+        self.elapsed = self.elapsed + dt;
+    }
+
+    /// remove removes the particle at `index` by moving the last particle
+    /// into its slot.
+    fn remove(&mut self, index: usize) {
+        self.positions.swap_remove(index);
+        self.velocities.swap_remove(index);
+        self.accelerations.swap_remove(index);
+        self.ages.swap_remove(index);
+        self.lifetimes.swap_remove(index);
+    }
+
+    /// clear removes all particles. Emitters keep their state.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.velocities.clear();
+        self.accelerations.clear();
+        self.ages.clear();
+        self.lifetimes.clear();
+    }
+
+    /// len returns the number of live particles.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// is_empty reports whether there are no live particles.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// positions returns the positions of all live particles.
+    pub fn positions(&self) -> &[Point<F>] {
+        &self.positions
+    }
+
+    /// velocities returns the velocities of all live particles.
+    pub fn velocities(&self) -> &[Vector<F>] {
+        &self.velocities
+    }
+
+    /// contacts returns the collisions of all particles with the colliders
+    /// of the physics projectile during the last update.
+    pub fn contacts(&self) -> &[Contact<F>] {
+        &self.contacts
+    }
+
+    /// ages returns the ages in seconds of all live particles.
+    pub fn ages(&self) -> &[F] {
+        &self.ages
+    }
+
+    /// lifetimes returns the lifetimes in seconds of all live particles.
+    pub fn lifetimes(&self) -> &[F] {
+        &self.lifetimes
+    }
+
+    /// get returns a snapshot of the particle at `index`.
+    pub fn get(&self, index: usize) -> Option<Particle<F>> {
+        (index < self.len()).then(|| Particle {
+            position: self.positions[index],
+            velocity: self.velocities[index],
+            age: self.ages[index],
+            lifetime: self.lifetimes[index],
+        })
+    }
+
+    /// iter returns snapshots of all live particles.
+    pub fn iter(&self) -> impl Iterator<Item = Particle<F>> + '_ {
+        (0..self.len()).filter_map(|i| self.get(i))
+    }
+}
+
+impl<F: Float> Particle<F> {
+    /// progress returns how far the particle is through its life, from `0.0`
+    /// when spawned to `1.0` when it dies. Useful to fade or shrink it.
+    pub fn progress(&self) -> F {
+        if self.lifetime <= F::ZERO {
+            F::ONE
+        } else {
+            (self.age / self.lifetime).max(F::ZERO).min(F::ONE)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DeltaTime, EmissionMode, Emitter, EmitterShape, ParticleRng, ParticleSystem, Point,
+        Projectile, ProjectileState, Vector,
+    };
+
+    fn burst(shape: EmitterShape, count: usize) -> Emitter {
+        Emitter::new(
+            Point {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            shape,
+            EmissionMode::Burst { count },
+        )
+        .with_speed(2.0, 4.0)
+        .with_lifetime(10.0, 10.0)
+    }
+
+    #[test]
+    fn test_rate_emission_accumulates() {
+        let emitter = Emitter::new(
+            Point::default(),
+            EmitterShape::Point,
+            EmissionMode::Rate { per_second: 25.0 },
+        );
+        let mut system = ParticleSystem::new(1).with_emitter(emitter);
+
+        for _ in 0..10 {
+            system.update(DeltaTime(0.01));
+        }
+        // 10 steps of 0.25 particles each.
+        assert_eq!(system.len(), 2);
+
+        for _ in 0..30 {
+            system.update(DeltaTime(0.01));
+        }
+        assert_eq!(system.len(), 10);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip_continues_identically() {
+        let emitter = Emitter::new(
+            Point::default(),
+            EmitterShape::Sphere { radius: 1.0 },
+            EmissionMode::Rate { per_second: 30.0 },
+        );
+        let mut system = ParticleSystem::new(7).with_emitter(emitter);
+        for _ in 0..20 {
+            system.update(DeltaTime(0.05));
+        }
+
+        let json = serde_json::to_string(&system).unwrap();
+        let mut back: ParticleSystem = serde_json::from_str(&json).unwrap();
+        for _ in 0..20 {
+            system.update(DeltaTime(0.05));
+            back.update(DeltaTime(0.05));
+        }
+        assert_eq!(back.positions(), system.positions());
+        assert_eq!(back.velocities(), system.velocities());
+    }
+
+    #[test]
+    fn test_burst_fires_once_and_respects_capacity() {
+        let mut system = ParticleSystem::new(1)
+            .with_emitter(burst(EmitterShape::Point, 100))
+            .with_max_particles(64);
+
+        system.update(DeltaTime(0.01));
+        assert_eq!(system.len(), 64);
+        system.update(DeltaTime(0.01));
+        assert_eq!(system.len(), 64);
+
+        system.clear();
+        system.emitters_mut()[0].reset();
+        system.update(DeltaTime(0.01));
+        assert_eq!(system.len(), 64);
+    }
+
+    #[test]
+    fn test_particles_expire() {
+        let mut system = ParticleSystem::new(1);
+        let state = ProjectileState::default();
+        system.spawn(state, 0.05);
+        system.spawn(state, 0.5);
+        system.spawn(state, 0.15);
+
+        for _ in 0..10 {
+            system.update(DeltaTime(0.02));
+        }
+
+        assert_eq!(system.len(), 1);
+        assert_eq!(system.lifetimes(), &[0.5]);
+        assert!(relative_eq!(system.get(0).unwrap().progress(), 0.4));
+    }
+
+    #[test]
+    fn test_shapes_respect_bounds() {
+        let origin = Point {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let up = Vector {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        let half_angle: f64 = 0.3;
+
+        let mut cone = ParticleSystem::new(3).with_emitter(burst(
+            EmitterShape::Cone {
+                direction: up,
+                half_angle,
+            },
+            500,
+        ));
+        cone.update(DeltaTime(0.0));
+        for particle in cone.iter() {
+            let speed = particle.velocity.length();
+            assert!((2.0..4.0).contains(&speed));
+            assert!(particle.velocity.dot(up) / speed >= half_angle.cos() - 1e-12);
+            assert_eq!(particle.position, origin);
+        }
+
+        let mut sphere =
+            ParticleSystem::new(3).with_emitter(burst(EmitterShape::Sphere { radius: 2.0 }, 500));
+        sphere.update(DeltaTime(0.0));
+        for particle in sphere.iter() {
+            let offset = origin.to(particle.position);
+            assert!(offset.length() <= 2.0 + 1e-12);
+            // Moving outwards from the center.
+            assert!(offset.dot(particle.velocity) >= 0.0);
+        }
+
+        let half_extents = Vector {
+            x: 1.0,
+            y: 0.5,
+            z: 0.0,
+        };
+        let mut cuboid =
+            ParticleSystem::new(3).with_emitter(burst(EmitterShape::Box { half_extents }, 500));
+        cuboid.update(DeltaTime(0.0));
+        for particle in cuboid.iter() {
+            let offset = origin.to(particle.position);
+            assert!(offset.x.abs() <= 1.0 && offset.y.abs() <= 0.5 && offset.z == 0.0);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_particles() {
+        let make = |seed| {
+            let mut system = ParticleSystem::new(seed).with_emitter(burst(EmitterShape::Point, 50));
+            for _ in 0..10 {
+                system.update(DeltaTime(crate::fps(60)));
+            }
+            system.positions().to_vec()
+        };
+
+        assert_eq!(make(9), make(9));
+        assert_ne!(make(9), make(10));
+
+        let mut rng = ParticleRng::new(5);
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&rng.next_f64()));
+            assert!(relative_eq!(
+                rng.unit_vector::<f64>().length(),
+                1.0,
+                epsilon = 1e-12
+            ));
+        }
+    }
+
+    #[test]
+    fn test_particles_move_like_physics_projectile() {
+        let velocity = Vector {
+            x: 3.0,
+            y: 4.0,
+            z: 0.0,
+        };
+        let physics = Projectile::default().with_drag(0.5, 0.1);
+        let mut projectile = physics.clone();
+        projectile.set_velocity(velocity);
+        projectile.set_acceleration(crate::GRAVITY);
+
+        let mut system = ParticleSystem::new(0).with_physics(physics);
+        system.spawn(projectile.state(), 10.0);
+
+        for _ in 0..60 {
+            system.update(DeltaTime(crate::fps(60)));
+            projectile.update(DeltaTime(crate::fps(60)));
+        }
+
+        assert_eq!(system.positions()[0], projectile.position());
+        assert_eq!(system.velocities()[0], projectile.velocity());
+    }
+}
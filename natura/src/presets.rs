@@ -0,0 +1,419 @@
+//! This file defines named spring presets. The built-in presets match the
+//! configurations popular UI animation libraries ship with (react-spring's
+//! `gentle`, `wobbly`, `stiff`, `slow` and `molasses`, and SwiftUI's
+//! `snappy`), so motion can be described by name instead of by numbers.
+//!
+//! A [SpringPresets] registry starts out with the built-ins and can be
+//! extended or overridden with custom presets, for example loaded from a RON
+//! or TOML file with the `ron` or `toml` feature:
+//!
+//! ```
+//! use natura::{Spring, SpringPreset, SpringPresets, DeltaTime, DurationBounce};
+//!
+//! let mut presets = SpringPresets::default();
+//! presets.insert("button", DurationBounce { duration: 0.3, bounce: 0.1 });
+//!
+//! let params = presets.get("wobbly").unwrap();
+//! assert_eq!(params, SpringPreset::Wobbly.params());
+//! let mut spring = Spring::with_params(DeltaTime(natura::fps(60)), params);
+//! ```
+//!
+//! In a config file each preset is described by name, either as a built-in
+//! preset, as an angular frequency and damping ratio, as a duration and
+//! bounce, as a stiffness, damping and mass, or as a half-life:
+//!
+//! ```toml
+//! button = { duration = 0.3, bounce = 0.1 }
+//! card = { stiffness = 170.0, damping = 26.0, mass = 1.0 }
+//! toast = { angular_frequency = 8.0, damping_ratio = 0.6 }
+//! cursor = { half_life = 0.05 }
+//! dialog = "gentle"
+//! ```
+use crate::{DurationBounce, PhysicalParams, SpringParams};
+use alloc::collections::btree_map::{self, BTreeMap};
+use alloc::string::String;
+
+/// SpringPreset names one of the built-in spring presets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum SpringPreset {
+    /// A soft spring with a little bounce (react-spring `gentle`).
+    Gentle,
+    /// A bouncy spring (react-spring `wobbly`).
+    Wobbly,
+    /// A fast spring with a small bounce (react-spring `stiff`).
+    Stiff,
+    /// A slow spring without bounce (react-spring `slow`).
+    Slow,
+    /// A very slow, heavily damped spring (react-spring `molasses`).
+    Molasses,
+    /// A quick spring with a small bounce (SwiftUI `snappy`).
+    Snappy,
+}
+
+impl SpringPreset {
+    /// ALL lists every built-in preset.
+    pub const ALL: [SpringPreset; 6] = [
+        SpringPreset::Gentle,
+        SpringPreset::Wobbly,
+        SpringPreset::Stiff,
+        SpringPreset::Slow,
+        SpringPreset::Molasses,
+        SpringPreset::Snappy,
+    ];
+
+    /// name returns the name the preset is registered under.
+    pub fn name(self) -> &'static str {
+        match self {
+            SpringPreset::Gentle => "gentle",
+            SpringPreset::Wobbly => "wobbly",
+            SpringPreset::Stiff => "stiff",
+            SpringPreset::Slow => "slow",
+            SpringPreset::Molasses => "molasses",
+            SpringPreset::Snappy => "snappy",
+        }
+    }
+
+    /// from_name returns the built-in preset with the given name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        SpringPreset::ALL
+            .into_iter()
+            .find(|preset| preset.name() == name)
+    }
+
+    /// params returns the angular frequency and damping ratio of the preset.
+    /// They are computed with `f64` math, so a `Spring<Fixed>` built from a
+    /// preset is not guaranteed to be bit-identical across targets.
+    pub fn params(self) -> SpringParams {
+        // react-spring describes its presets by tension and friction with a
+        // unit mass.
+        let physical = |stiffness, damping| PhysicalParams {
+            stiffness,
+            damping,
+            mass: 1.0,
+        };
+
+        match self {
+            SpringPreset::Gentle => physical(120.0, 14.0).into(),
+            SpringPreset::Wobbly => physical(180.0, 12.0).into(),
+            SpringPreset::Stiff => physical(210.0, 20.0).into(),
+            SpringPreset::Slow => physical(280.0, 60.0).into(),
+            SpringPreset::Molasses => physical(280.0, 120.0).into(),
+            SpringPreset::Snappy => DurationBounce {
+                duration: 0.5,
+                bounce: 0.15,
+            }
+            .into(),
+        }
+    }
+}
+
+impl From<SpringPreset> for SpringParams {
+    fn from(preset: SpringPreset) -> Self {
+        preset.params()
+    }
+}
+
+/// SpringPresets is a registry of named spring parameters. The default
+/// registry holds the built-in [SpringPreset]s; inserting a preset with the
+/// same name overrides it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        from = "BTreeMap<String, PresetConfig>",
+        into = "BTreeMap<String, SpringParams>"
+    )
+)]
+pub struct SpringPresets {
+    presets: BTreeMap<String, SpringParams>,
+}
+
+impl Default for SpringPresets {
+    fn default() -> Self {
+        SpringPreset::ALL
+            .into_iter()
+            .map(|preset| (preset.name(), preset))
+            .collect()
+    }
+}
+
+impl SpringPresets {
+    /// new creates an empty registry, without the built-in presets.
+    pub fn new() -> Self {
+        SpringPresets {
+            presets: BTreeMap::new(),
+        }
+    }
+
+    /// get returns the parameters of the preset with the given name.
+    pub fn get(&self, name: &str) -> Option<SpringParams> {
+        self.presets.get(name).copied()
+    }
+
+    /// insert adds a preset, returning the parameters it replaced.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        params: impl Into<SpringParams>,
+    ) -> Option<SpringParams> {
+        self.presets.insert(name.into(), params.into())
+    }
+
+    /// remove removes a preset, returning its parameters.
+    pub fn remove(&mut self, name: &str) -> Option<SpringParams> {
+        self.presets.remove(name)
+    }
+
+    /// contains returns whether a preset with the given name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.presets.contains_key(name)
+    }
+
+    /// names returns the preset names in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.presets.keys().map(String::as_str)
+    }
+
+    /// iter returns the presets in alphabetical order of their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, SpringParams)> {
+        self.presets
+            .iter()
+            .map(|(name, params)| (name.as_str(), *params))
+    }
+
+    /// len returns the number of presets.
+    pub fn len(&self) -> usize {
+        self.presets.len()
+    }
+
+    /// is_empty returns whether the registry holds no presets.
+    pub fn is_empty(&self) -> bool {
+        self.presets.is_empty()
+    }
+
+    /// from_ron parses presets from a RON map of names to preset
+    /// descriptions. Extend the default registry with the result to override
+    /// the built-ins.
+    #[cfg(feature = "ron")]
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// from_toml parses presets from a TOML table of names to preset
+    /// descriptions. Extend the default registry with the result to override
+    /// the built-ins.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(source)
+    }
+}
+
+impl<N: Into<String>, P: Into<SpringParams>> Extend<(N, P)> for SpringPresets {
+    fn extend<I: IntoIterator<Item = (N, P)>>(&mut self, iter: I) {
+        for (name, params) in iter {
+            self.insert(name, params);
+        }
+    }
+}
+
+impl<N: Into<String>, P: Into<SpringParams>> FromIterator<(N, P)> for SpringPresets {
+    fn from_iter<I: IntoIterator<Item = (N, P)>>(iter: I) -> Self {
+        let mut presets = SpringPresets::new();
+        presets.extend(iter);
+        presets
+    }
+}
+
+impl IntoIterator for SpringPresets {
+    type Item = (String, SpringParams);
+    type IntoIter = btree_map::IntoIter<String, SpringParams>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.presets.into_iter()
+    }
+}
+
+impl From<SpringPresets> for BTreeMap<String, SpringParams> {
+    fn from(presets: SpringPresets) -> Self {
+        presets.presets
+    }
+}
+
+/// PresetConfig is any of the ways a preset can be described in a config
+/// file.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum PresetConfig {
+    Preset(SpringPreset),
+    Params(SpringParams),
+    DurationBounce(DurationBounce),
+    Physical(PhysicalParams),
+    HalfLife { half_life: crate::HalfLife },
+}
+
+#[cfg(feature = "serde")]
+impl From<PresetConfig> for SpringParams {
+    fn from(config: PresetConfig) -> Self {
+        match config {
+            PresetConfig::Preset(preset) => preset.into(),
+            PresetConfig::Params(params) => params,
+            PresetConfig::DurationBounce(params) => params.into(),
+            PresetConfig::Physical(params) => params.into(),
+            PresetConfig::HalfLife { half_life } => half_life.into(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<BTreeMap<String, PresetConfig>> for SpringPresets {
+    fn from(configs: BTreeMap<String, PresetConfig>) -> Self {
+        configs.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AngularFrequency, DampingRatio, DurationBounce, SpringParams, SpringPreset, SpringPresets,
+    };
+
+    #[test]
+    fn test_builtin_presets() {
+        let presets = SpringPresets::default();
+        assert_eq!(presets.len(), SpringPreset::ALL.len());
+
+        for preset in SpringPreset::ALL {
+            assert_eq!(presets.get(preset.name()), Some(preset.params()));
+            assert_eq!(SpringPreset::from_name(preset.name()), Some(preset));
+        }
+
+        // react-spring's gentle preset: tension 120, friction 14.
+        let gentle = SpringPreset::Gentle.params();
+        assert!(relative_eq!(gentle.angular_frequency.0, 120.0_f64.sqrt()));
+        assert!(relative_eq!(
+            gentle.damping_ratio.0,
+            14.0 / (2.0 * 120.0_f64.sqrt())
+        ));
+
+        // Molasses is over-damped, wobbly is the bounciest.
+        assert!(SpringPreset::Molasses.params().damping_ratio.0 > 1.0);
+        assert!(SpringPreset::ALL
+            .iter()
+            .all(|p| p.params().damping_ratio.0 >= SpringPreset::Wobbly.params().damping_ratio.0));
+    }
+
+    #[test]
+    fn test_override_presets() {
+        let mut presets = SpringPresets::default();
+        let custom = SpringParams::new(AngularFrequency(10.0), DampingRatio(0.9));
+
+        assert_eq!(
+            presets.insert("gentle", custom),
+            Some(SpringPreset::Gentle.params())
+        );
+        presets.extend([(
+            "button",
+            SpringParams::from(DurationBounce {
+                duration: 0.3,
+                bounce: 0.1,
+            }),
+        )]);
+
+        assert_eq!(presets.get("gentle"), Some(custom));
+        assert!(presets.contains("button"));
+        assert!(presets.remove("button").is_some());
+        assert!(!presets.contains("button"));
+        assert!(SpringPresets::new().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_presets_from_json() {
+        let presets: SpringPresets = serde_json::from_str(
+            r#"{
+                "button": { "duration": 0.3, "bounce": 0.1 },
+                "card": { "stiffness": 170.0, "damping": 26.0, "mass": 1.0 },
+                "toast": { "angular_frequency": 8.0, "damping_ratio": 0.6 },
+                "cursor": { "half_life": 0.05 },
+                "dialog": "gentle"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(presets.len(), 5);
+        assert_eq!(presets.get("dialog"), Some(SpringPreset::Gentle.params()));
+        assert_eq!(
+            presets.get("toast"),
+            Some(SpringParams::new(AngularFrequency(8.0), DampingRatio(0.6)))
+        );
+        assert!(relative_eq!(
+            presets.get("cursor").unwrap().half_life().0,
+            0.05
+        ));
+
+        // Presets serialize as angular frequency and damping ratio.
+        let toast: SpringPresets = presets
+            .iter()
+            .filter(|(name, _)| *name == "toast")
+            .collect();
+        assert_eq!(
+            serde_json::to_string(&toast).unwrap(),
+            r#"{"toast":{"angular_frequency":8.0,"damping_ratio":0.6}}"#
+        );
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_presets_from_ron() {
+        let mut presets = SpringPresets::default();
+        presets.extend(
+            SpringPresets::from_ron(
+                r#"{
+                    "wobbly": (angular_frequency: 12.0, damping_ratio: 0.4),
+                    "button": (duration: 0.3, bounce: 0.1),
+                    "dialog": "stiff",
+                }"#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(presets.len(), SpringPreset::ALL.len() + 2);
+        assert_eq!(
+            presets.get("wobbly"),
+            Some(SpringParams::new(AngularFrequency(12.0), DampingRatio(0.4)))
+        );
+        assert_eq!(presets.get("dialog"), Some(SpringPreset::Stiff.params()));
+        assert!(SpringPresets::from_ron("{ \"oops\": (bounce: 0.1) }").is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_presets_from_toml() {
+        let mut presets = SpringPresets::default();
+        presets.extend(
+            SpringPresets::from_toml(
+                r#"
+                    stiff = { stiffness = 300.0, damping = 30.0, mass = 1.0 }
+                    cursor = { half_life = 0.05 }
+                    dialog = "snappy"
+                "#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(presets.len(), SpringPreset::ALL.len() + 2);
+        assert!(relative_eq!(
+            presets.get("stiff").unwrap().angular_frequency.0,
+            300.0_f64.sqrt()
+        ));
+        assert_eq!(presets.get("dialog"), Some(SpringPreset::Snappy.params()));
+        assert!(SpringPresets::from_toml("oops = 1.0").is_err());
+    }
+}
@@ -1,33 +1,112 @@
-/// This file defines simple physics projectile motion.
+//! This file defines simple physics projectile motion.
+//!
+//! Example projectile usage:
+//!
+//! ```
+//! use natura::{Projectile, Point, Vector, DeltaTime};
+//! let delta_time = DeltaTime(natura::fps(60));
+//! let mut projectile = Projectile::launch(
+//!     Point { x: 0.0, y: 0.0, z: 0.0 },
+//!     Vector { x: 5.0, y: 5.0, z: 0.0 },
+//!     Vector { x: 0.0, y: 9.81, z: 0.0 });
+//!
+//! # fn some_update_loop(mut f: impl FnMut()) { f() }
+//! // Update on every frame.
+//! some_update_loop(|| {
+//!     let pos: Point = projectile.update(delta_time);
+//! });
+//! ```
+//!
+//! For background on projectile motion see:
+//! https://en.wikipedia.org/wiki/Projectile_motion
+#[cfg(feature = "alloc")]
+use crate::collision::resolve_collisions;
+#[cfg(not(feature = "std"))]
+use crate::math::Math;
+use crate::{Animatable, DeltaTime, Float, EPSILON};
+#[cfg(feature = "alloc")]
+use crate::{Collider, Contact, ForceField};
+#[cfg(feature = "alloc")]
+use alloc::{sync::Arc, vec::Vec};
+
+/// Projectile is the representation of a projectile that has a position on
+/// a plane, an acceleration, and velocity.
 ///
-/// Example projectile usage:
+/// A projectile owns its state, so it can be stored in collections or
+/// components and sent across threads. To drive position and velocity values
+/// that live elsewhere, use [ProjectileMut], which [Projectile::new] returns.
 ///
-/// ```
-/// use natura::{Projectile, Point, Vector};
-/// use std::borrow::{BorrowMut};
-/// let fps = 60;
-/// let time = &crate::fps(fps);
-/// let mut initial_acceleration = Vector { x: 0.0, y: 9.81, z: 0.0 };
-/// let mut initial_position = Point { x: 0.0, y: 0.0, z: 0.0 };
-/// let mut initial_velocity = Vector { x: 5.0, y: 5.0, z: 0.0 };
-/// let mut projectile = Projectile::new(
-///     time,
-///     initial_position.borrow_mut(),
-///     initial_velocity.borrow_mut(),
-///     initial_acceleration.borrow_mut());
+/// Besides its constant acceleration, a projectile can be slowed down by
+/// drag, pushed by wind, and moved by any number of [ForceField]s. Forces are
+/// divided by the mass of the projectile.
 ///
-/// // Update on every frame.
-/// some_update_loop(|| {
-///     let pos:&Point = projectile.update();
-/// });
-/// ```
+/// Like [ProjectileState], a projectile can compute with any [Float] type.
+/// With [Fixed](crate::Fixed) numbers every update, including drag, force
+/// fields and collisions, gives bit-identical results on every platform.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Projectile<F: Float = f64> {
+    state: ProjectileState<F>,
+    integrator: Integrator,
+    mass: F,
+    linear_drag: F,
+    quadratic_drag: F,
+    wind: Vector<F>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    force_fields: Vec<Arc<dyn ForceField<F>>>,
+    radius: F,
+    colliders: Vec<Collider<F>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    contacts: Vec<Contact<F>>,
+    elapsed: F,
+}
+
+/// Integrator selects the numerical method used to advance a projectile.
 ///
-/// For background on projectile motion see:
-/// https://en.wikipedia.org/wiki/Projectile_motion
+/// With a constant acceleration, `VelocityVerlet`, `RungeKutta4` and
+/// `Analytic` follow the exact parabola; `ExplicitEuler` and
+/// `SemiImplicitEuler` drift from it by an error proportional to the time
+/// step. The methods differ once acceleration depends on position or velocity.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Integrator {
+    /// Updates position with the old velocity, then velocity. Cheapest, but
+    /// drifts noticeably at game frame rates.
+    #[default]
+    ExplicitEuler,
+    /// Updates velocity first, then position with the new velocity. As cheap
+    /// as explicit Euler, and stable for oscillating forces.
+    SemiImplicitEuler,
+    /// Second-order method that averages the accelerations at the start and
+    /// end of the step.
+    VelocityVerlet,
+    /// Classic fourth-order Runge–Kutta method, most accurate for forces that
+    /// depend on position or velocity.
+    RungeKutta4,
+    /// Closed-form solution for an acceleration held constant over the step.
+    Analytic,
+}
 
-/// Projectile is the representation of a projectile that has a position on
-/// a plane, an acceleration, and velocity.
-pub struct Projectile<'a> {
+/// ProjectileState is a snapshot of the motion of a projectile.
+///
+/// Like [Point] and [Vector], a state can hold any [Float] type. With
+/// [Fixed](crate::Fixed) numbers, [ProjectileState::step] gives bit-identical
+/// results on every platform.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectileState<F = f64> {
+    /// position on a plane
+    pub position: Point<F>,
+    /// velocity of the projectile
+    pub velocity: Vector<F>,
+    /// acceleration of projectile
+    pub acceleration: Vector<F>,
+}
+
+/// ProjectileMut is a projectile that borrows its position, velocity and
+/// acceleration, and advances them by a fixed delta time on every update.
+pub struct ProjectileMut<'a> {
     /// position on a plane
     pos: &'a mut Point,
     /// velocity of the projectile
@@ -37,25 +116,32 @@ pub struct Projectile<'a> {
 
     /// delta time (usually engines provides this)
     delta_time: &'a f64,
+
+    /// numerical method used to advance the projectile
+    integrator: Integrator,
 }
 
 /// Point represents a point containing the x, y, z coordinates of the point on
-/// a plane.
-#[derive(Default)]
-pub struct Point {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+/// a plane. Coordinates are `f64` unless another [Float] type is given.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point<F = f64> {
+    pub x: F,
+    pub y: F,
+    pub z: F,
 }
 
 /// Vector represents a vector carrying a magnitude and a direction. We
 /// represent the vector as a point from the origin (0, 0) where the magnitude
 /// is the euclidean distance from the origin and the direction is the direction
-/// to the point from the origin.
-pub struct Vector {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+/// to the point from the origin. Components are `f64` unless another [Float]
+/// type is given.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector<F = f64> {
+    pub x: F,
+    pub y: F,
+    pub z: F,
 }
 
 /// GRAVITY is a utility vector that represents gravity in 2D and 3D contexts,
@@ -81,7 +167,778 @@ pub const TERMINAL_GRAVITY: Vector = Vector {
     z: 0.0,
 };
 
-impl Projectile<'_> {
+impl<F: Float> Point<F> {
+    /// to returns the vector pointing from this point to `other`.
+    pub fn to(self, other: Point<F>) -> Vector<F> {
+        Vector {
+            x: other.x - self.x,
+            y: other.y - self.y,
+            z: other.z - self.z,
+        }
+    }
+}
+
+impl<F: Float> Vector<F> {
+    /// length returns the magnitude of the vector.
+    pub fn length(self) -> F {
+        self.dot(self).sqrt()
+    }
+
+    /// dot returns the dot product of two vectors.
+    pub fn dot(self, other: Vector<F>) -> F {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// cross returns the cross product of two vectors.
+    pub fn cross(self, other: Vector<F>) -> Vector<F> {
+        Vector {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// normalized returns a vector with the same direction and a length of
+    /// one, or the zero vector if the vector has no length.
+    pub fn normalized(self) -> Vector<F> {
+        let length = self.length();
+        if length == F::ZERO {
+            return Vector::default();
+        }
+        let inv_length = F::ONE / length;
+        Vector {
+            x: self.x * inv_length,
+            y: self.y * inv_length,
+            z: self.z * inv_length,
+        }
+    }
+}
+
+impl<F: Float> ProjectileState<F> {
+    /// step advances position and velocity by the given delta time with the
+    /// given integrator.
+    pub fn step(&mut self, delta_time: DeltaTime<F>, integrator: Integrator) {
+        let acceleration = self.acceleration;
+        self.step_with(delta_time, integrator, |_, _| acceleration);
+    }
+
+    /// step_with advances position and velocity by the given delta time, using
+    /// `acceleration` to compute the acceleration for a given position and
+    /// velocity.
+    pub(crate) fn step_with(
+        &mut self,
+        delta_time: DeltaTime<F>,
+        integrator: Integrator,
+        acceleration: impl Fn(Point<F>, Vector<F>) -> Vector<F>,
+    ) {
+        let dt = delta_time.0;
+        let half = F::from_f64(0.5);
+        let two = F::from_f64(2.0);
+        let pos = self.position;
+        let vel = self.velocity;
+
+        match integrator {
+            Integrator::ExplicitEuler => {
+                let acc = acceleration(pos, vel);
+                self.position = translate(pos, vel, dt);
+                self.velocity = vel.add(acc.scale(dt));
+            }
+            Integrator::SemiImplicitEuler => {
+                let acc = acceleration(pos, vel);
+                self.velocity = vel.add(acc.scale(dt));
+                self.position = translate(pos, self.velocity, dt);
+            }
+            Integrator::VelocityVerlet => {
+                let acc = acceleration(pos, vel);
+                self.position = translate(translate(pos, vel, dt), acc, half * dt * dt);
+
+                // velocity at the end of the step is estimated with the old
+                // acceleration to evaluate velocity dependent forces.
+                let predicted_vel = vel.add(acc.scale(dt));
+                let new_acc = acceleration(self.position, predicted_vel);
+                self.velocity = vel.add(acc.add(new_acc).scale(half * dt));
+            }
+            Integrator::RungeKutta4 => {
+                let k1_vel = vel;
+                let k1_acc = acceleration(pos, vel);
+
+                let k2_vel = vel.add(k1_acc.scale(half * dt));
+                let k2_acc = acceleration(translate(pos, k1_vel, half * dt), k2_vel);
+
+                let k3_vel = vel.add(k2_acc.scale(half * dt));
+                let k3_acc = acceleration(translate(pos, k2_vel, half * dt), k3_vel);
+
+                let k4_vel = vel.add(k3_acc.scale(dt));
+                let k4_acc = acceleration(translate(pos, k3_vel, dt), k4_vel);
+
+                let avg_vel = k1_vel
+                    .add(k2_vel.scale(two))
+                    .add(k3_vel.scale(two))
+                    .add(k4_vel);
+                let avg_acc = k1_acc
+                    .add(k2_acc.scale(two))
+                    .add(k3_acc.scale(two))
+                    .add(k4_acc);
+
+                let sixth_dt = dt / F::from_f64(6.0);
+                self.position = translate(pos, avg_vel, sixth_dt);
+                self.velocity = vel.add(avg_acc.scale(sixth_dt));
+            }
+            Integrator::Analytic => {
+                let acc = acceleration(pos, vel);
+                self.position = translate(translate(pos, vel, dt), acc, half * dt * dt);
+                self.velocity = vel.add(acc.scale(dt));
+            }
+        }
+    }
+}
+
+impl ProjectileState {
+    /// position_at returns the position after `time` seconds, assuming the
+    /// acceleration stays constant. Drag, wind and force fields are ignored;
+    /// use [Projectile::predict] to include them.
+    pub fn position_at(&self, time: f64) -> Point {
+        translate(
+            translate(self.position, self.velocity, time),
+            self.acceleration,
+            0.5 * time * time,
+        )
+    }
+
+    /// velocity_at returns the velocity after `time` seconds, assuming the
+    /// acceleration stays constant.
+    pub fn velocity_at(&self, time: f64) -> Vector {
+        self.velocity.add(self.acceleration.scale(time))
+    }
+
+    /// sample returns `samples` evenly spaced positions along the predicted
+    /// path over `duration` seconds, starting at the current position, for
+    /// drawing the trajectory as a polyline.
+    #[cfg(feature = "alloc")]
+    pub fn sample(&self, duration: f64, samples: usize) -> Vec<Point> {
+        match samples {
+            0 => Vec::new(),
+            1 => alloc::vec![self.position],
+            _ => (0..samples)
+                .map(|i| self.position_at(duration * i as f64 / (samples - 1) as f64))
+                .collect(),
+        }
+    }
+
+    /// apex returns the time and position of the highest point of the path,
+    /// measured against the direction of the acceleration. It returns `None`
+    /// if there is no acceleration or the apex has already been passed.
+    ///
+    /// ```
+    /// use natura::{ProjectileState, Point, Vector, GRAVITY};
+    /// let state = ProjectileState {
+    ///     position: Point::default(),
+    ///     velocity: Vector { x: 3.0, y: 9.81, z: 0.0 },
+    ///     acceleration: GRAVITY,
+    /// };
+    /// let (time, apex) = state.apex().unwrap();
+    /// assert!((time - 1.0).abs() < 1e-12);
+    /// assert!((apex.y - 4.905).abs() < 1e-12);
+    /// ```
+    pub fn apex(&self) -> Option<(f64, Point)> {
+        let acceleration_squared = self.acceleration.dot(self.acceleration);
+        if acceleration_squared == 0.0 {
+            return None;
+        }
+
+        let time = -self.velocity.dot(self.acceleration) / acceleration_squared;
+        if time < 0.0 {
+            return None;
+        }
+
+        Some((time, self.position_at(time)))
+    }
+
+    /// plane_intersection returns the time and position at which the path
+    /// first crosses the plane through `point` with the given `normal`, or
+    /// `None` if it never does. Crossings at the current position are
+    /// ignored, so a projectile launched from the ground reports where it
+    /// lands.
+    pub fn plane_intersection(&self, point: Point, normal: Vector) -> Option<(f64, Point)> {
+        // Signed distance along the normal: a·t² + b·t + c = 0
+        let a = 0.5 * self.acceleration.dot(normal);
+        let b = self.velocity.dot(normal);
+        let c = point.to(self.position).dot(normal);
+
+        let time = smallest_positive_root(a, b, c)?;
+        Some((time, self.position_at(time)))
+    }
+
+    /// ground_intersection returns the time and position at which the path
+    /// first crosses the horizontal plane `y = height`.
+    pub fn ground_intersection(&self, height: f64) -> Option<(f64, Point)> {
+        self.plane_intersection(
+            Point {
+                x: 0.0,
+                y: height,
+                z: 0.0,
+            },
+            Vector {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+        )
+    }
+}
+
+/// smallest_positive_root returns the smallest root of `a·t² + b·t + c`
+/// greater than zero.
+fn smallest_positive_root(a: f64, b: f64, c: f64) -> Option<f64> {
+    if a.abs() < EPSILON {
+        if b == 0.0 {
+            return None;
+        }
+        let t = -c / b;
+        return (t > 0.0).then_some(t);
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    // Avoids cancellation when b is large compared to a·c.
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    let (r1, r2) = if q == 0.0 { (0.0, 0.0) } else { (q / a, c / q) };
+    let (low, high) = (f64::min(r1, r2), f64::max(r1, r2));
+
+    if low > 0.0 {
+        Some(low)
+    } else if high > 0.0 {
+        Some(high)
+    } else {
+        None
+    }
+}
+
+/// LaunchArc is one way to hit a target with a given launch speed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LaunchArc {
+    /// elevation above the horizontal in radians
+    pub angle: f64,
+    /// launch velocity
+    pub velocity: Vector,
+    /// time in seconds until the target is reached
+    pub flight_time: f64,
+}
+
+/// LaunchArcs holds both launch arcs that reach a target. When the target is
+/// at the edge of the range both arcs are the same.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LaunchArcs {
+    /// flatter and faster arc
+    pub low: LaunchArc,
+    /// steeper, lobbed arc
+    pub high: LaunchArc,
+}
+
+/// solve_launch returns the launch velocities that carry a projectile from
+/// `origin` to `target` at the given launch `speed` under a constant
+/// `gravity`, or `None` if the target is out of range, `speed` is not
+/// positive or any input is not finite. "Up" is the opposite of gravity, so
+/// any orientation of the world works.
+///
+/// # Example:
+///
+/// ```
+/// use natura::{solve_launch, Point, Projectile, GRAVITY};
+/// let origin = Point::default();
+/// let target = Point { x: 20.0, y: 2.0, z: 5.0 };
+///
+/// let arcs = solve_launch(origin, target, 20.0, GRAVITY).unwrap();
+/// assert!(arcs.low.angle < arcs.high.angle);
+///
+/// let projectile = Projectile::launch(origin, arcs.low.velocity, GRAVITY);
+/// let hit = projectile.state().position_at(arcs.low.flight_time);
+/// assert!((hit.x - target.x).abs() < 1e-9 && (hit.y - target.y).abs() < 1e-9);
+/// ```
+pub fn solve_launch(
+    origin: Point,
+    target: Point,
+    speed: f64,
+    gravity: Vector,
+) -> Option<LaunchArcs> {
+    let inputs = [
+        origin.x, origin.y, origin.z, target.x, target.y, target.z, gravity.x, gravity.y,
+        gravity.z, speed,
+    ];
+    if speed <= 0.0 || !inputs.iter().all(|input| input.is_finite()) {
+        return None;
+    }
+
+    let g = gravity.length();
+    let up = gravity.normalized().scale(-1.0);
+    let offset = origin.to(target);
+
+    let rise = offset.dot(up);
+    let horizontal = offset.sub(up.scale(rise));
+    let distance = horizontal.length();
+
+    if g == 0.0 || distance < EPSILON {
+        // Without gravity or for a target straight above or below, aim
+        // directly at it.
+        return straight_launch(offset, speed, g, rise);
+    }
+
+    let speed_squared = speed * speed;
+    let discriminant =
+        speed_squared * speed_squared - g * (g * distance * distance + 2.0 * rise * speed_squared);
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let forward = horizontal.scale(1.0 / distance);
+    let arc = |angle: f64| LaunchArc {
+        angle,
+        velocity: forward
+            .scale(speed * angle.cos())
+            .add(up.scale(speed * angle.sin())),
+        flight_time: distance / (speed * angle.cos()),
+    };
+
+    let root = discriminant.sqrt();
+    Some(LaunchArcs {
+        low: arc(((speed_squared - root) / (g * distance)).atan()),
+        high: arc(((speed_squared + root) / (g * distance)).atan()),
+    })
+}
+
+/// straight_launch aims directly at a target that is straight above or
+/// below, or anywhere when there is no gravity.
+fn straight_launch(offset: Vector, speed: f64, g: f64, rise: f64) -> Option<LaunchArcs> {
+    let length = offset.length();
+    if !(speed > 0.0 && speed.is_finite()) || length == 0.0 {
+        return None;
+    }
+
+    // Distance travelled towards the target: s = v·t - g·sin(θ)/2·t²
+    let elevation = rise / length;
+    let flight_time = smallest_positive_root(-0.5 * g * elevation, speed, -length)?;
+
+    let arc = LaunchArc {
+        angle: elevation.asin(),
+        velocity: offset.scale(speed / length),
+        flight_time,
+    };
+    Some(LaunchArcs {
+        low: arc,
+        high: arc,
+    })
+}
+
+/// translate returns `point` moved along `vector` scaled by `scale`.
+#[inline(always)]
+pub(crate) fn translate<F: Float>(point: Point<F>, vector: Vector<F>, scale: F) -> Point<F> {
+    Point {
+        x: point.x + vector.x * scale,
+        y: point.y + vector.y * scale,
+        z: point.z + vector.z * scale,
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float> Projectile<F> {
+    /// launch creates a new projectile from initial values for position,
+    /// velocity, and acceleration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use natura::{Projectile, Point, Vector, GRAVITY};
+    /// let projectile = Projectile::launch(
+    ///     Point { x: 0.0, y: 0.0, z: 0.0 },
+    ///     Vector { x: 5.0, y: 5.0, z: 0.0 },
+    ///     GRAVITY);
+    /// ```
+    pub fn launch(position: Point<F>, velocity: Vector<F>, acceleration: Vector<F>) -> Self {
+        Projectile::from_state(ProjectileState {
+            position,
+            velocity,
+            acceleration,
+        })
+    }
+
+    /// from_state creates a new projectile from a state snapshot.
+    pub fn from_state(state: ProjectileState<F>) -> Self {
+        Projectile {
+            state,
+            integrator: Integrator::default(),
+            mass: F::ONE,
+            linear_drag: F::ZERO,
+            quadratic_drag: F::ZERO,
+            wind: Vector::default(),
+            force_fields: Vec::new(),
+            radius: F::ZERO,
+            colliders: Vec::new(),
+            contacts: Vec::new(),
+            elapsed: F::ZERO,
+        }
+    }
+
+    /// with_mass returns the projectile with the given mass. Heavier
+    /// projectiles are less affected by drag, wind and force fields; the
+    /// constant acceleration is not scaled. The default mass is `1.0`.
+    pub fn with_mass(mut self, mass: F) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// with_drag returns the projectile slowed down by air resistance. The
+    /// drag force is `-(linear + quadratic·|v|)·v`, where `v` is the velocity
+    /// relative to the wind. Linear drag dominates for small, slow objects
+    /// like sparks and leaves, quadratic drag for fast ones like arrows.
+    ///
+    /// ```
+    /// use natura::{Projectile, Point, Vector, GRAVITY};
+    /// let arrow = Projectile::launch(Point::default(), Vector { x: 60.0, y: 10.0, z: 0.0 }, GRAVITY)
+    ///     .with_mass(0.02)
+    ///     .with_drag(0.0, 0.0005);
+    /// ```
+    pub fn with_drag(mut self, linear: F, quadratic: F) -> Self {
+        self.linear_drag = linear;
+        self.quadratic_drag = quadratic;
+        self
+    }
+
+    /// with_wind returns the projectile moving through air that flows with
+    /// the given velocity. Wind only has an effect together with drag.
+    pub fn with_wind(mut self, wind: Vector<F>) -> Self {
+        self.wind = wind;
+        self
+    }
+
+    /// with_force_field returns the projectile with an additional force field
+    /// evaluated on every step.
+    pub fn with_force_field(mut self, field: impl ForceField<F> + 'static) -> Self {
+        self.force_fields.push(Arc::new(field));
+        self
+    }
+
+    /// mass returns the mass of the projectile.
+    pub fn mass(&self) -> F {
+        self.mass
+    }
+
+    /// set_mass changes the mass of the projectile.
+    pub fn set_mass(&mut self, mass: F) {
+        self.mass = mass;
+    }
+
+    /// drag returns the linear and quadratic drag coefficients.
+    pub fn drag(&self) -> (F, F) {
+        (self.linear_drag, self.quadratic_drag)
+    }
+
+    /// set_drag changes the linear and quadratic drag coefficients.
+    pub fn set_drag(&mut self, linear: F, quadratic: F) {
+        self.linear_drag = linear;
+        self.quadratic_drag = quadratic;
+    }
+
+    /// wind returns the velocity of the air around the projectile.
+    pub fn wind(&self) -> Vector<F> {
+        self.wind
+    }
+
+    /// set_wind changes the velocity of the air around the projectile.
+    pub fn set_wind(&mut self, wind: Vector<F>) {
+        self.wind = wind;
+    }
+
+    /// force_fields returns the force fields acting on the projectile.
+    pub fn force_fields(&self) -> &[Arc<dyn ForceField<F>>] {
+        &self.force_fields
+    }
+
+    /// add_force_field adds a force field evaluated on every step.
+    pub fn add_force_field(&mut self, field: Arc<dyn ForceField<F>>) {
+        self.force_fields.push(field);
+    }
+
+    /// clear_force_fields removes all force fields.
+    pub fn clear_force_fields(&mut self) {
+        self.force_fields.clear();
+    }
+
+    /// with_radius returns the projectile colliding as a sphere with the
+    /// given radius instead of as a point.
+    pub fn with_radius(mut self, radius: F) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// with_collider returns the projectile bouncing off an additional
+    /// collider.
+    pub fn with_collider(mut self, collider: Collider<F>) -> Self {
+        self.colliders.push(collider);
+        self
+    }
+
+    /// radius returns the collision radius of the projectile.
+    pub fn radius(&self) -> F {
+        self.radius
+    }
+
+    /// set_radius changes the collision radius of the projectile.
+    pub fn set_radius(&mut self, radius: F) {
+        self.radius = radius;
+    }
+
+    /// colliders returns the colliders the projectile bounces off.
+    pub fn colliders(&self) -> &[Collider<F>] {
+        &self.colliders
+    }
+
+    /// colliders_mut returns the colliders the projectile bounces off for
+    /// modification.
+    pub fn colliders_mut(&mut self) -> &mut Vec<Collider<F>> {
+        &mut self.colliders
+    }
+
+    /// contacts returns the collisions that happened during the last update,
+    /// in the order they happened.
+    pub fn contacts(&self) -> &[Contact<F>] {
+        &self.contacts
+    }
+
+    /// elapsed returns the time in seconds the projectile has been updated
+    /// for. Force fields receive it to vary over time.
+    pub fn elapsed(&self) -> F {
+        self.elapsed
+    }
+
+    /// predict returns the positions the projectile will pass through over
+    /// the next `steps` updates of `delta_time`, starting with the current
+    /// position. Unlike [ProjectileState::position_at], the prediction uses the
+    /// integrator, drag, wind and force fields of the projectile, so it
+    /// matches what [Projectile::update] will produce.
+    pub fn predict(&self, delta_time: DeltaTime<F>, steps: usize) -> Vec<Point<F>> {
+        let mut ghost = self.clone();
+        let mut points = Vec::with_capacity(steps + 1);
+        points.push(ghost.position());
+        for _ in 0..steps {
+            points.push(ghost.update(delta_time));
+        }
+        points
+    }
+
+    /// acceleration_at returns the total acceleration at the given position
+    /// and velocity: `base` plus drag, wind and force fields.
+    fn acceleration_at(
+        &self,
+        base: Vector<F>,
+        position: Point<F>,
+        velocity: Vector<F>,
+        time: F,
+    ) -> Vector<F> {
+        let relative = velocity.sub(self.wind);
+        let drag = self.linear_drag + self.quadratic_drag * relative.length();
+        let mut force = relative.scale(-drag);
+
+        for field in &self.force_fields {
+            force = force.add(field.force(position, velocity, time));
+        }
+
+        base.add(force.scale(F::ONE / self.mass))
+    }
+
+    /// has_forces reports whether anything besides the constant acceleration
+    /// acts on the projectile.
+    fn has_forces(&self) -> bool {
+        self.linear_drag != F::ZERO
+            || self.quadratic_drag != F::ZERO
+            || !self.force_fields.is_empty()
+    }
+
+    /// with_integrator returns the projectile using the given integrator.
+    ///
+    /// ```
+    /// use natura::{Projectile, Integrator, Point, Vector, GRAVITY};
+    /// let projectile = Projectile::launch(Point::default(), Vector { x: 5.0, y: 5.0, z: 0.0 }, GRAVITY)
+    ///     .with_integrator(Integrator::VelocityVerlet);
+    /// ```
+    pub fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
+    /// integrator returns the integrator used to advance the projectile.
+    pub fn integrator(&self) -> Integrator {
+        self.integrator
+    }
+
+    /// set_integrator changes the integrator used to advance the projectile.
+    pub fn set_integrator(&mut self, integrator: Integrator) {
+        self.integrator = integrator;
+    }
+
+    /// update advances the projectile by the given delta time and returns its
+    /// new position.
+    pub fn update(&mut self, delta_time: DeltaTime<F>) -> Point<F> {
+        let mut state = self.state;
+        let mut contacts = core::mem::take(&mut self.contacts);
+        contacts.clear();
+        self.advance(&mut state, delta_time, self.elapsed, &mut contacts);
+        self.state = state;
+        self.contacts = contacts;
+
+        self.elapsed = self.elapsed + delta_time.0;
+        self.state.position
+    }
+
+    /// advance steps `state` by the given delta time as if it belonged to this
+    /// projectile, applying its integrator, mass, drag, wind, force fields and
+    /// colliders. `time` is passed to the force fields, and collisions are
+    /// appended to `contacts`.
+    pub(crate) fn advance(
+        &self,
+        state: &mut ProjectileState<F>,
+        delta_time: DeltaTime<F>,
+        time: F,
+        contacts: &mut Vec<Contact<F>>,
+    ) {
+        let start = state.position;
+        if self.has_forces() {
+            // Forces are evaluated at the start of the step; sub-steps of
+            // higher order integrators see the same time.
+            let base = state.acceleration;
+            state.step_with(delta_time, self.integrator, |pos, vel| {
+                self.acceleration_at(base, pos, vel, time)
+            });
+        } else {
+            state.step(delta_time, self.integrator);
+        }
+
+        if !self.colliders.is_empty() {
+            resolve_collisions(
+                &self.colliders,
+                self.radius,
+                start,
+                state,
+                delta_time,
+                time,
+                contacts,
+            );
+        }
+    }
+
+    /// state returns a snapshot of the position, velocity and acceleration.
+    pub fn state(&self) -> ProjectileState<F> {
+        self.state
+    }
+
+    /// set_state replaces the position, velocity and acceleration.
+    pub fn set_state(&mut self, state: ProjectileState<F>) {
+        self.state = state;
+    }
+
+    /// position returns the position of the projectile.
+    pub fn position(&self) -> Point<F> {
+        self.state.position
+    }
+
+    /// set_position moves the projectile to the given position.
+    pub fn set_position(&mut self, position: Point<F>) {
+        self.state.position = position;
+    }
+
+    /// velocity returns the velocity of the projectile.
+    pub fn velocity(&self) -> Vector<F> {
+        self.state.velocity
+    }
+
+    /// set_velocity changes the velocity of the projectile.
+    pub fn set_velocity(&mut self, velocity: Vector<F>) {
+        self.state.velocity = velocity;
+    }
+
+    /// acceleration returns the acceleration of the projectile.
+    pub fn acceleration(&self) -> Vector<F> {
+        self.state.acceleration
+    }
+
+    /// set_acceleration changes the acceleration of the projectile.
+    pub fn set_acceleration(&mut self, acceleration: Vector<F>) {
+        self.state.acceleration = acceleration;
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Projectile {
+    /// new creates a [ProjectileMut] that advances the given position,
+    /// velocity and acceleration in place, by `delta_time` on every update.
+    /// It keeps code written for the borrowing projectile working; use
+    /// [Projectile::launch] for a projectile that owns its state.
+    ///
+    /// ```
+    /// use natura::{Projectile, Point, Vector};
+    /// let time = natura::fps(60);
+    /// let mut position = Point::default();
+    /// let mut velocity = Vector { x: 5.0, y: 5.0, z: 0.0 };
+    /// let mut acceleration = natura::GRAVITY;
+    /// let mut projectile = Projectile::new(&time, &mut position, &mut velocity, &mut acceleration);
+    /// let pos: &Point = projectile.update();
+    /// ```
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<'a>(
+        delta_time: &'a f64,
+        initial_position: &'a mut Point,
+        initial_velocity: &'a mut Vector,
+        initial_acceleration: &'a mut Vector,
+    ) -> ProjectileMut<'a> {
+        ProjectileMut::new(
+            delta_time,
+            initial_position,
+            initial_velocity,
+            initial_acceleration,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float> Default for Projectile<F> {
+    fn default() -> Self {
+        Projectile::from_state(ProjectileState::default())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float> PartialEq for Projectile<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+            && self.integrator == other.integrator
+            && self.mass == other.mass
+            && self.linear_drag == other.linear_drag
+            && self.quadratic_drag == other.quadratic_drag
+            && self.wind == other.wind
+            && self.radius == other.radius
+            && self.colliders == other.colliders
+            && self.elapsed == other.elapsed
+            && self.force_fields.len() == other.force_fields.len()
+            && self
+                .force_fields
+                .iter()
+                .zip(&other.force_fields)
+                .all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float> From<ProjectileState<F>> for Projectile<F> {
+    fn from(state: ProjectileState<F>) -> Self {
+        Projectile::from_state(state)
+    }
+}
+
+impl ProjectileMut<'_> {
     /// new creates a new projectile. It accepts a frame rate and initial
     /// values for [position, velocity, and acceleration. It returns a new projectile.
     ///
@@ -95,14 +952,14 @@ impl Projectile<'_> {
     /// # Examples
     ///
     /// ```
-    /// use natura::{Projectile, Point, Vector};
-    /// use std::borrow::{BorrowMut};
+    /// use natura::{ProjectileMut, Point, Vector};
+    /// use core::borrow::{BorrowMut};
     /// let fps = 60;
-    /// let time = &crate::fps(fps);
+    /// let time = &natura::fps(fps);
     /// let mut initial_acceleration = Vector { x: 0.0, y: 9.81, z: 0.0 };
     /// let mut initial_position = Point { x: 0.0, y: 0.0, z: 0.0 };
     /// let mut initial_velocity = Vector { x: 5.0, y: 5.0, z: 0.0 };
-    /// let mut projectile = Projectile::new(
+    /// let mut projectile = ProjectileMut::new(
     ///     time,
     ///     initial_position.borrow_mut(),
     ///     initial_velocity.borrow_mut(),
@@ -113,25 +970,34 @@ impl Projectile<'_> {
         initial_position: &'a mut Point,
         initial_velocity: &'a mut Vector,
         initial_acceleration: &'a mut Vector,
-    ) -> Projectile<'a> {
-        Projectile {
+    ) -> ProjectileMut<'a> {
+        ProjectileMut {
             pos: initial_position,
             vel: initial_velocity,
             acc: initial_acceleration,
             delta_time,
+            integrator: Integrator::default(),
         }
     }
 
+    /// with_integrator returns the projectile using the given integrator.
+    pub fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
     /// update updates the position and velocity values for the given projectile.
-    /// call this after calling [Projectile::new] to update values.
+    /// call this after calling [ProjectileMut::new] to update values.
     pub fn update(&mut self) -> &Point {
-        self.pos.x += self.vel.x * self.delta_time;
-        self.pos.y += self.vel.y * self.delta_time;
-        self.pos.z += self.vel.z * self.delta_time;
+        let mut state = ProjectileState {
+            position: *self.pos,
+            velocity: *self.vel,
+            acceleration: *self.acc,
+        };
+        state.step(DeltaTime(*self.delta_time), self.integrator);
 
-        self.vel.x += self.acc.x * self.delta_time;
-        self.vel.y += self.acc.y * self.delta_time;
-        self.vel.z += self.acc.z * self.delta_time;
+        *self.pos = state.position;
+        *self.vel = state.velocity;
 
         self.pos
     }
@@ -144,7 +1010,10 @@ impl Projectile<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Projectile, Vector};
+    use crate::{
+        solve_launch, DeltaTime, Integrator, Point, PointAttractor, Projectile, ProjectileMut,
+        ProjectileState, Vector, Vortex,
+    };
     use std::borrow::BorrowMut;
 
     #[test]
@@ -215,14 +1084,462 @@ mod tests {
             let (_, c): (usize, &Point) = item;
             let mut pos = &Point::default();
 
-            for _ in 1..fps {
+            for _ in 0..fps {
                 pos = projectile.update();
             }
 
             let x1 = relative_eq!(pos.x, c.x, epsilon = 1e-2);
             let y1 = relative_eq!(pos.y, c.y, epsilon = 1e-2);
-            assert_eq!(x1, true);
-            assert_eq!(y1, true);
+            assert!(x1);
+            assert!(y1);
+        }
+    }
+
+    #[test]
+    fn test_owned_matches_borrowed() {
+        let delta_time = crate::fps(60);
+        let mut position = Point::default();
+        let mut velocity = Vector {
+            x: 5.0,
+            y: 5.0,
+            z: 1.0,
+        };
+        let mut acceleration = crate::GRAVITY;
+
+        let mut owned = Projectile::launch(position, velocity, acceleration);
+        let mut borrowed =
+            ProjectileMut::new(&delta_time, &mut position, &mut velocity, &mut acceleration);
+
+        for _ in 0..120 {
+            let expected = *borrowed.update();
+            assert_eq!(owned.update(DeltaTime(delta_time)), expected);
+        }
+    }
+
+    #[test]
+    fn test_state_setters() {
+        let mut projectile = Projectile::default();
+        projectile.set_velocity(Vector {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        });
+        projectile.set_acceleration(crate::GRAVITY);
+        projectile.update(DeltaTime(1.0));
+
+        let state = projectile.state();
+        assert_eq!(state.position.x, 1.0);
+        assert_eq!(state.velocity.y, -9.81);
+
+        projectile.set_position(Point {
+            x: 10.0,
+            y: 0.0,
+            z: 0.0,
+        });
+        assert_eq!(projectile.position().x, 10.0);
+
+        let copy = Projectile::from(state);
+        assert_eq!(copy.state(), state);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let projectile = Projectile::launch(
+            Point {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            Vector {
+                x: 4.0,
+                y: 5.0,
+                z: 6.0,
+            },
+            crate::GRAVITY,
+        );
+
+        let json = serde_json::to_string(&projectile).unwrap();
+        let back: Projectile = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, projectile);
+    }
+
+    #[test]
+    fn test_integrators_follow_parabola() {
+        let fps = 60;
+        let delta_time = DeltaTime(crate::fps(fps));
+        let initial_velocity = Vector {
+            x: 5.0,
+            y: 5.0,
+            z: 0.0,
+        };
+        let exact = |t: f64| Point {
+            x: 5.0 * t,
+            y: 5.0 * t - 0.5 * 9.81 * t * t,
+            z: 0.0,
+        };
+
+        for integrator in [
+            Integrator::VelocityVerlet,
+            Integrator::RungeKutta4,
+            Integrator::Analytic,
+        ] {
+            let mut projectile =
+                Projectile::launch(Point::default(), initial_velocity, crate::GRAVITY)
+                    .with_integrator(integrator);
+
+            let mut pos = Point::default();
+            for _ in 0..3 * fps {
+                pos = projectile.update(delta_time);
+            }
+
+            let expected = exact(3.0 * fps as f64 * delta_time.0);
+            assert!(relative_eq!(pos.x, expected.x, epsilon = 1e-9));
+            assert!(relative_eq!(pos.y, expected.y, epsilon = 1e-9));
         }
     }
+
+    #[test]
+    fn test_euler_variants_bracket_parabola() {
+        let delta_time = DeltaTime(crate::fps(60));
+        let initial_velocity = Vector {
+            x: 0.0,
+            y: 20.0,
+            z: 0.0,
+        };
+
+        let mut explicit = Projectile::launch(Point::default(), initial_velocity, crate::GRAVITY);
+        let mut semi_implicit = explicit
+            .clone()
+            .with_integrator(Integrator::SemiImplicitEuler);
+        let mut exact = explicit.clone().with_integrator(Integrator::Analytic);
+
+        for _ in 0..60 {
+            explicit.update(delta_time);
+            semi_implicit.update(delta_time);
+            exact.update(delta_time);
+        }
+
+        // Under gravity explicit Euler overshoots and semi-implicit Euler
+        // undershoots the true height by the same amount.
+        let exact_y = exact.position().y;
+        assert!(explicit.position().y > exact_y);
+        assert!(semi_implicit.position().y < exact_y);
+        assert!(relative_eq!(
+            explicit.position().y - exact_y,
+            exact_y - semi_implicit.position().y,
+            epsilon = 1e-9
+        ));
+    }
+
+    #[test]
+    fn test_linear_drag_terminal_velocity() {
+        let mut projectile =
+            Projectile::launch(Point::default(), Vector::default(), crate::GRAVITY)
+                .with_integrator(Integrator::RungeKutta4)
+                .with_mass(2.0)
+                .with_drag(4.0, 0.0);
+
+        for _ in 0..600 {
+            projectile.update(DeltaTime(crate::fps(60)));
+        }
+
+        // m·g = k·v
+        assert!(relative_eq!(
+            projectile.velocity().y,
+            -2.0 * 9.81 / 4.0,
+            epsilon = 1e-6
+        ));
+    }
+
+    #[test]
+    fn test_quadratic_drag_terminal_velocity() {
+        let mut projectile =
+            Projectile::launch(Point::default(), Vector::default(), crate::GRAVITY)
+                .with_integrator(Integrator::RungeKutta4)
+                .with_mass(0.5)
+                .with_drag(0.0, 0.2);
+
+        for _ in 0..1200 {
+            projectile.update(DeltaTime(crate::fps(60)));
+        }
+
+        // m·g = c·v²
+        assert!(relative_eq!(
+            projectile.velocity().y,
+            -(0.5 * 9.81 / 0.2_f64).sqrt(),
+            epsilon = 1e-6
+        ));
+    }
+
+    #[test]
+    fn test_wind_carries_projectile() {
+        let wind = Vector {
+            x: 3.0,
+            y: 0.0,
+            z: -1.0,
+        };
+        let mut projectile =
+            Projectile::launch(Point::default(), Vector::default(), Vector::default())
+                .with_drag(2.0, 0.0)
+                .with_wind(wind);
+
+        for _ in 0..600 {
+            projectile.update(DeltaTime(crate::fps(60)));
+        }
+
+        assert!(relative_eq!(
+            projectile.velocity().x,
+            wind.x,
+            epsilon = 1e-6
+        ));
+        assert!(relative_eq!(
+            projectile.velocity().z,
+            wind.z,
+            epsilon = 1e-6
+        ));
+        assert!(relative_eq!(
+            projectile.elapsed(),
+            600.0 * crate::fps(60),
+            epsilon = 1e-9
+        ));
+    }
+
+    #[test]
+    fn test_force_fields_act_on_projectile() {
+        let start = Point {
+            x: 5.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let mut attracted = Projectile::launch(start, Vector::default(), Vector::default())
+            .with_force_field(PointAttractor {
+                center: Point::default(),
+                strength: 10.0,
+                min_distance: 0.1,
+            });
+        let mut swirled = Projectile::launch(start, Vector::default(), Vector::default())
+            .with_force_field(Vortex {
+                center: Point::default(),
+                axis: Vector {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 1.0,
+                },
+                strength: 10.0,
+                min_distance: 0.1,
+            });
+
+        for _ in 0..10 {
+            attracted.update(DeltaTime(crate::fps(60)));
+            swirled.update(DeltaTime(crate::fps(60)));
+        }
+
+        assert!(attracted.position().x < start.x);
+        assert!(relative_eq!(attracted.position().y, 0.0));
+        assert!(swirled.velocity().y > 0.0);
+
+        // Clones share their force fields.
+        assert_eq!(attracted.clone(), attracted);
+        assert_ne!(
+            attracted,
+            Projectile::launch(start, Vector::default(), Vector::default())
+        );
+    }
+
+    #[test]
+    fn test_apex_and_landing() {
+        let state = ProjectileState {
+            position: Point::default(),
+            velocity: Vector {
+                x: 4.0,
+                y: 19.62,
+                z: 0.0,
+            },
+            acceleration: crate::GRAVITY,
+        };
+
+        let (apex_time, apex) = state.apex().unwrap();
+        assert!(relative_eq!(apex_time, 2.0, epsilon = 1e-12));
+        assert!(relative_eq!(apex.x, 8.0, epsilon = 1e-12));
+        assert!(relative_eq!(apex.y, 19.62, epsilon = 1e-12));
+
+        // Launched from the ground, it lands after twice the apex time.
+        let (land_time, land) = state.ground_intersection(0.0).unwrap();
+        assert!(relative_eq!(land_time, 4.0, epsilon = 1e-12));
+        assert!(relative_eq!(land.x, 16.0, epsilon = 1e-12));
+
+        // Once past the apex there is none left, and a plane above the apex
+        // is never reached.
+        let falling = ProjectileState {
+            velocity: state.velocity_at(3.0),
+            ..state
+        };
+        assert_eq!(falling.apex(), None);
+        assert_eq!(state.ground_intersection(30.0), None);
+
+        let path = state.sample(4.0, 5);
+        assert_eq!(path.len(), 5);
+        assert_eq!(path[0], state.position);
+        assert!(relative_eq!(path[2].y, apex.y, epsilon = 1e-12));
+        assert!(relative_eq!(path[4].y, 0.0, epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_solve_launch_hits_target() {
+        let origin = Point {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let target = Point {
+            x: 30.0,
+            y: 7.0,
+            z: -10.0,
+        };
+
+        let arcs = solve_launch(origin, target, 25.0, crate::GRAVITY).unwrap();
+        assert!(arcs.low.angle < arcs.high.angle);
+        assert!(arcs.low.flight_time < arcs.high.flight_time);
+
+        for arc in [arcs.low, arcs.high] {
+            assert!(relative_eq!(arc.velocity.length(), 25.0, epsilon = 1e-9));
+
+            let state = ProjectileState {
+                position: origin,
+                velocity: arc.velocity,
+                acceleration: crate::GRAVITY,
+            };
+            let hit = state.position_at(arc.flight_time);
+            assert!(relative_eq!(hit.x, target.x, epsilon = 1e-9));
+            assert!(relative_eq!(hit.y, target.y, epsilon = 1e-9));
+            assert!(relative_eq!(hit.z, target.z, epsilon = 1e-9));
+        }
+
+        // At the edge of the range on flat ground both arcs are 45°.
+        let range = 25.0 * 25.0 / 9.81;
+        let edge = Point {
+            x: range,
+            y: 0.0,
+            z: 0.0,
+        };
+        let arcs = solve_launch(Point::default(), edge, 25.0, crate::GRAVITY).unwrap();
+        let quarter = std::f64::consts::FRAC_PI_4;
+        assert!(relative_eq!(arcs.low.angle, quarter, epsilon = 1e-6));
+        assert!(relative_eq!(arcs.high.angle, quarter, epsilon = 1e-6));
+
+        let beyond = Point {
+            x: range + 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        assert_eq!(
+            solve_launch(Point::default(), beyond, 25.0, crate::GRAVITY),
+            None
+        );
+    }
+
+    #[test]
+    fn test_solve_launch_straight_up() {
+        let target = Point {
+            x: 0.0,
+            y: 5.0,
+            z: 0.0,
+        };
+
+        let arcs = solve_launch(Point::default(), target, 20.0, crate::GRAVITY).unwrap();
+        assert!(relative_eq!(arcs.low.angle, std::f64::consts::FRAC_PI_2));
+        assert!(relative_eq!(arcs.low.velocity.y, 20.0));
+
+        let state = ProjectileState {
+            position: Point::default(),
+            velocity: arcs.low.velocity,
+            acceleration: crate::GRAVITY,
+        };
+        assert!(relative_eq!(
+            state.position_at(arcs.low.flight_time).y,
+            5.0,
+            epsilon = 1e-9
+        ));
+
+        assert_eq!(
+            solve_launch(Point::default(), target, 5.0, crate::GRAVITY),
+            None
+        );
+    }
+
+    #[test]
+    fn test_solve_launch_rejects_invalid_speed() {
+        let target = Point {
+            x: 20.0,
+            y: 2.0,
+            z: 0.0,
+        };
+        let above = Point {
+            x: 0.0,
+            y: 5.0,
+            z: 0.0,
+        };
+
+        for speed in [-20.0, 0.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                solve_launch(Point::default(), target, speed, crate::GRAVITY),
+                None
+            );
+            assert_eq!(
+                solve_launch(Point::default(), above, speed, crate::GRAVITY),
+                None
+            );
+            assert_eq!(
+                solve_launch(Point::default(), target, speed, Vector::default()),
+                None
+            );
+        }
+
+        let nowhere = Point {
+            x: f64::NAN,
+            y: 0.0,
+            z: 0.0,
+        };
+        assert_eq!(
+            solve_launch(Point::default(), nowhere, 20.0, crate::GRAVITY),
+            None
+        );
+    }
+
+    #[test]
+    fn test_predict_matches_update() {
+        let delta_time = DeltaTime(crate::fps(60));
+        let mut projectile = Projectile::launch(
+            Point::default(),
+            Vector {
+                x: 10.0,
+                y: 10.0,
+                z: 0.0,
+            },
+            crate::GRAVITY,
+        )
+        .with_drag(0.1, 0.01)
+        .with_integrator(Integrator::SemiImplicitEuler);
+
+        let predicted = projectile.predict(delta_time, 90);
+        assert_eq!(predicted.len(), 91);
+        assert_eq!(predicted[0], projectile.position());
+
+        for expected in &predicted[1..] {
+            assert_eq!(projectile.update(delta_time), *expected);
+        }
+
+        // Drag makes the projectile fall short of the drag-free prediction.
+        let free = ProjectileState {
+            position: Point::default(),
+            velocity: Vector {
+                x: 10.0,
+                y: 10.0,
+                z: 0.0,
+            },
+            acceleration: crate::GRAVITY,
+        };
+        assert!(predicted[90].x < free.position_at(90.0 * delta_time.0).x);
+    }
 }
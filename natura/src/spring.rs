@@ -1,22 +1,23 @@
-/// This file defines a simplified damped harmonic oscillator, colloquially
-/// known as a spring. This is ported from Ryan Juckett’s simple damped harmonic
-/// motion, originally written in C++.
-///
-/// Example usage:
-///
-///```
-/// use natura::{Spring, Vector, Point, DeltaTime, AngularFrequency, DampingRatio};
-/// // Run once to initialize.
-/// let mut spring = Spring::new(DeltaTime(natura::fps(60)), AngularFrequency(6.0), DampingRatio(0.5));
-///
-/// // Update on every frame.
-/// let mut pos = 0.0;
-/// let mut velocity = 0.0;
-/// const TARGET_POS:f64 = 100.0;
-/// some_update_loop(|| {
-///    let (pos_new, velocity_new) = spring.update(pos, velocity, TARGET_POS);
-/// });
-///```
+//! This file defines a simplified damped harmonic oscillator, colloquially
+//! known as a spring. This is ported from Ryan Juckett’s simple damped harmonic
+//! motion, originally written in C++.
+//!
+//! Example usage:
+//!
+//!```
+//! use natura::{Spring, Vector, Point, DeltaTime, AngularFrequency, DampingRatio};
+//! // Run once to initialize.
+//! let mut spring = Spring::new(DeltaTime(natura::fps(60)), AngularFrequency(6.0), DampingRatio(0.5));
+//!
+//! # fn some_update_loop(mut f: impl FnMut()) { f() }
+//! // Update on every frame.
+//! let mut pos = 0.0;
+//! let mut velocity = 0.0;
+//! const TARGET_POS:f64 = 100.0;
+//! some_update_loop(|| {
+//!    let (pos_new, velocity_new) = spring.update(pos, velocity, TARGET_POS);
+//! });
+//!```
 //
 // For background on the algorithm see:
 // https://www.ryanjuckett.com/damped-springs/
@@ -39,9 +40,14 @@
   3. This notice may not be removed or altered from any source
      distribution.
 ********************************************************************************/
-use std::fmt;
-use std::fmt::Formatter;
-use std::time::Duration;
+#[cfg(feature = "alloc")]
+use crate::SpringTrajectory;
+use crate::{Animatable, Float, SpringParams};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Formatter;
+use core::time::Duration;
 
 /// Spring contains a cached set of motion parameters that can be used to
 /// efficiently update multiple springs using the same time step, angular
@@ -55,7 +61,7 @@ use std::time::Duration;
 /// # Example:
 ///
 /// ```
-/// use natura::{Spring, fps, AngularFrequency, DampingRatio};
+/// use natura::{Spring, fps, DeltaTime, AngularFrequency, DampingRatio};
 ///
 /// // First precompute spring coefficients based on your settings:
 /// let x:f64 =0.0;
@@ -63,32 +69,82 @@ use std::time::Duration;
 /// let y:f64 = 0.0;
 /// let y_vel:f64 = 0.0;
 ///
-/// delta_time = fps(60);
+/// let delta_time = DeltaTime(fps(60));
 /// let mut s = Spring::new(delta_time, AngularFrequency(5.0),DampingRatio(0.2));
 ///
 /// // Then, in your update loop:
 /// let (x_new, x_vel_new) = s.update(x, x_vel, 10.0); // update the X position
-/// let (y_new, y_vel_new) = s.update(y, y_vel, 20.0); // update the Y position///
-#[derive(Default)]
-pub struct Spring {
-    ///
-    pos_pos_coef: f64,
-
-    ///
-    pos_vel_coef: f64,
+/// let (y_new, y_vel_new) = s.update(y, y_vel, 20.0); // update the Y position
+/// ```
+///
+/// Springs compute with `f64` by default. Pass `f32` parameters to get a
+/// spring that works in `f32` throughout, see [Float].
+///
+/// With the `serde` feature, a Spring serializes its delta time, angular
+/// frequency and damping ratio; the coefficients are recomputed when it is
+/// deserialized.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        from = "SpringConfig<F>",
+        bound(deserialize = "F: Float + serde::Deserialize<'de>")
+    )
+)]
+pub struct Spring<F = f64> {
+    /// time step the coefficients are computed for
+    delta_time: F,
+
+    /// angular frequency of motion, at least zero
+    angular_frequency: F,
+
+    /// damping ratio of motion, at least zero
+    damping_ratio: F,
+
+    /// weight of the old position in the new position
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pos_pos_coef: F,
+
+    /// weight of the old velocity in the new position
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pos_vel_coef: F,
+
+    /// weight of the old position in the new velocity
+    #[cfg_attr(feature = "serde", serde(skip))]
+    vel_pos_coef: F,
+
+    /// weight of the old velocity in the new velocity
+    #[cfg_attr(feature = "serde", serde(skip))]
+    vel_vel_coef: F,
+}
 
-    ///
-    vel_pos_coef: f64,
+/// SpringConfig is the serialized form of a [Spring]: its parameters,
+/// without the coefficients derived from them.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SpringConfig<F> {
+    delta_time: F,
+    angular_frequency: F,
+    damping_ratio: F,
+}
 
-    ///
-    vel_vel_coef: f64,
+#[cfg(feature = "serde")]
+impl<F: Float> From<SpringConfig<F>> for Spring<F> {
+    fn from(config: SpringConfig<F>) -> Self {
+        Spring::new(
+            DeltaTime(config.delta_time),
+            AngularFrequency(config.angular_frequency),
+            DampingRatio(config.damping_ratio),
+        )
+    }
 }
 
 /// In calculus ε is, in vague terms, an arbitrarily small positive number. In
 /// the original C++ source ε is represented as such:
 /// `const float epsilon = 0.0001`;
 ///
-const EPSILON: f64 = 0.00000001;
+pub(crate) const EPSILON: f64 = 0.00000001;
 
 /// fps returns a time delta for a given number of frames per second. This
 /// value can be used as the time delta when initializing a Spring. Note that
@@ -105,18 +161,34 @@ pub fn fps(n: u64) -> f64 {
     let duration = Duration::new(0, n as u32).as_nanos();
     let second = Duration::from_secs(1).as_nanos();
 
-    (((second / duration) as f64 / 1000000.0) / 1000.0) as f64
+    ((second / duration) as f64 / 1000000.0) / 1000.0
 }
 
-pub struct DeltaTime(pub f64);
-
-#[derive(Clone)]
-pub struct AngularFrequency(pub f64);
-
-#[derive(Clone)]
-pub struct DampingRatio(pub f64);
-
-impl Spring {
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct DeltaTime<F = f64>(pub F);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct AngularFrequency<F = f64>(pub F);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct DampingRatio<F = f64>(pub F);
+
+impl<F: Float> Spring<F> {
     /// new initializes a new Spring, computing the parameters needed to
     /// simulate a damped spring over a given period of time.
     ///
@@ -141,29 +213,36 @@ impl Spring {
     /// An under-damped spring will reach equilibrium the fastest, but also
     /// overshoots it and continues to oscillate as its amplitude decays over time.
     pub fn new(
-        delta_time: DeltaTime,
-        mut angular_frequency: AngularFrequency,
-        mut damping_ratio: DampingRatio,
+        delta_time: DeltaTime<F>,
+        mut angular_frequency: AngularFrequency<F>,
+        mut damping_ratio: DampingRatio<F>,
     ) -> Self {
-        let mut spring = Spring::default();
+        let epsilon = F::from_f64(EPSILON);
 
         // keep values in a legal range.
-        angular_frequency.0 = f64::max(0.0, angular_frequency.0);
-        damping_ratio.0 = f64::max(0.0, damping_ratio.0);
+        angular_frequency.0 = F::ZERO.max(angular_frequency.0);
+        damping_ratio.0 = F::ZERO.max(damping_ratio.0);
+
+        let mut spring = Spring {
+            delta_time: delta_time.0,
+            angular_frequency: angular_frequency.0,
+            damping_ratio: damping_ratio.0,
+            ..Spring::default()
+        };
 
         // if there is no angular frequency, the spring will not move and we can
         // return identity.
-        if angular_frequency.0 < EPSILON {
-            spring.pos_pos_coef = 1.0;
-            spring.pos_vel_coef = 0.0;
-            spring.vel_pos_coef = 0.0;
-            spring.vel_vel_coef = 1.0;
+        if angular_frequency.0 < epsilon {
+            spring.pos_pos_coef = F::ONE;
+            spring.pos_vel_coef = F::ZERO;
+            spring.vel_pos_coef = F::ZERO;
+            spring.vel_vel_coef = F::ONE;
             return spring;
         }
 
-        let f_delta_time = delta_time.0 as f64;
+        let f_delta_time = delta_time.0;
 
-        if damping_ratio.0 > 1.0 + EPSILON {
+        if damping_ratio.0 > F::ONE + epsilon {
             // Over-damped.
             Self::calculate_over_damped(
                 delta_time.0,
@@ -171,7 +250,7 @@ impl Spring {
                 damping_ratio.0,
                 &mut spring,
             );
-        } else if damping_ratio.0 < 1.0 - EPSILON {
+        } else if damping_ratio.0 < F::ONE - epsilon {
             // Under-damped.
             Self::calculate_under_damped(
                 f_delta_time,
@@ -188,9 +267,62 @@ impl Spring {
         spring
     }
 
+    /// with_params initializes a new Spring from any description of a spring
+    /// that converts into [SpringParams], such as [DurationBounce],
+    /// [PhysicalParams] or [HalfLife](crate::HalfLife).
+    ///
+    /// The parameters are converted to an angular frequency and damping ratio
+    /// with `f64` math before they are turned into `F`. Only [SpringParams]
+    /// given directly as angular frequency and damping ratio are guaranteed to
+    /// give a bit-identical `Spring<Fixed>` on every target; for lockstep
+    /// simulations, convert the parameters once and ship those.
+    ///
+    /// ```
+    /// use natura::{Spring, fps, DeltaTime, DurationBounce};
+    ///
+    /// let mut spring = Spring::with_params(
+    ///     DeltaTime(fps(60)),
+    ///     DurationBounce { duration: 0.5, bounce: 0.3 },
+    /// );
+    /// ```
+    ///
+    /// [DurationBounce]: crate::DurationBounce
+    /// [PhysicalParams]: crate::PhysicalParams
+    pub fn with_params(delta_time: DeltaTime<F>, params: impl Into<SpringParams>) -> Self {
+        let params = params.into();
+        Spring::new(
+            delta_time,
+            AngularFrequency(F::from_f64(params.angular_frequency.0)),
+            DampingRatio(F::from_f64(params.damping_ratio.0)),
+        )
+    }
+
+    /// delta_time returns the time step the spring was computed for.
+    pub fn delta_time(&self) -> DeltaTime<F> {
+        DeltaTime(self.delta_time)
+    }
+
+    /// angular_frequency returns the angular frequency of the spring.
+    pub fn angular_frequency(&self) -> AngularFrequency<F> {
+        AngularFrequency(self.angular_frequency)
+    }
+
+    /// damping_ratio returns the damping ratio of the spring.
+    pub fn damping_ratio(&self) -> DampingRatio<F> {
+        DampingRatio(self.damping_ratio)
+    }
+
+    /// params returns the angular frequency and damping ratio of the spring.
+    pub fn params(&self) -> SpringParams {
+        SpringParams::new(
+            AngularFrequency(self.angular_frequency.to_f64()),
+            DampingRatio(self.damping_ratio.to_f64()),
+        )
+    }
+
     /// update updates position and velocity values against a given target value.
     /// call this after calling [Spring::new] to update values.
-    pub fn update(&mut self, pos: f64, vel: f64, equilibrium_pos: f64) -> (f64, f64) {
+    pub fn update(&mut self, pos: F, vel: F, equilibrium_pos: F) -> (F, F) {
         let old_pos = pos - equilibrium_pos; // update in equilibrium relative space
         let old_vel = vel;
 
@@ -200,8 +332,45 @@ impl Spring {
         (new_pos, new_vel)
     }
 
+    /// update_value updates position and velocity values of any [Animatable]
+    /// type against a given target value, so a whole `Point`, `[f64; N]` or
+    /// tuple advances in a single call instead of once per axis.
+    ///
+    /// ```
+    /// use natura::{Spring, fps, DeltaTime, AngularFrequency, DampingRatio};
+    ///
+    /// let mut s = Spring::new(DeltaTime(fps(60)), AngularFrequency(5.0), DampingRatio(0.2));
+    /// let (pos, vel) = s.update_value([0.0, 0.0], [0.0, 0.0], [10.0, 20.0]);
+    /// ```
+    pub fn update_value<T: Animatable<F>>(&mut self, pos: T, vel: T, equilibrium_pos: T) -> (T, T) {
+        let old_pos = pos.sub(equilibrium_pos); // update in equilibrium relative space
+        let old_vel = vel;
+
+        let new_pos = old_pos
+            .scale(self.pos_pos_coef)
+            .add(old_vel.scale(self.pos_vel_coef))
+            .add(equilibrium_pos);
+        let new_vel = old_pos
+            .scale(self.vel_pos_coef)
+            .add(old_vel.scale(self.vel_vel_coef));
+
+        (new_pos, new_vel)
+    }
+
+    /// coefficients returns the position-position, position-velocity,
+    /// velocity-position and velocity-velocity coefficients, in that order.
+    #[inline(always)]
+    pub(crate) fn coefficients(&self) -> [f64; 4] {
+        [
+            self.pos_pos_coef.to_f64(),
+            self.pos_vel_coef.to_f64(),
+            self.vel_pos_coef.to_f64(),
+            self.vel_vel_coef.to_f64(),
+        ]
+    }
+
     #[inline(always)]
-    fn calculate_critically_damped(delta_time: f64, angular_frequency: f64, spring: &mut Spring) {
+    fn calculate_critically_damped(delta_time: F, angular_frequency: F, spring: &mut Spring<F>) {
         let exp_term = (-angular_frequency * delta_time).exp();
         let time_exp = delta_time * exp_term;
         let time_exp_freq = time_exp * angular_frequency;
@@ -215,19 +384,19 @@ impl Spring {
 
     #[inline(always)]
     fn calculate_under_damped(
-        delta_time: f64,
-        angular_frequency: f64,
-        damping_ratio: f64,
-        spring: &mut Spring,
+        delta_time: F,
+        angular_frequency: F,
+        damping_ratio: F,
+        spring: &mut Spring<F>,
     ) {
         let omega_zeta = angular_frequency * damping_ratio;
-        let alpha = angular_frequency * (1.0 - damping_ratio * damping_ratio).sqrt();
+        let alpha = angular_frequency * (F::ONE - damping_ratio * damping_ratio).sqrt();
 
         let exp_term = (-omega_zeta * delta_time).exp();
         let cos_term = (alpha * delta_time).cos();
         let sin_term = (alpha * delta_time).sin();
 
-        let inv_alpha = 1.0 / alpha;
+        let inv_alpha = F::ONE / alpha;
 
         let exp_sin = exp_term * sin_term;
         let exp_cos = exp_term * cos_term;
@@ -242,20 +411,20 @@ impl Spring {
 
     #[inline(always)]
     fn calculate_over_damped(
-        delta_time: f64,
-        angular_frequency: f64,
-        damping_ratio: f64,
-        spring: &mut Spring,
+        delta_time: F,
+        angular_frequency: F,
+        damping_ratio: F,
+        spring: &mut Spring<F>,
     ) {
         let za = -angular_frequency * damping_ratio;
-        let zb = angular_frequency * (damping_ratio * damping_ratio - 1.0).sqrt();
+        let zb = angular_frequency * (damping_ratio * damping_ratio - F::ONE).sqrt();
         let z1 = za - zb;
         let z2 = za + zb;
 
         let e1 = (z1 * delta_time).exp();
         let e2 = (z2 * delta_time).exp();
 
-        let inv_two_zb = 1.0 / (2.0 * zb); // = 1 / (z2 - z1)
+        let inv_two_zb = F::ONE / ((F::ONE + F::ONE) * zb); // = 1 / (z2 - z1)
 
         let e1_over_two_zb = e1 * inv_two_zb;
         let e2_over_two_zb = e2 * inv_two_zb;
@@ -271,7 +440,7 @@ impl Spring {
     }
 }
 
-impl fmt::Display for Spring {
+impl<F: Float> fmt::Display for Spring<F> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -280,3 +449,237 @@ impl fmt::Display for Spring {
         )
     }
 }
+
+/// DynamicSpring keeps the spring parameters instead of coefficients baked for
+/// a single time step, so it can evaluate the closed-form oscillator for an
+/// arbitrary delta time on every call. Use it when the frame length varies
+/// (e.g. with the delta time reported by a game engine).
+///
+/// Coefficients are recomputed for each new delta time. An optional small
+/// least-recently-used cache keeps the coefficient sets of recently seen time
+/// steps, which avoids the `exp`/`sin`/`cos` calls when the same deltas repeat.
+///
+/// # Example:
+///
+/// ```
+/// use natura::{DynamicSpring, DeltaTime, AngularFrequency, DampingRatio};
+///
+/// let mut spring = DynamicSpring::with_cache_capacity(AngularFrequency(6.0), DampingRatio(0.5), 4);
+///
+/// // In your update loop, pass the time elapsed since the last frame:
+/// let (pos, vel) = spring.update(0.0, 0.0, 100.0, DeltaTime(0.016));
+/// let (pos, vel) = spring.update(pos, vel, 100.0, DeltaTime(0.021));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DynamicSpring<F = f64> {
+    angular_frequency: F,
+    damping_ratio: F,
+
+    /// maximum number of coefficient sets kept in `cache`
+    cache_capacity: usize,
+
+    /// coefficient sets keyed by delta time, most recently used first
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cache: Vec<(F, Spring<F>)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float> DynamicSpring<F> {
+    /// new creates a DynamicSpring that recomputes its coefficients on every
+    /// call. See [Spring::new] for the meaning of the parameters.
+    pub fn new(angular_frequency: AngularFrequency<F>, damping_ratio: DampingRatio<F>) -> Self {
+        Self::with_cache_capacity(angular_frequency, damping_ratio, 0)
+    }
+
+    /// with_params creates a DynamicSpring from any description of a spring
+    /// that converts into [SpringParams]. Like [Spring::with_params], it is
+    /// only bit-identical across targets for parameters given directly as
+    /// angular frequency and damping ratio.
+    pub fn with_params(params: impl Into<SpringParams>) -> Self {
+        let params = params.into();
+        Self::new(
+            AngularFrequency(F::from_f64(params.angular_frequency.0)),
+            DampingRatio(F::from_f64(params.damping_ratio.0)),
+        )
+    }
+
+    /// with_cache_capacity creates a DynamicSpring that remembers the
+    /// coefficients of up to `capacity` recently used delta times.
+    pub fn with_cache_capacity(
+        angular_frequency: AngularFrequency<F>,
+        damping_ratio: DampingRatio<F>,
+        capacity: usize,
+    ) -> Self {
+        DynamicSpring {
+            angular_frequency: angular_frequency.0,
+            damping_ratio: damping_ratio.0,
+            cache_capacity: capacity,
+            cache: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// angular_frequency returns the angular frequency of the spring.
+    pub fn angular_frequency(&self) -> AngularFrequency<F> {
+        AngularFrequency(self.angular_frequency)
+    }
+
+    /// damping_ratio returns the damping ratio of the spring.
+    pub fn damping_ratio(&self) -> DampingRatio<F> {
+        DampingRatio(self.damping_ratio)
+    }
+
+    /// params returns the angular frequency and damping ratio of the spring.
+    pub fn params(&self) -> SpringParams {
+        SpringParams::new(
+            AngularFrequency(self.angular_frequency.to_f64()),
+            DampingRatio(self.damping_ratio.to_f64()),
+        )
+    }
+
+    /// trajectory returns the analytic motion of this spring from the given
+    /// initial state, which can be evaluated at any time without stepping.
+    pub fn trajectory<T: Animatable>(
+        &self,
+        initial_pos: T,
+        initial_vel: T,
+        equilibrium_pos: T,
+    ) -> SpringTrajectory<T> {
+        let params = self.params();
+        SpringTrajectory::new(
+            params.angular_frequency,
+            params.damping_ratio,
+            initial_pos,
+            initial_vel,
+            equilibrium_pos,
+        )
+    }
+
+    /// spring returns the coefficients for the given delta time, computing
+    /// them unless they are cached.
+    pub fn spring(&mut self, delta_time: DeltaTime<F>) -> Spring<F> {
+        if let Some(index) = self.cache.iter().position(|(dt, _)| *dt == delta_time.0) {
+            let entry = self.cache.remove(index);
+            self.cache.insert(0, entry);
+            return entry.1;
+        }
+
+        let spring = Spring::new(
+            delta_time,
+            AngularFrequency(self.angular_frequency),
+            DampingRatio(self.damping_ratio),
+        );
+
+        if self.cache_capacity > 0 {
+            self.cache.truncate(self.cache_capacity - 1);
+            self.cache.insert(0, (delta_time.0, spring));
+        }
+
+        spring
+    }
+
+    /// update advances position and velocity by the given delta time against
+    /// a given target value.
+    pub fn update(
+        &mut self,
+        pos: F,
+        vel: F,
+        equilibrium_pos: F,
+        delta_time: DeltaTime<F>,
+    ) -> (F, F) {
+        self.spring(delta_time).update(pos, vel, equilibrium_pos)
+    }
+
+    /// update_value advances any [Animatable] position and velocity by the
+    /// given delta time against a given target value.
+    pub fn update_value<T: Animatable<F>>(
+        &mut self,
+        pos: T,
+        vel: T,
+        equilibrium_pos: T,
+        delta_time: DeltaTime<F>,
+    ) -> (T, T) {
+        let mut spring = self.spring(delta_time);
+        spring.update_value(pos, vel, equilibrium_pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AngularFrequency, DampingRatio, DeltaTime, DynamicSpring, Spring};
+
+    #[test]
+    fn test_dynamic_spring_matches_fixed_spring() {
+        for damping in [0.3, 1.0, 1.7] {
+            let mut fixed = Spring::new(
+                DeltaTime(0.016),
+                AngularFrequency(6.0),
+                DampingRatio(damping),
+            );
+            let mut dynamic = DynamicSpring::new(AngularFrequency(6.0), DampingRatio(damping));
+
+            let (mut pos, mut vel) = (0.0, 0.0);
+            let (mut dyn_pos, mut dyn_vel) = (0.0, 0.0);
+            for _ in 0..100 {
+                (pos, vel) = fixed.update(pos, vel, 100.0);
+                (dyn_pos, dyn_vel) = dynamic.update(dyn_pos, dyn_vel, 100.0, DeltaTime(0.016));
+            }
+
+            assert_eq!(pos, dyn_pos);
+            assert_eq!(vel, dyn_vel);
+        }
+    }
+
+    #[test]
+    fn test_dynamic_spring_variable_steps_are_exact() {
+        // Two half steps of a closed-form solution must land on one full step.
+        let mut spring = DynamicSpring::new(AngularFrequency(8.0), DampingRatio(0.4));
+
+        let (half_pos, half_vel) = spring.update(0.0, 5.0, 100.0, DeltaTime(0.0125));
+        let (split_pos, split_vel) = spring.update(half_pos, half_vel, 100.0, DeltaTime(0.0125));
+        let (full_pos, full_vel) = spring.update(0.0, 5.0, 100.0, DeltaTime(0.025));
+
+        assert!(relative_eq!(split_pos, full_pos, epsilon = 1e-9));
+        assert!(relative_eq!(split_vel, full_vel, epsilon = 1e-9));
+    }
+
+    #[test]
+    fn test_dynamic_spring_cache_evicts_least_recently_used() {
+        let mut spring =
+            DynamicSpring::with_cache_capacity(AngularFrequency(6.0), DampingRatio(0.5), 2);
+
+        spring.spring(DeltaTime(0.01));
+        spring.spring(DeltaTime(0.02));
+        spring.spring(DeltaTime(0.01));
+        spring.spring(DeltaTime(0.03));
+
+        let cached: Vec<f64> = spring.cache.iter().map(|(dt, _)| *dt).collect();
+        assert_eq!(cached, vec![0.03, 0.01]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let spring = Spring::new(DeltaTime(0.016), AngularFrequency(6.0), DampingRatio(0.5));
+
+        let json = serde_json::to_string(&spring).unwrap();
+        assert_eq!(
+            json,
+            r#"{"delta_time":0.016,"angular_frequency":6.0,"damping_ratio":0.5}"#
+        );
+        let back: Spring = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, spring);
+
+        let mut dynamic =
+            DynamicSpring::with_cache_capacity(AngularFrequency(6.0), DampingRatio(0.5), 2);
+        dynamic.spring(DeltaTime(0.016));
+        let json = serde_json::to_string(&dynamic).unwrap();
+        let mut back: DynamicSpring = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.params(), dynamic.params());
+        assert_eq!(
+            back.update(0.0, 0.0, 100.0, DeltaTime(0.016)),
+            dynamic.update(0.0, 0.0, 100.0, DeltaTime(0.016))
+        );
+    }
+}
@@ -1,16 +1,17 @@
-use std::fmt;
-use std::fmt::Formatter;
+use core::fmt;
+use core::fmt::Formatter;
 
 // A thing we want to animate.
 #[derive(Default)]
-pub struct Sprite {
-    pub x: f64,
-    pub x_velocity: f64,
-    pub y: f64,
-    pub y_velocity: f64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sprite<F = f64> {
+    pub x: F,
+    pub x_velocity: F,
+    pub y: F,
+    pub y_velocity: F,
 }
 
-impl fmt::Display for Sprite {
+impl<F: fmt::Display> fmt::Display for Sprite<F> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -0,0 +1,631 @@
+//! This file defines the analytic trajectory of a spring. Because the damped
+//! harmonic oscillator has a closed-form solution, position and velocity at any
+//! time can be computed directly from the initial state, without stepping
+//! through every frame. This is useful to scrub animations in editors, render
+//! timelines, or jump to an arbitrary frame.
+//!
+//! Example usage:
+//!
+//! ```
+//! use natura::{SpringTrajectory, AngularFrequency, DampingRatio};
+//!
+//! let trajectory = SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(0.5), 0.0, 0.0, 100.0);
+//!
+//! let pos = trajectory.position_at(0.25);
+//! let vel = trajectory.velocity_at(0.25);
+//! let (pos, vel) = trajectory.state_at(1.5);
+//! ```
+//!
+//! Scalar trajectories can also be analyzed, to predict how long a spring
+//! takes to settle, how far it overshoots and how many times it oscillates,
+//! before running it:
+//!
+//! ```
+//! use natura::{SpringTrajectory, AngularFrequency, DampingRatio};
+//!
+//! let trajectory = SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(0.5), 0.0, 0.0, 100.0);
+//!
+//! let settles_after = trajectory.settling_time(0.1); // seconds until within 0.1 of 100.0
+//! let overshoot = trajectory.peak_overshoot(); // how far past 100.0 it goes
+//! let peak_at = trajectory.first_peak_time();
+//! let bounces = trajectory.oscillation_count(0.1);
+//! ```
+use crate::spring::EPSILON;
+#[cfg(not(feature = "std"))]
+use crate::{math::Math, Float};
+use crate::{AngularFrequency, Animatable, DampingRatio, DeltaTime, Spring};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::f64::consts::{E, PI};
+
+/// Number of samples used to locate the settling time within its upper bound.
+const MIN_SETTLING_SAMPLES: usize = 1024;
+
+/// Number of samples per half oscillation used to locate the settling time of
+/// an under-damped spring.
+const SAMPLES_PER_HALF_PERIOD: f64 = 32.0;
+
+/// Upper limit on the number of samples used to locate the settling time.
+const MAX_SETTLING_SAMPLES: usize = 1_000_000;
+
+/// Iterations used to refine the settling time once it has been bracketed.
+const BISECTION_ITERATIONS: usize = 64;
+
+/// SpringTrajectory is the motion of a spring released from an initial
+/// position and velocity towards an equilibrium position.
+///
+/// # Example:
+///
+/// ```
+/// use natura::{SpringTrajectory, AngularFrequency, DampingRatio, Point};
+///
+/// let trajectory = SpringTrajectory::new(
+///     AngularFrequency(6.0),
+///     DampingRatio(0.5),
+///     Point { x: 0.0, y: 0.0, z: 0.0 },
+///     Point::default(),
+///     Point { x: 50.0, y: 100.0, z: 0.0 },
+/// );
+///
+/// // Positions of the first second, one per frame at 60 fps:
+/// let frames: Vec<Point> = trajectory.sample(1.0, 60);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringTrajectory<T: Animatable> {
+    angular_frequency: f64,
+    damping_ratio: f64,
+
+    /// position at time zero
+    initial_pos: T,
+    /// velocity at time zero
+    initial_vel: T,
+    /// target the spring settles on
+    equilibrium_pos: T,
+}
+
+impl<T: Animatable> SpringTrajectory<T> {
+    /// new creates the trajectory of a spring starting at `initial_pos` with
+    /// `initial_vel` and moving towards `equilibrium_pos`. See [Spring::new]
+    /// for the meaning of the parameters.
+    pub fn new(
+        angular_frequency: AngularFrequency,
+        damping_ratio: DampingRatio,
+        initial_pos: T,
+        initial_vel: T,
+        equilibrium_pos: T,
+    ) -> Self {
+        SpringTrajectory {
+            angular_frequency: angular_frequency.0,
+            damping_ratio: damping_ratio.0,
+            initial_pos,
+            initial_vel,
+            equilibrium_pos,
+        }
+    }
+
+    /// angular_frequency returns the angular frequency of the spring.
+    pub fn angular_frequency(&self) -> AngularFrequency {
+        AngularFrequency(self.angular_frequency)
+    }
+
+    /// damping_ratio returns the damping ratio of the spring.
+    pub fn damping_ratio(&self) -> DampingRatio {
+        DampingRatio(self.damping_ratio)
+    }
+
+    /// initial_position returns the position at time zero.
+    pub fn initial_position(&self) -> T {
+        self.initial_pos
+    }
+
+    /// initial_velocity returns the velocity at time zero.
+    pub fn initial_velocity(&self) -> T {
+        self.initial_vel
+    }
+
+    /// equilibrium_position returns the target the spring settles on.
+    pub fn equilibrium_position(&self) -> T {
+        self.equilibrium_pos
+    }
+
+    /// state_at returns the position and velocity at the given time in
+    /// seconds. Times before zero return the initial state.
+    pub fn state_at(&self, time: f64) -> (T, T) {
+        if time <= 0.0 {
+            return (self.initial_pos, self.initial_vel);
+        }
+
+        let mut spring = Spring::new(
+            DeltaTime(time),
+            AngularFrequency(self.angular_frequency),
+            DampingRatio(self.damping_ratio),
+        );
+        spring.update_value(self.initial_pos, self.initial_vel, self.equilibrium_pos)
+    }
+
+    /// position_at returns the position at the given time in seconds.
+    pub fn position_at(&self, time: f64) -> T {
+        self.state_at(time).0
+    }
+
+    /// velocity_at returns the velocity at the given time in seconds.
+    pub fn velocity_at(&self, time: f64) -> T {
+        self.state_at(time).1
+    }
+
+    /// sample returns `samples` positions evenly spaced over `duration`
+    /// seconds, starting at time zero and ending at `duration`.
+    #[cfg(feature = "alloc")]
+    pub fn sample(&self, duration: f64, samples: usize) -> Vec<T> {
+        match samples {
+            0 => Vec::new(),
+            1 => alloc::vec![self.initial_pos],
+            _ => {
+                let step = duration / (samples - 1) as f64;
+                (0..samples)
+                    .map(|i| self.position_at(i as f64 * step))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl SpringTrajectory<f64> {
+    /// settling_time returns the time in seconds after which the position
+    /// stays within `tolerance` of the equilibrium position, or `None` if the
+    /// spring never settles (e.g. with a damping ratio of zero).
+    pub fn settling_time(&self, tolerance: f64) -> Option<f64> {
+        let motion = self.motion();
+        motion.settling_time(
+            self.initial_pos - self.equilibrium_pos,
+            self.initial_vel,
+            tolerance,
+        )
+    }
+
+    /// velocity_settling_time returns the time in seconds after which the
+    /// speed stays below `tolerance`, or `None` if the spring never settles.
+    pub fn velocity_settling_time(&self, tolerance: f64) -> Option<f64> {
+        let motion = self.motion();
+        let displacement = self.initial_pos - self.equilibrium_pos;
+
+        // Velocity obeys the same differential equation as displacement, so it
+        // settles like a trajectory starting at the initial velocity and
+        // acceleration.
+        motion.settling_time(
+            self.initial_vel,
+            motion.acceleration(displacement, self.initial_vel),
+            tolerance,
+        )
+    }
+
+    /// first_peak_time returns the time in seconds of the first turning point
+    /// of the motion (where velocity reaches zero), or `None` if the spring
+    /// moves monotonically towards the equilibrium position.
+    ///
+    /// For a spring released from rest this is the time of peak overshoot.
+    pub fn first_peak_time(&self) -> Option<f64> {
+        self.motion()
+            .peak_times(self.initial_pos - self.equilibrium_pos, self.initial_vel)
+            .first
+    }
+
+    /// peak_overshoot returns the largest distance the spring travels past
+    /// the equilibrium position, on the opposite side from where it started.
+    /// A critically or over-damped spring released from rest never overshoots
+    /// and returns zero.
+    pub fn peak_overshoot(&self) -> f64 {
+        let motion = self.motion();
+        let displacement = self.initial_pos - self.equilibrium_pos;
+
+        let side = if displacement != 0.0 {
+            displacement.signum()
+        } else if self.initial_vel != 0.0 {
+            self.initial_vel.signum()
+        } else {
+            return 0.0;
+        };
+
+        // Peaks shrink over time, so the first one on the far side is the
+        // largest. Under-damped peaks alternate sides, so two are enough.
+        let peaks = motion.peak_times(displacement, self.initial_vel);
+        let candidates = [
+            peaks.first,
+            peaks.first.zip(peaks.interval).map(|(t, dt)| t + dt),
+        ];
+
+        candidates
+            .into_iter()
+            .flatten()
+            .map(|t| side * (self.equilibrium_pos - self.position_at(t)))
+            .find(|overshoot| *overshoot > 0.0)
+            .unwrap_or(0.0)
+    }
+
+    /// overshoot_ratio returns [SpringTrajectory::peak_overshoot] relative to
+    /// the initial distance from the equilibrium position (e.g. `0.16` for a
+    /// 16% overshoot), or `None` if the spring starts at equilibrium.
+    pub fn overshoot_ratio(&self) -> Option<f64> {
+        let displacement = (self.initial_pos - self.equilibrium_pos).abs();
+        if displacement < EPSILON {
+            return None;
+        }
+
+        Some(self.peak_overshoot() / displacement)
+    }
+
+    /// oscillation_count returns the number of turning points the spring
+    /// passes before it settles within `tolerance` of the equilibrium
+    /// position, or `None` if it never settles.
+    pub fn oscillation_count(&self, tolerance: f64) -> Option<usize> {
+        let settling_time = self.settling_time(tolerance)?;
+        let displacement = |t: f64| (self.position_at(t) - self.equilibrium_pos).abs();
+
+        let peaks = self
+            .motion()
+            .peak_times(self.initial_pos - self.equilibrium_pos, self.initial_vel);
+        let Some(first) = peaks.first else {
+            return Some(0);
+        };
+
+        match peaks.interval {
+            Some(interval) => Some(
+                (0..)
+                    .map(|k| first + k as f64 * interval)
+                    .take_while(|t| *t <= settling_time)
+                    .filter(|t| displacement(*t) > tolerance)
+                    .count(),
+            ),
+            None => Some(usize::from(displacement(first) > tolerance)),
+        }
+    }
+
+    fn motion(&self) -> Motion {
+        Motion {
+            angular_frequency: f64::max(0.0, self.angular_frequency),
+            damping_ratio: f64::max(0.0, self.damping_ratio),
+        }
+    }
+}
+
+/// Times of the turning points of a motion. Turning points after the first
+/// are `interval` seconds apart when the spring oscillates.
+struct PeakTimes {
+    first: Option<f64>,
+    interval: Option<f64>,
+}
+
+/// Motion holds the parameters of the oscillator `y'' + 2ζωy' + ω²y = 0`, and
+/// answers questions about its solution `y(t)` for a given `y(0)` and `y'(0)`.
+struct Motion {
+    angular_frequency: f64,
+    damping_ratio: f64,
+}
+
+impl Motion {
+    /// acceleration returns `y''` for the given `y` and `y'`.
+    fn acceleration(&self, y: f64, dy: f64) -> f64 {
+        let omega = self.angular_frequency;
+        -omega * omega * y - 2.0 * self.damping_ratio * omega * dy
+    }
+
+    /// value returns `y(t)`.
+    fn value(&self, y0: f64, dy0: f64, t: f64) -> f64 {
+        let mut spring = Spring::new(
+            DeltaTime(t),
+            AngularFrequency(self.angular_frequency),
+            DampingRatio(self.damping_ratio),
+        );
+        spring.update(y0, dy0, 0.0).0
+    }
+
+    /// envelope returns `(k, rate)` such that `|y(t)| <= k * exp(-rate * t)`.
+    fn envelope(&self, y0: f64, dy0: f64) -> (f64, f64) {
+        let omega = self.angular_frequency;
+        let zeta = self.damping_ratio;
+
+        if zeta > 1.0 + EPSILON {
+            let (z1, z2) = self.over_damped_roots();
+            let (c1, c2) = over_damped_weights(z1, z2, y0, dy0);
+            (c1.abs() + c2.abs(), -z2)
+        } else if zeta < 1.0 - EPSILON {
+            let alpha = omega * (1.0 - zeta * zeta).sqrt();
+            let b = (dy0 + zeta * omega * y0) / alpha;
+            ((y0 * y0 + b * b).sqrt(), zeta * omega)
+        } else {
+            // t * exp(-ωt / 2) peaks at 2 / (eω).
+            let b = dy0 + omega * y0;
+            (y0.abs() + 2.0 * b.abs() / (E * omega), omega / 2.0)
+        }
+    }
+
+    /// over_damped_roots returns the two exponents of an over-damped motion,
+    /// fastest decaying first.
+    fn over_damped_roots(&self) -> (f64, f64) {
+        let omega = self.angular_frequency;
+        let zeta = self.damping_ratio;
+        let za = -omega * zeta;
+        let zb = omega * (zeta * zeta - 1.0).sqrt();
+        (za - zb, za + zb)
+    }
+
+    /// settling_time returns the last time `|y(t)|` exceeds `tolerance`.
+    fn settling_time(&self, y0: f64, dy0: f64, tolerance: f64) -> Option<f64> {
+        if self.angular_frequency < EPSILON {
+            // A spring without frequency never moves.
+            return (y0.abs() <= tolerance).then_some(0.0);
+        }
+
+        let (k, rate) = self.envelope(y0, dy0);
+        if k <= tolerance {
+            return Some(0.0);
+        }
+        if rate < EPSILON || tolerance <= 0.0 {
+            return None;
+        }
+        let bound = (k / tolerance).ln() / rate;
+
+        let mut samples = MIN_SETTLING_SAMPLES;
+        if self.damping_ratio < 1.0 - EPSILON {
+            let half_period = PI
+                / (self.angular_frequency * (1.0 - self.damping_ratio * self.damping_ratio).sqrt());
+            samples = samples.max((bound / half_period * SAMPLES_PER_HALF_PERIOD) as usize);
+        }
+        let samples = samples.min(MAX_SETTLING_SAMPLES);
+        let step = bound / samples as f64;
+
+        let exceeds = |t: f64| self.value(y0, dy0, t).abs() > tolerance;
+        let Some(last) = (0..samples).rev().find(|i| exceeds(*i as f64 * step)) else {
+            return Some(0.0);
+        };
+
+        let mut low = last as f64 * step;
+        let mut high = low + step;
+        for _ in 0..BISECTION_ITERATIONS {
+            let mid = (low + high) / 2.0;
+            if exceeds(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        Some(high)
+    }
+
+    /// peak_times returns the times where `y'(t)` crosses zero for `t > 0`.
+    fn peak_times(&self, y0: f64, dy0: f64) -> PeakTimes {
+        let none = PeakTimes {
+            first: None,
+            interval: None,
+        };
+        if self.angular_frequency < EPSILON {
+            return none;
+        }
+
+        let omega = self.angular_frequency;
+        let zeta = self.damping_ratio;
+
+        // y' is itself a solution, starting at y'(0) and y''(0).
+        let v0 = dy0;
+        let a0 = self.acceleration(y0, dy0);
+
+        if zeta > 1.0 + EPSILON {
+            let (z1, z2) = self.over_damped_roots();
+            let (d1, d2) = over_damped_weights(z1, z2, v0, a0);
+            if d1 == 0.0 || -d2 / d1 <= 0.0 {
+                return none;
+            }
+            let t = (-d2 / d1).ln() / (z1 - z2);
+            PeakTimes {
+                first: (t > 0.0).then_some(t),
+                interval: None,
+            }
+        } else if zeta < 1.0 - EPSILON {
+            if v0 == 0.0 && a0 == 0.0 {
+                return none;
+            }
+            let alpha = omega * (1.0 - zeta * zeta).sqrt();
+            let c = v0;
+            let d = (a0 + zeta * omega * v0) / alpha;
+
+            // c·cos(αt) + d·sin(αt) = r·sin(αt + φ) is zero at αt = kπ - φ.
+            let phi = c.atan2(d);
+            let mut theta = (-phi).rem_euclid(PI);
+            if theta < EPSILON {
+                theta += PI;
+            }
+            PeakTimes {
+                first: Some(theta / alpha),
+                interval: Some(PI / alpha),
+            }
+        } else {
+            let c = v0;
+            let d = a0 + omega * v0;
+            let t = if d != 0.0 { -c / d } else { 0.0 };
+            PeakTimes {
+                first: (t > 0.0).then_some(t),
+                interval: None,
+            }
+        }
+    }
+}
+
+/// over_damped_weights returns `(c1, c2)` such that
+/// `y(t) = c1·exp(z1·t) + c2·exp(z2·t)` has the given `y(0)` and `y'(0)`.
+fn over_damped_weights(z1: f64, z2: f64, y0: f64, dy0: f64) -> (f64, f64) {
+    let c1 = (z2 * y0 - dy0) / (z2 - z1);
+    let c2 = (dy0 - z1 * y0) / (z2 - z1);
+    (c1, c2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AngularFrequency, DampingRatio, DeltaTime, Spring, SpringTrajectory};
+
+    #[test]
+    fn test_state_at_matches_stepping() {
+        for damping in [0.3, 1.0, 1.7] {
+            let trajectory = SpringTrajectory::new(
+                AngularFrequency(6.0),
+                DampingRatio(damping),
+                0.0,
+                3.0,
+                100.0,
+            );
+            let mut spring = Spring::new(
+                DeltaTime(0.01),
+                AngularFrequency(6.0),
+                DampingRatio(damping),
+            );
+
+            let (mut pos, mut vel) = (0.0, 3.0);
+            for _ in 0..150 {
+                (pos, vel) = spring.update(pos, vel, 100.0);
+            }
+
+            let (seek_pos, seek_vel) = trajectory.state_at(1.5);
+            assert!(relative_eq!(seek_pos, pos, epsilon = 1e-6));
+            assert!(relative_eq!(seek_vel, vel, epsilon = 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_state_at_before_start_is_initial_state() {
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(0.5), 10.0, 2.0, 100.0);
+
+        assert_eq!(trajectory.state_at(0.0), (10.0, 2.0));
+        assert_eq!(trajectory.state_at(-1.0), (10.0, 2.0));
+    }
+
+    #[test]
+    fn test_sample_spans_duration() {
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(1.0), 0.0, 0.0, 100.0);
+        let samples = trajectory.sample(2.0, 5);
+
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[0], 0.0);
+        assert_eq!(samples[4], trajectory.position_at(2.0));
+        assert!(trajectory.sample(2.0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_settling_time_is_last_exit_from_tolerance() {
+        for damping in [0.2, 0.7, 1.0, 2.5] {
+            let trajectory = SpringTrajectory::new(
+                AngularFrequency(6.0),
+                DampingRatio(damping),
+                0.0,
+                0.0,
+                100.0,
+            );
+            let settling_time = trajectory.settling_time(0.5).unwrap();
+
+            assert!((trajectory.position_at(settling_time - 1e-3) - 100.0).abs() > 0.5);
+            for i in 0..1000 {
+                let t = settling_time + 1e-6 + i as f64 * 0.01;
+                assert!((trajectory.position_at(t) - 100.0).abs() <= 0.5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_undamped_spring_never_settles() {
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(0.0), 0.0, 0.0, 100.0);
+
+        assert_eq!(trajectory.settling_time(0.5), None);
+        assert_eq!(trajectory.oscillation_count(0.5), None);
+    }
+
+    #[test]
+    fn test_step_response_overshoot_matches_textbook() {
+        // A spring released from rest overshoots by exp(-πζ / sqrt(1 - ζ²)) at
+        // time π / ωd.
+        let (omega, zeta) = (6.0, 0.5);
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(omega), DampingRatio(zeta), 0.0, 0.0, 100.0);
+
+        let root = (1.0_f64 - zeta * zeta).sqrt();
+        let expected_ratio = (-std::f64::consts::PI * zeta / root).exp();
+        let expected_peak = std::f64::consts::PI / (omega * root);
+
+        assert!(relative_eq!(
+            trajectory.overshoot_ratio().unwrap(),
+            expected_ratio,
+            epsilon = 1e-9
+        ));
+        assert!(relative_eq!(
+            trajectory.peak_overshoot(),
+            100.0 * expected_ratio,
+            epsilon = 1e-7
+        ));
+        assert!(relative_eq!(
+            trajectory.first_peak_time().unwrap(),
+            expected_peak,
+            epsilon = 1e-9
+        ));
+    }
+
+    #[test]
+    fn test_critically_damped_from_rest_does_not_overshoot() {
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(1.0), 0.0, 0.0, 100.0);
+
+        assert_eq!(trajectory.peak_overshoot(), 0.0);
+        assert_eq!(trajectory.first_peak_time(), None);
+        assert_eq!(trajectory.oscillation_count(0.1), Some(0));
+    }
+
+    #[test]
+    fn test_over_damped_with_fast_start_overshoots_once() {
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(1.5), 0.0, 2000.0, 100.0);
+
+        let peak = trajectory.first_peak_time().unwrap();
+        assert!(relative_eq!(
+            trajectory.velocity_at(peak),
+            0.0,
+            epsilon = 1e-9
+        ));
+        assert!(trajectory.peak_overshoot() > 0.0);
+        assert_eq!(trajectory.oscillation_count(0.1), Some(1));
+    }
+
+    #[test]
+    fn test_oscillation_count_matches_simulation() {
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(8.0), DampingRatio(0.2), 0.0, 0.0, 100.0);
+        let settling_time = trajectory.settling_time(1.0).unwrap();
+
+        // Count velocity sign changes while still outside the tolerance.
+        let step = 1e-4;
+        let mut count = 0;
+        let mut prev_vel = trajectory.velocity_at(step);
+        let mut t = 2.0 * step;
+        while t < settling_time {
+            let vel = trajectory.velocity_at(t);
+            if vel.signum() != prev_vel.signum() && (trajectory.position_at(t) - 100.0).abs() > 1.0
+            {
+                count += 1;
+            }
+            prev_vel = vel;
+            t += step;
+        }
+
+        assert_eq!(trajectory.oscillation_count(1.0), Some(count));
+    }
+
+    #[test]
+    fn test_velocity_settling_time() {
+        let trajectory =
+            SpringTrajectory::new(AngularFrequency(6.0), DampingRatio(0.7), 0.0, 0.0, 100.0);
+        let settling_time = trajectory.velocity_settling_time(0.01).unwrap();
+
+        assert!(trajectory.velocity_at(settling_time - 1e-3).abs() > 0.01);
+        assert!(trajectory.velocity_at(settling_time + 1e-3).abs() <= 0.01);
+    }
+}